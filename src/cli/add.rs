@@ -1,10 +1,15 @@
+use std::io::Read;
+
 use anyhow::{bail, Context, Result};
-use chrono::{Local, NaiveDate};
+use chrono::{Local, NaiveDate, Utc};
 use clap::Args;
+use log::info;
 
-use crate::config::Config;
-use crate::data::model::Category;
+use crate::cli::{parse_week_arg, shell, status};
+use crate::config::{Config, ConfigOverrides, TimerConfig};
+use crate::data::model::{Category, WeekEntry};
 use crate::data::{store, week};
+use crate::display;
 use crate::git;
 use crate::ui;
 use crate::ui::PromptResult;
@@ -18,41 +23,126 @@ Navigation (interactive mode):
   Esc/q       Go back one level
   g           Jump to first item
   G           Jump to last item
+  c           Pick the current week
+  s/r/d/i     Pick Ind Sup / Grp Sup / Direct / Indirect
   ?           Show help overlay
   Ctrl+C      Exit immediately")]
 pub struct AddArgs {
-    #[arg(long, help = "Tuesday start date of the week (YYYY-MM-DD)")]
+    #[arg(
+        long,
+        help = "Start date of the week (YYYY-MM-DD, on the week start day)"
+    )]
     pub week: Option<String>,
 
-    #[arg(long, help = "Hour category")]
+    #[arg(
+        long,
+        help = "Hour category: individual_supervision, group_supervision, direct, indirect (or is, gs, d, i)"
+    )]
     pub category: Option<String>,
 
-    #[arg(long, allow_hyphen_values = true, help = "Number of hours to add")]
+    #[arg(
+        long,
+        allow_hyphen_values = true,
+        value_parser = display::parse_hours,
+        help = "Number of hours to add"
+    )]
     pub hours: Option<f64>,
 
+    #[arg(
+        long,
+        conflicts_with = "week",
+        help = "First day the hours were worked (YYYY-MM-DD, any weekday)"
+    )]
+    pub date: Option<String>,
+
+    #[arg(
+        long,
+        requires = "date",
+        value_parser = clap::value_parser!(u32).range(1..=366),
+        help = "Spread the hours evenly over N days (1-366) starting at --date"
+    )]
+    pub over: Option<u32>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["hours", "date"],
+        requires = "category",
+        help = "Read a duration (e.g. 1h30m, 1:30, or seconds) from [timer] command or stdin; implies --non-interactive"
+    )]
+    pub from_timer: bool,
+
     #[arg(long, help = "Run without interactive prompts")]
     pub non_interactive: bool,
+
+    #[arg(
+        long,
+        help = "After saving, print each affected week's totals and whether it meets its direct target"
+    )]
+    pub confirm_summary: bool,
+
+    #[arg(
+        long,
+        value_name = "TEXT",
+        help = "Set the week's note, replacing any existing one (requires --non-interactive)"
+    )]
+    pub note: Option<String>,
 }
 
-pub fn run(args: AddArgs, no_git: bool) -> Result<()> {
-    let config = Config::load()?;
+pub fn run(args: AddArgs, overrides: &ConfigOverrides, no_git: bool, quiet: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
     let data_file = config.data_file();
 
     let today = Local::now().date_naive();
 
-    if args.non_interactive {
+    if args.date.is_some() && !args.non_interactive {
+        bail!("--date and --over require --non-interactive");
+    }
+    if args.confirm_summary && !(args.non_interactive || args.from_timer) {
+        bail!("--confirm-summary requires --non-interactive");
+    }
+    if args.note.is_some() && !(args.non_interactive || args.from_timer) {
+        bail!("--note requires --non-interactive");
+    }
+
+    let timer_hours = if args.from_timer {
+        let output = read_timer_output(&config.timer)?;
+        let entry = output
+            .lines()
+            .map(str::trim)
+            .rfind(|line| !line.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Timer produced no output"))?;
+        let hours = parse_duration(entry)?;
+        if hours == 0.0 {
+            bail!("Timer duration '{entry}' is zero; nothing to add");
+        }
+        status(
+            quiet,
+            format_args!("Timer reported {entry} = {hours:.2} hrs"),
+        );
+        Some(hours)
+    } else {
+        None
+    };
+
+    if args.non_interactive || args.from_timer {
+        git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
         let mut data = store::load(&data_file)?;
 
         let week_start = match &args.week {
-            Some(w) => {
-                let date = NaiveDate::parse_from_str(w, "%Y-%m-%d")
-                    .with_context(|| format!("Invalid date format: {w}"))?;
-                if !week::is_tuesday(date) {
-                    bail!("Week start date must be a Tuesday, got {date}");
-                }
-                date
+            Some(w) => parse_week_arg(w, config.week_start())?,
+            None => week::current_week(today, config.week_start()).0,
+        };
+
+        let span = match &args.date {
+            Some(d) => {
+                let date = NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| {
+                    anyhow::anyhow!(
+                        "Invalid date format: {d}. Expected YYYY-MM-DD, e.g. --date 2025-02-02"
+                    )
+                })?;
+                Some((date, args.over.unwrap_or(1)))
             }
-            None => week::current_week(today).0,
+            None => None,
         };
 
         let cat_str = args
@@ -60,36 +150,116 @@ pub fn run(args: AddArgs, no_git: bool) -> Result<()> {
             .ok_or_else(|| anyhow::anyhow!("--category is required in non-interactive mode"))?;
         let category: Category = cat_str.parse()?;
 
-        let hours = args
-            .hours
+        let hours = timer_hours
+            .or(args.hours)
             .ok_or_else(|| anyhow::anyhow!("--hours is required in non-interactive mode"))?;
         if hours < 0.0 {
             bail!("Hours must be >= 0, got {hours}");
         }
 
-        let (_, week_end) = week::week_containing(week_start);
-        let entry = match data.weeks.iter_mut().find(|w| w.start == week_start) {
-            Some(entry) => entry,
-            None => {
-                data.weeks
-                    .push(crate::data::model::WeekEntry::new(week_start, week_end));
-                data.weeks.last_mut().unwrap()
+        let allocations = match span {
+            Some((date, days)) => {
+                split_hours(hours, &week::days_by_week(date, days, config.week_start())?)
             }
+            None => vec![(week_start, hours)],
+        };
+        // A single dated day is also kept as a daily entry; spans from --over
+        // are only bucketed by week.
+        let day = span.filter(|(_, days)| *days == 1).map(|(date, _)| date);
+        // A note describes the day it was logged on, so a span only notes the
+        // week holding --date.
+        let note_week = match span {
+            Some((date, _)) => week::week_containing(date, config.week_start()).0,
+            None => week_start,
         };
-        entry.add(category, hours);
 
-        store::save(&data_file, &data)?;
+        let before = data.total();
+        let now = Utc::now();
+        for (week_start, week_hours) in &allocations {
+            let (_, week_end) = week::week_containing(*week_start, config.week_start());
+            let entry = match data.weeks.iter_mut().find(|w| w.start == *week_start) {
+                Some(entry) => entry,
+                None => {
+                    data.weeks.push(crate::data::model::WeekEntry::created(
+                        *week_start,
+                        week_end,
+                        now,
+                    ));
+                    data.weeks.last_mut().unwrap()
+                }
+            };
+            info!("Adding {week_hours} {category} hours to week of {week_start}");
+            match day {
+                Some(date) => entry.add_day(date, category, *week_hours),
+                None => entry.add(category, *week_hours),
+            }
+            entry.record_entry(*week_hours);
+            if let Some(text) = args.note.as_ref().filter(|_| *week_start == note_week) {
+                entry.set_note(text);
+            }
+            entry.touch(now);
+        }
 
-        println!("Added {hours:.1} {category} hours for week of {week_start}");
+        store::save(
+            &data_file,
+            &data,
+            config.data_backups(),
+            config.week_start(),
+        )?;
 
-        let message = format!(
-            "Add {} {} hours for week of {}",
-            hours, category, week_start
-        );
-        git::git_sync(&config.data_dir(), &config.git, &message, no_git)?;
+        let message = match span {
+            Some((date, days)) => {
+                status(
+                    quiet,
+                    format_args!(
+                        "Added {hours:.1} {category} hours over {days} day(s) starting {date}:"
+                    ),
+                );
+                for (week_start, week_hours) in &allocations {
+                    status(
+                        quiet,
+                        format_args!("  week of {week_start}: {week_hours:.2} hrs"),
+                    );
+                }
+                format!(
+                    "Add {} {} hours over {} days from {}",
+                    hours, category, days, date
+                )
+            }
+            None => {
+                status(
+                    quiet,
+                    format_args!("Added {hours:.1} {category} hours for week of {week_start}"),
+                );
+                format!(
+                    "Add {} {} hours for week of {}",
+                    hours, category, week_start
+                )
+            }
+        };
+        if let Some(milestone) =
+            crossed_milestone(before, data.total(), config.milestones.increment)
+        {
+            status(quiet, milestone_message(milestone));
+        }
+        if args.confirm_summary {
+            for (week_start, _) in &allocations {
+                if let Some(entry) = data.weeks.iter().find(|w| w.start == *week_start) {
+                    println!("{}", week_recap(entry, config.licensure.min_weekly_average));
+                }
+            }
+        }
+
+        git::git_sync(
+            &config.data_dir(),
+            config.data_filename(),
+            &config.git,
+            &message,
+            no_git,
+        )?;
     } else {
-        let weeks = week::all_weeks(config.licensure.start_date, today);
-        let (current_start, _) = week::current_week(today);
+        let weeks = week::all_weeks(config.licensure.start_date, today, config.week_start());
+        let (current_start, _) = week::current_week(today, config.week_start());
 
         'week_loop: loop {
             let data = store::load(&data_file)?;
@@ -112,32 +282,55 @@ pub fn run(args: AddArgs, no_git: bool) -> Result<()> {
                     PromptResult::Exit => return Ok(()),
                 };
 
+                git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
                 let mut data = store::load(&data_file)?;
-                let (_, week_end) = week::week_containing(week_start);
+                let before = data.total();
+                let now = Utc::now();
+                let (_, week_end) = week::week_containing(week_start, config.week_start());
                 let entry = match data.weeks.iter_mut().find(|w| w.start == week_start) {
                     Some(entry) => entry,
                     None => {
-                        data.weeks
-                            .push(crate::data::model::WeekEntry::new(week_start, week_end));
+                        data.weeks.push(crate::data::model::WeekEntry::created(
+                            week_start, week_end, now,
+                        ));
                         data.weeks.last_mut().unwrap()
                     }
                 };
+                info!("Adding {hours} {category} hours to week of {week_start}");
                 entry.add(category, hours);
+                entry.record_entry(hours);
+                entry.touch(now);
 
                 let new_total = entry.total();
+                let milestone =
+                    crossed_milestone(before, data.total(), config.milestones.increment);
 
-                store::save(&data_file, &data)?;
+                store::save(
+                    &data_file,
+                    &data,
+                    config.data_backups(),
+                    config.week_start(),
+                )?;
 
                 let message = format!(
                     "Add {} {} hours for week of {}",
                     hours, category, week_start
                 );
-                git::git_sync(&config.data_dir(), &config.git, &message, no_git)?;
+                git::git_sync(
+                    &config.data_dir(),
+                    config.data_filename(),
+                    &config.git,
+                    &message,
+                    no_git,
+                )?;
 
                 ui::flash_confirmation(&format!(
                     "Added {hours:.1} {} hours -> week total: {new_total:.1}",
                     category.long_name()
                 ))?;
+                if let Some(milestone) = milestone {
+                    ui::flash_confirmation(&milestone_message(milestone))?;
+                }
 
                 continue 'category_loop;
             }
@@ -146,3 +339,246 @@ pub fn run(args: AddArgs, no_git: bool) -> Result<()> {
 
     Ok(())
 }
+
+fn read_timer_output(timer: &TimerConfig) -> Result<String> {
+    let Some(command) = &timer.command else {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .context("Failed to read timer output from stdin")?;
+        return Ok(input);
+    };
+
+    let output = shell(command)
+        .output()
+        .with_context(|| format!("Failed to run timer command '{command}'"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("Timer command '{command}' failed: {}", stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// Accepts unit strings (1h30m, 45m, 90s, 1.5h), clock strings (1:30, 1:30:15),
+// or a bare number of seconds, and returns fractional hours rounded to
+// hundredths.
+fn parse_duration(input: &str) -> Result<f64> {
+    let invalid = || {
+        anyhow::anyhow!(
+            "Invalid timer duration '{input}'. Expected e.g. 1h30m, 45m, 1:30, or a number of seconds"
+        )
+    };
+    let text: String = input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if text.is_empty() {
+        return Err(invalid());
+    }
+
+    let seconds = if text.contains(':') {
+        let parts = text
+            .split(':')
+            .map(|p| p.parse::<u64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>>>()?;
+        let (h, m, s) = match parts[..] {
+            [h, m] if m < 60 => (h, m, 0),
+            [h, m, s] if m < 60 && s < 60 => (h, m, s),
+            _ => return Err(invalid()),
+        };
+        h.checked_mul(3600)
+            .and_then(|secs| secs.checked_add(m * 60 + s))
+            .ok_or_else(|| anyhow::anyhow!("Timer duration '{input}' is too large"))? as f64
+    } else if let Ok(secs) = text.parse::<u64>() {
+        secs as f64
+    } else {
+        let mut total = 0.0;
+        let mut number = String::new();
+        for c in text.chars() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+                continue;
+            }
+            let value: f64 = number.parse().map_err(|_| invalid())?;
+            number.clear();
+            total += value
+                * match c {
+                    'h' => 3600.0,
+                    'm' => 60.0,
+                    's' => 1.0,
+                    _ => return Err(invalid()),
+                };
+        }
+        if !number.is_empty() {
+            return Err(invalid());
+        }
+        total
+    };
+
+    Ok((seconds / 3600.0 * 100.0).round() / 100.0)
+}
+
+// Splits `hours` across weeks in proportion to the days each one covers. Shares
+// are rounded to hundredths and the last week takes the remainder, so the
+// allocations always add back up to the input.
+fn split_hours(hours: f64, days_by_week: &[(NaiveDate, u32)]) -> Vec<(NaiveDate, f64)> {
+    let total_days: u32 = days_by_week.iter().map(|(_, days)| days).sum();
+    let mut allocated = 0.0;
+    let mut allocations = Vec::with_capacity(days_by_week.len());
+    for (i, (week_start, days)) in days_by_week.iter().enumerate() {
+        let share = if i + 1 == days_by_week.len() {
+            ((hours - allocated) * 100.0).round() / 100.0
+        } else {
+            (hours * f64::from(*days) / f64::from(total_days) * 100.0).round() / 100.0
+        };
+        allocated += share;
+        allocations.push((*week_start, share));
+    }
+    allocations
+}
+
+// The week's standing after an add: every category, the total, and how its
+// direct hours compare with its target.
+fn week_recap(entry: &WeekEntry, default_target: f64) -> String {
+    let categories: Vec<String> = Category::ALL
+        .iter()
+        .map(|c| format!("{} {}", c.display_name(), display::hours(entry.get(*c))))
+        .collect();
+    let target = entry.direct_target(default_target);
+    let status = if entry.meets_target(default_target) {
+        "met".to_string()
+    } else {
+        format!("{} short", display::hours(target - entry.direct))
+    };
+    format!(
+        "Week of {} – {}: {} | Total {}\n  Direct target: {} ({status})",
+        entry.start.format("%b %d"),
+        entry.end.format("%b %d, %Y"),
+        categories.join(" | "),
+        display::hours(entry.total()),
+        display::hours(target)
+    )
+}
+
+fn crossed_milestone(before: f64, after: f64, increment: u32) -> Option<u32> {
+    if increment == 0 {
+        return None;
+    }
+    let increment = f64::from(increment);
+    let reached = (after / increment).floor();
+    if reached > (before / increment).floor() && reached >= 1.0 {
+        Some((reached * increment) as u32)
+    } else {
+        None
+    }
+}
+
+fn milestone_message(milestone: u32) -> String {
+    if std::env::var_os("NO_COLOR").is_some() {
+        format!("Milestone: {milestone} hours!")
+    } else {
+        format!("🎉 Milestone: {milestone} hours!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parse_duration_formats() {
+        assert_eq!(parse_duration("1h30m").unwrap(), 1.5);
+        assert_eq!(parse_duration("1h 23m").unwrap(), 1.38);
+        assert_eq!(parse_duration("45m").unwrap(), 0.75);
+        assert_eq!(parse_duration("2H").unwrap(), 2.0);
+        assert_eq!(parse_duration("1.5h").unwrap(), 1.5);
+        assert_eq!(parse_duration("1:30").unwrap(), 1.5);
+        assert_eq!(parse_duration("0:45:00").unwrap(), 0.75);
+        assert_eq!(parse_duration("5400").unwrap(), 1.5);
+        assert_eq!(parse_duration(" 90m\n").unwrap(), 1.5);
+    }
+
+    #[test]
+    fn parse_duration_rejects_garbage() {
+        for input in ["", "abc", "1h30", "1:75", "1:2:3:4", "1d", "-5m", "h"] {
+            assert!(
+                parse_duration(input).is_err(),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_duration_rejects_overflowing_clock() {
+        for input in ["99999999999999999:00", "9999999999999999:59:59"] {
+            let err = parse_duration(input).unwrap_err();
+            assert!(err.to_string().contains("too large"), "{input:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn split_hours_within_week() {
+        let split = split_hours(9.0, &[(date(2025, 1, 28), 3)]);
+        assert_eq!(split, vec![(date(2025, 1, 28), 9.0)]);
+    }
+
+    #[test]
+    fn split_hours_across_boundary() {
+        let split = split_hours(9.0, &[(date(2025, 1, 28), 2), (date(2025, 2, 4), 1)]);
+        assert_eq!(
+            split,
+            vec![(date(2025, 1, 28), 6.0), (date(2025, 2, 4), 3.0)]
+        );
+    }
+
+    #[test]
+    fn split_hours_uneven_sums_to_input() {
+        let split = split_hours(10.0, &[(date(2025, 1, 28), 1), (date(2025, 2, 4), 2)]);
+        assert_eq!(
+            split,
+            vec![(date(2025, 1, 28), 3.33), (date(2025, 2, 4), 6.67)]
+        );
+        let total: f64 = split.iter().map(|(_, h)| h).sum();
+        assert!((total - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn week_recap_reports_totals_and_target() {
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.individual_supervision = 1.0;
+        entry.direct = 12.5;
+        assert_eq!(
+            week_recap(&entry, 15.0),
+            "Week of Jan 28 – Feb 03, 2025: Ind Sv 1.0 | Grp Sv 0.0 | Direct 12.5 | Indirect 0.0 | Total 13.5\n  Direct target: 15.0 (2.5 short)"
+        );
+
+        entry.target = Some(10.0);
+        assert!(week_recap(&entry, 15.0).ends_with("Direct target: 10.0 (met)"));
+    }
+
+    #[test]
+    fn crossed_milestone_detects_crossing() {
+        assert_eq!(crossed_milestone(240.0, 255.0, 250), Some(250));
+        assert_eq!(crossed_milestone(990.0, 1000.0, 250), Some(1000));
+        assert_eq!(crossed_milestone(100.0, 600.0, 250), Some(500));
+    }
+
+    #[test]
+    fn crossed_milestone_ignores_non_crossing() {
+        assert_eq!(crossed_milestone(10.0, 20.0, 250), None);
+        assert_eq!(crossed_milestone(250.0, 260.0, 250), None);
+        assert_eq!(crossed_milestone(0.0, 0.0, 250), None);
+    }
+
+    #[test]
+    fn crossed_milestone_disabled_with_zero_increment() {
+        assert_eq!(crossed_milestone(240.0, 300.0, 0), None);
+    }
+}
@@ -0,0 +1,197 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use clap::Args;
+use log::info;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::data::store;
+use crate::display;
+use crate::git;
+
+#[derive(Args)]
+pub struct BackupArgs {
+    #[arg(long, conflicts_with = "restore", help = "List the available backups")]
+    pub list: bool,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Replace the data file with this backup (a name from --list or a path)"
+    )]
+    pub restore: Option<String>,
+}
+
+// `hours.json` -> `hours-20250128-093000.json`.
+fn backup_name(data_filename: &str, stamp: &str) -> String {
+    let path = Path::new(data_filename);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("hours");
+    match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{stem}-{stamp}.{ext}"),
+        None => format!("{stem}-{stamp}"),
+    }
+}
+
+// Backup files oldest first. The timestamp in each name sorts by date.
+fn list_backups(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+// A bare name is looked up in the backups directory; anything else is a path.
+fn resolve(dir: &Path, file: &str) -> PathBuf {
+    let in_dir = dir.join(file);
+    if Path::new(file).components().count() == 1 && in_dir.exists() {
+        in_dir
+    } else {
+        PathBuf::from(file)
+    }
+}
+
+fn create(config: &Config, dir: &Path) -> Result<()> {
+    let data_file = config.data_file();
+    if !data_file.exists() {
+        bail!("Nothing to back up: {} does not exist", data_file.display());
+    }
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let stamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let target = dir.join(backup_name(config.data_filename(), &stamp));
+    if target.exists() {
+        bail!(
+            "{} already exists; wait a second and try again",
+            target.display()
+        );
+    }
+    fs::copy(&data_file, &target).with_context(|| {
+        format!(
+            "Failed to copy {} to {}",
+            data_file.display(),
+            target.display()
+        )
+    })?;
+    info!("Backed up {} to {}", data_file.display(), target.display());
+    println!(
+        "Backed up {} to {}",
+        config.data_filename(),
+        target.display()
+    );
+    Ok(())
+}
+
+fn list(dir: &Path) -> Result<()> {
+    let files = list_backups(dir)?;
+    if files.is_empty() {
+        println!("No backups in {}", dir.display());
+        return Ok(());
+    }
+    for path in files {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        match store::read(&path) {
+            Ok(data) => println!(
+                "{name}  {} week(s)  {} hrs",
+                data.weeks.len(),
+                display::hours(data.total())
+            ),
+            Err(_) => println!("{name}  (unreadable)"),
+        }
+    }
+    Ok(())
+}
+
+fn restore(config: &Config, dir: &Path, file: &str, no_git: bool) -> Result<()> {
+    let source = resolve(dir, file);
+    if !source.exists() {
+        bail!(
+            "No such backup: {file} (see `hours backup --list` for {})",
+            dir.display()
+        );
+    }
+    // Read as-is: a broken backup is reported, not recovered from its siblings.
+    let restored = store::read(&source)?;
+
+    let data_file = config.data_file();
+    let before = if data_file.exists() {
+        store::load(&data_file)?.total()
+    } else {
+        0.0
+    };
+    // Saving validates and sorts the backup, and keeps the replaced file as a
+    // backup generation so `hours undo` can step back.
+    store::save(
+        &data_file,
+        &restored,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+    info!("Restored {} from {}", data_file.display(), source.display());
+    println!(
+        "Restored {} from {}: total {} -> {} hrs",
+        config.data_filename(),
+        source.display(),
+        display::hours(before),
+        display::hours(restored.total())
+    );
+
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        "Restore backup",
+        no_git,
+    )?;
+    Ok(())
+}
+
+pub fn run(args: BackupArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let dir = config.data_dir().join("backups");
+
+    if args.list {
+        list(&dir)
+    } else if let Some(file) = &args.restore {
+        restore(&config, &dir, file, no_git)
+    } else {
+        create(&config, &dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_name_keeps_stem_and_extension() {
+        assert_eq!(
+            backup_name("hours.json", "20250128-093000"),
+            "hours-20250128-093000.json"
+        );
+        assert_eq!(
+            backup_name("clinic", "20250128-093000"),
+            "clinic-20250128-093000"
+        );
+    }
+
+    #[test]
+    fn resolve_prefers_backups_dir_for_bare_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("backups");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("hours-1.json"), "{}").unwrap();
+
+        assert_eq!(resolve(&dir, "hours-1.json"), dir.join("hours-1.json"));
+        assert_eq!(resolve(&dir, "other.json"), PathBuf::from("other.json"));
+        let explicit = tmp.path().join("hours-1.json");
+        assert_eq!(resolve(&dir, explicit.to_str().unwrap()), explicit);
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::{Context, Result};
+use clap::Args;
+
+use crate::cli::week_label;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{Category, HoursData, WeekChange, WeekEntry};
+use crate::data::store;
+use crate::git;
+
+#[derive(Args)]
+pub struct ChangesArgs {
+    #[arg(
+        long,
+        help = "Git revision to compare against (e.g. HEAD~3 or a commit hash)"
+    )]
+    pub since: String,
+
+    #[arg(long, help = "Output as JSON")]
+    pub json: bool,
+}
+
+fn category_deltas(before: &WeekEntry, after: &WeekEntry) -> String {
+    Category::ALL
+        .iter()
+        .filter(|c| before.get(**c) != after.get(**c))
+        .map(|c| {
+            format!(
+                "{} {:.1} -> {:.1}",
+                c.display_name(),
+                before.get(*c),
+                after.get(*c)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn describe(change: &WeekChange) -> String {
+    match change {
+        WeekChange::Added(w) => format!("  added     {}  {:.1} hrs", week_label(w), w.total()),
+        WeekChange::Removed(w) => format!("  removed   {}  {:.1} hrs", week_label(w), w.total()),
+        WeekChange::Modified { before, after } => format!(
+            "  modified  {}  {:.1} -> {:.1} hrs ({})",
+            week_label(after),
+            before.total(),
+            after.total(),
+            category_deltas(before, after)
+        ),
+    }
+}
+
+fn week_json(entry: Option<&WeekEntry>) -> serde_json::Value {
+    match entry {
+        Some(w) => serde_json::json!({
+            "individual_supervision": w.individual_supervision,
+            "group_supervision": w.group_supervision,
+            "direct": w.direct,
+            "indirect": w.indirect,
+            "total": w.total(),
+        }),
+        None => serde_json::Value::Null,
+    }
+}
+
+fn change_json(change: &WeekChange) -> serde_json::Value {
+    let (before, after) = match change {
+        WeekChange::Added(w) => (None, Some(w)),
+        WeekChange::Removed(w) => (Some(w), None),
+        WeekChange::Modified { before, after } => (Some(before), Some(after)),
+    };
+    serde_json::json!({
+        "change": change.kind(),
+        "start": change.start().format("%Y-%m-%d").to_string(),
+        "before": week_json(before),
+        "after": week_json(after),
+    })
+}
+
+pub fn run(args: ChangesArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    let data = store::load_lenient(&data_file, config.week_start())?;
+
+    let contents = git::git_show_file(
+        &config.data_dir(),
+        &args.since,
+        config.data_filename(),
+        no_git,
+    )?;
+    let previous: HoursData = serde_json::from_str(&contents).with_context(|| {
+        format!(
+            "Failed to parse {} at revision {}",
+            config.data_filename(),
+            args.since
+        )
+    })?;
+
+    let changes = data.changes_since(&previous);
+
+    if args.json {
+        let json: Vec<_> = changes.iter().map(change_json).collect();
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else if changes.is_empty() {
+        println!("No changes since {}.", args.since);
+    } else {
+        println!("Changes since {}:", args.since);
+        for change in &changes {
+            println!("{}", describe(change));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn week(direct: f64, indirect: f64) -> WeekEntry {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = direct;
+        entry.indirect = indirect;
+        entry
+    }
+
+    #[test]
+    fn describe_modified_lists_changed_categories() {
+        let change = WeekChange::Modified {
+            before: week(5.0, 1.0),
+            after: week(7.5, 1.0),
+        };
+        let line = describe(&change);
+        assert!(line.contains("modified"));
+        assert!(line.contains("6.0 -> 8.5 hrs"));
+        assert!(line.contains("Direct 5.0 -> 7.5"));
+        assert!(!line.contains("Indirect"));
+    }
+
+    #[test]
+    fn change_json_added_has_null_before() {
+        let json = change_json(&WeekChange::Added(week(3.0, 0.0)));
+        assert_eq!(json["change"], "added");
+        assert_eq!(json["start"], "2025-01-28");
+        assert!(json["before"].is_null());
+        assert_eq!(json["after"]["direct"], 3.0);
+    }
+}
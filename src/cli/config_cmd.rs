@@ -0,0 +1,414 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, Weekday};
+use clap::{Args, Subcommand};
+
+use crate::cli::week_label;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::store;
+use crate::data::week;
+use crate::git;
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    // Bare `hours config` behaves like `hours config show`.
+    #[command(subcommand)]
+    pub action: Option<ConfigAction>,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    #[command(about = "Change a config value (start_date, licensure targets, git.auto_push)")]
+    Set(SetArgs),
+    #[command(about = "Print one config value as used this run")]
+    Get(GetArgs),
+    #[command(about = "Print the config in effect, or the file as written with --raw")]
+    Show(ShowArgs),
+}
+
+#[derive(Args)]
+pub struct ShowArgs {
+    #[arg(
+        long,
+        conflicts_with = "raw",
+        help = "Print the config as used this run, with env, flag, and default values applied (default)"
+    )]
+    pub effective: bool,
+
+    #[arg(long, help = "Print the config file exactly as written")]
+    pub raw: bool,
+}
+
+#[derive(Args)]
+pub struct GetArgs {
+    #[arg(help = "Config key to print")]
+    pub key: String,
+}
+
+#[derive(Args)]
+pub struct SetArgs {
+    #[arg(help = "Config key to change")]
+    pub key: String,
+
+    #[arg(allow_hyphen_values = true, help = "New value")]
+    pub value: String,
+
+    #[arg(
+        long,
+        help = "Delete logged weeks that fall before a new start_date instead of keeping them"
+    )]
+    pub prune_before: bool,
+}
+
+pub fn run(args: ConfigArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    match args.action {
+        Some(ConfigAction::Set(args)) => set(args, overrides, no_git),
+        Some(ConfigAction::Get(args)) => get(args, overrides),
+        Some(ConfigAction::Show(args)) => show(args, overrides),
+        None => show(
+            ShowArgs {
+                effective: true,
+                raw: false,
+            },
+            overrides,
+        ),
+    }
+}
+
+const SUPPORTED_KEYS: &str = "start_date, total_hours_target, direct_hours_target, \
+     individual_supervision_target, group_supervision_target, indirect_target, min_months, \
+     min_weekly_average, git.auto_push, git.auto_pull";
+
+fn get(args: GetArgs, overrides: &ConfigOverrides) -> Result<()> {
+    let config = Config::load(overrides)?;
+    println!("{}", get_value(&config, &args.key)?);
+    Ok(())
+}
+
+fn get_value(config: &Config, key: &str) -> Result<String> {
+    let licensure = &config.licensure;
+    Ok(match key {
+        "start_date" => licensure.start_date.to_string(),
+        "total_hours_target" => licensure.total_hours_target.to_string(),
+        "direct_hours_target" => licensure.direct_hours_target.to_string(),
+        "individual_supervision_target" => optional(licensure.individual_supervision_target),
+        "group_supervision_target" => optional(licensure.group_supervision_target),
+        "indirect_target" => optional(licensure.indirect_target),
+        "min_months" => licensure.min_months.to_string(),
+        "min_weekly_average" => licensure.min_weekly_average.to_string(),
+        "git.auto_push" => config.git.auto_push.to_string(),
+        "git.auto_pull" => config.git.auto_pull.to_string(),
+        other => bail!("Unsupported config key '{other}'. Supported keys: {SUPPORTED_KEYS}"),
+    })
+}
+
+fn show(args: ShowArgs, overrides: &ConfigOverrides) -> Result<()> {
+    let config_path = Config::config_path(overrides);
+    if !config_path.exists() {
+        bail!("Configuration not found. Run `hours init` to set up.");
+    }
+
+    if args.raw {
+        let contents = std::fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        print!("{contents}");
+        return Ok(());
+    }
+
+    let config = Config::load(overrides)?;
+    println!("# {} with overrides applied", config_path.display());
+    print!("{}", render_effective(&config)?);
+    Ok(())
+}
+
+// Optional settings are filled in with the defaults they resolve to, so the
+// output shows every value the run actually uses.
+fn render_effective(config: &Config) -> Result<String> {
+    let mut effective = config.clone();
+    effective.data.filename = Some(config.data_filename().to_string());
+    effective.data.backups = Some(config.data_backups());
+    toml::to_string_pretty(&effective).context("Failed to serialize config")
+}
+
+fn parse_start_date(input: &str, week_start: Weekday) -> Result<NaiveDate> {
+    let date = NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d")
+        .with_context(|| format!("Invalid date format: {input}. Expected YYYY-MM-DD"))?;
+    if !week::is_week_start(date, week_start) {
+        let (containing, _) = week::week_containing(date, week_start);
+        bail!(
+            "Start date must be a {}, got {date} ({}). The week containing it starts on {containing}",
+            week::weekday_name(week_start),
+            date.format("%A")
+        );
+    }
+    Ok(date)
+}
+
+fn parse_whole(key: &str, value: &str) -> Result<u32> {
+    value.trim().parse().map_err(|_| {
+        anyhow::anyhow!("Invalid {key} '{value}': expected a non-negative whole number")
+    })
+}
+
+// `none` clears an optional target.
+fn parse_optional_whole(key: &str, value: &str) -> Result<Option<u32>> {
+    match value.trim() {
+        "none" => Ok(None),
+        whole => whole.parse().map(Some).map_err(|_| {
+            anyhow::anyhow!("Invalid {key} '{value}': expected a non-negative whole number or none")
+        }),
+    }
+}
+
+fn parse_average(value: &str) -> Result<f64> {
+    match value.trim().parse::<f64>() {
+        Ok(average) if average.is_finite() && average >= 0.0 => Ok(average),
+        _ => bail!("Invalid min_weekly_average '{value}': expected a number >= 0"),
+    }
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool> {
+    match value.trim() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => bail!("Invalid {key} '{value}': expected true or false"),
+    }
+}
+
+fn replace<T: Display>(slot: &mut T, new: T) -> (String, String) {
+    let old = slot.to_string();
+    *slot = new;
+    (old, slot.to_string())
+}
+
+fn optional(value: Option<u32>) -> String {
+    value.map_or_else(|| "none".to_string(), |v| v.to_string())
+}
+
+fn replace_optional(slot: &mut Option<u32>, new: Option<u32>) -> (String, String) {
+    let old = optional(*slot);
+    *slot = new;
+    (old, optional(*slot))
+}
+
+// Applies a plain (non-start_date) key to the config as written, returning the
+// old and new values for the report line.
+fn apply(config: &mut Config, key: &str, value: &str) -> Result<(String, String)> {
+    let licensure = &mut config.licensure;
+    Ok(match key {
+        "total_hours_target" => {
+            replace(&mut licensure.total_hours_target, parse_whole(key, value)?)
+        }
+        "direct_hours_target" => {
+            replace(&mut licensure.direct_hours_target, parse_whole(key, value)?)
+        }
+        "individual_supervision_target" => replace_optional(
+            &mut licensure.individual_supervision_target,
+            parse_optional_whole(key, value)?,
+        ),
+        "group_supervision_target" => replace_optional(
+            &mut licensure.group_supervision_target,
+            parse_optional_whole(key, value)?,
+        ),
+        "indirect_target" => replace_optional(
+            &mut licensure.indirect_target,
+            parse_optional_whole(key, value)?,
+        ),
+        "min_months" => replace(&mut licensure.min_months, parse_whole(key, value)?),
+        "min_weekly_average" => replace(&mut licensure.min_weekly_average, parse_average(value)?),
+        "git.auto_push" => replace(&mut config.git.auto_push, parse_bool(key, value)?),
+        "git.auto_pull" => replace(&mut config.git.auto_pull, parse_bool(key, value)?),
+        other => bail!("Unsupported config key '{other}'. Supported keys: {SUPPORTED_KEYS}"),
+    })
+}
+
+fn set(args: SetArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    if args.key == "start_date" {
+        return set_start_date(&args.value, args.prune_before, overrides, no_git);
+    }
+    if args.prune_before {
+        bail!("--prune-before only applies to start_date");
+    }
+
+    let config_path = Config::config_path(overrides);
+    if !config_path.exists() {
+        bail!("Configuration not found. Run `hours init` to set up.");
+    }
+    let mut file_config = Config::read_file(&config_path)?;
+    let (previous, new) = apply(&mut file_config, &args.key, &args.value)?;
+    file_config.save(&config_path)?;
+    println!("{}: {previous} -> {new}", args.key);
+    Ok(())
+}
+
+fn set_start_date(
+    value: &str,
+    prune_before: bool,
+    overrides: &ConfigOverrides,
+    no_git: bool,
+) -> Result<()> {
+    // Edit the file as written; the loaded config carries environment overrides
+    // that must not be persisted.
+    let config_path = Config::config_path(overrides);
+    if !config_path.exists() {
+        bail!("Configuration not found. Run `hours init` to set up.");
+    }
+    let mut file_config = Config::read_file(&config_path)?;
+    let config = Config::load(overrides)?;
+    let start_date = parse_start_date(value, config.week_start())?;
+    let data_file = config.data_file();
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let mut data = store::load(&data_file)?;
+
+    let orphaned: Vec<_> = data
+        .weeks
+        .iter()
+        .filter(|w| w.start < start_date)
+        .cloned()
+        .collect();
+
+    if !orphaned.is_empty() {
+        if prune_before {
+            data.weeks.retain(|w| w.start >= start_date);
+            store::save(
+                &data_file,
+                &data,
+                config.data_backups(),
+                config.week_start(),
+            )?;
+            println!("Removed {} week(s) before {start_date}:", orphaned.len());
+            for w in &orphaned {
+                println!("  {}  {:.1} hrs", week_label(w), w.total());
+            }
+        } else {
+            eprintln!(
+                "Warning: {} logged week(s) start before the new start date {start_date}:",
+                orphaned.len()
+            );
+            for w in &orphaned {
+                eprintln!("  {}  {:.1} hrs", week_label(w), w.total());
+            }
+            eprintln!("They are kept. Rerun with --prune-before to remove them.");
+        }
+    }
+
+    let previous = file_config.licensure.start_date;
+    file_config.licensure.start_date = start_date;
+    file_config.save(&config_path)?;
+    println!("start_date: {previous} -> {start_date}");
+
+    // config.toml lives outside the data repository, like every `config set`
+    // key, so there is only something to commit when weeks were pruned.
+    if prune_before && !orphaned.is_empty() {
+        let message = format!(
+            "Remove {} week(s) before start date {start_date}",
+            orphaned.len()
+        );
+        git::git_sync(
+            &config.data_dir(),
+            config.data_filename(),
+            &config.git,
+            &message,
+            no_git,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_effective_fills_defaults() {
+        let config: Config = toml::from_str(
+            r#"[data]
+directory = "/srv/hours"
+
+[git]
+remote = "origin"
+auto_push = true
+
+[licensure]
+start_date = "2025-01-28"
+total_hours_target = 3000
+direct_hours_target = 1200
+min_months = 24
+min_weekly_average = 15.0
+"#,
+        )
+        .unwrap();
+        let out = render_effective(&config).unwrap();
+        assert!(out.contains("directory = \"/srv/hours\""), "{out}");
+        assert!(out.contains("filename = \"hours.json\""), "{out}");
+        assert!(out.contains("backups = 1"), "{out}");
+    }
+
+    #[test]
+    fn apply_validates_and_reports_change() {
+        let mut config: Config = toml::from_str(
+            r#"[data]
+directory = "/srv/hours"
+
+[git]
+remote = "origin"
+auto_push = true
+
+[licensure]
+start_date = "2025-01-28"
+total_hours_target = 3000
+direct_hours_target = 1200
+min_months = 24
+min_weekly_average = 15.0
+"#,
+        )
+        .unwrap();
+
+        let change = apply(&mut config, "total_hours_target", "3360").unwrap();
+        assert_eq!(change, ("3000".to_string(), "3360".to_string()));
+        assert_eq!(config.licensure.total_hours_target, 3360);
+        apply(&mut config, "min_weekly_average", "12.5").unwrap();
+        assert_eq!(get_value(&config, "min_weekly_average").unwrap(), "12.5");
+        apply(&mut config, "git.auto_push", "false").unwrap();
+        assert!(!config.git.auto_push);
+        apply(&mut config, "git.auto_pull", "true").unwrap();
+        assert_eq!(get_value(&config, "git.auto_pull").unwrap(), "true");
+
+        let err = apply(&mut config, "direct_hours_target", "-5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid direct_hours_target '-5': expected a non-negative whole number"
+        );
+        assert!(apply(&mut config, "min_weekly_average", "-1").is_err());
+        assert!(apply(&mut config, "git.auto_push", "maybe").is_err());
+        let err = apply(&mut config, "remote", "x").unwrap_err().to_string();
+        assert!(err.contains("Supported keys: start_date,"), "{err}");
+        assert_eq!(config.licensure.direct_hours_target, 1200);
+
+        let change = apply(&mut config, "group_supervision_target", "100").unwrap();
+        assert_eq!(change, ("none".to_string(), "100".to_string()));
+        assert_eq!(config.licensure.group_supervision_target, Some(100));
+        apply(&mut config, "group_supervision_target", "none").unwrap();
+        assert_eq!(
+            get_value(&config, "group_supervision_target").unwrap(),
+            "none"
+        );
+        assert!(apply(&mut config, "indirect_target", "-1").is_err());
+    }
+
+    #[test]
+    fn parse_start_date_requires_tuesday() {
+        assert_eq!(
+            parse_start_date("2025-02-04", Weekday::Tue).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 4).unwrap()
+        );
+        let err = parse_start_date("2025-02-06", Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Thursday"), "{err}");
+        assert!(err.contains("starts on 2025-02-04"), "{err}");
+        assert!(parse_start_date("02/04/2025", Weekday::Tue).is_err());
+    }
+}
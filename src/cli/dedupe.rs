@@ -0,0 +1,67 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::week_label;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::DuplicateMerge;
+use crate::data::store;
+use crate::git;
+
+#[derive(Args)]
+pub struct DedupeArgs {
+    #[arg(long, help = "Report duplicate weeks without saving")]
+    pub dry_run: bool,
+}
+
+fn describe(merge: &DuplicateMerge) -> String {
+    format!(
+        "  merged {} records  {}  -> {:.1} hrs",
+        merge.count,
+        week_label(&merge.merged),
+        merge.merged.total()
+    )
+}
+
+pub fn run(args: DedupeArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let mut data = store::load(&data_file)?;
+
+    let merges = data.dedupe();
+    if merges.is_empty() {
+        println!("No duplicate weeks found.");
+        return Ok(());
+    }
+
+    for merge in &merges {
+        println!("{}", describe(merge));
+    }
+
+    if args.dry_run {
+        println!(
+            "Dry run: {} duplicate week(s) would be merged. No changes written.",
+            merges.len()
+        );
+        return Ok(());
+    }
+
+    store::save(
+        &data_file,
+        &data,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+    println!("Merged {} duplicate week(s).", merges.len());
+
+    let message = format!("Merge {} duplicate week(s)", merges.len());
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        &message,
+        no_git,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use chrono::Weekday;
+use clap::Args;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::data::store;
+use crate::git;
+use crate::pdf;
+
+#[derive(Args)]
+pub struct DoctorArgs {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, detail: impl Into<String>) -> Self {
+        Check {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.status {
+            Status::Pass => "pass",
+            Status::Warn => "warn",
+            Status::Fail => "fail",
+        };
+        write!(f, "{label}  {:<10} {}", self.name, self.detail)
+    }
+}
+
+fn check_config(path: &Path, overrides: &ConfigOverrides) -> (Check, Option<Config>) {
+    if !path.exists() {
+        let detail = format!("{} not found. Run `hours init`", path.display());
+        return (Check::new("config", Status::Fail, detail), None);
+    }
+    match Config::load_from(path, overrides) {
+        Ok(config) => (
+            Check::new("config", Status::Pass, path.display().to_string()),
+            Some(config),
+        ),
+        Err(e) => (Check::new("config", Status::Fail, format!("{e:#}")), None),
+    }
+}
+
+fn check_data_dir(dir: &Path) -> Check {
+    if !dir.is_dir() {
+        return Check::new(
+            "data dir",
+            Status::Fail,
+            format!("{} does not exist", dir.display()),
+        );
+    }
+    let probe = dir.join(".hours-doctor.tmp");
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            Check::new(
+                "data dir",
+                Status::Pass,
+                format!("{} is writable", dir.display()),
+            )
+        }
+        Err(e) => Check::new(
+            "data dir",
+            Status::Fail,
+            format!("{} is not writable: {e}", dir.display()),
+        ),
+    }
+}
+
+// Reads with `store::read`, so the check reports the file itself rather than
+// pointing at a recoverable backup.
+fn check_data_file(path: &Path, week_start: Weekday) -> Check {
+    match store::read(path) {
+        Ok(data) => match store::validate(&data, week_start) {
+            Ok(()) => Check::new(
+                "data file",
+                Status::Pass,
+                format!("{} ({} week(s))", path.display(), data.weeks.len()),
+            ),
+            Err(e) => Check::new(
+                "data file",
+                Status::Warn,
+                format!("loads but fails validation: {e}. Run `hours verify`"),
+            ),
+        },
+        Err(e) => Check::new("data file", Status::Fail, format!("{e:#}")),
+    }
+}
+
+fn check_git(data_dir: &Path, remote: &str, no_git: bool) -> Vec<Check> {
+    if git::is_git_disabled(no_git) {
+        return vec![Check::new(
+            "git",
+            Status::Warn,
+            "disabled (--no-git or HOURS_NO_GIT=1); git checks skipped",
+        )];
+    }
+    if !git::git_binary_exists() {
+        return vec![Check::new(
+            "git",
+            Status::Warn,
+            "not installed; data is saved locally only",
+        )];
+    }
+
+    let mut checks = vec![Check::new("git", Status::Pass, "installed")];
+    if !git::is_git_repo(data_dir) {
+        checks.push(Check::new(
+            "repository",
+            Status::Warn,
+            format!("{} is not a git repository", data_dir.display()),
+        ));
+        return checks;
+    }
+    checks.push(Check::new(
+        "repository",
+        Status::Pass,
+        "data dir is a git repository",
+    ));
+    checks.push(match git::check_remote(data_dir, remote) {
+        Ok(url) => Check::new(
+            "remote",
+            Status::Pass,
+            format!("{remote} ({url}) is reachable"),
+        ),
+        Err(e) => Check::new("remote", Status::Warn, format!("{e:#}")),
+    });
+    checks
+}
+
+fn check_fonts() -> Check {
+    match pdf::load_font_family() {
+        Ok(_) => Check::new("fonts", Status::Pass, "PDF fonts load"),
+        Err(e) => Check::new("fonts", Status::Fail, format!("{e:#}")),
+    }
+}
+
+pub fn run(_args: DoctorArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let (config_check, config) = check_config(&Config::config_path(overrides), overrides);
+    let mut checks = vec![config_check];
+
+    if let Some(config) = config {
+        let data_dir = config.data_dir();
+        let dir_check = check_data_dir(&data_dir);
+        let dir_ok = dir_check.status == Status::Pass;
+        checks.push(dir_check);
+        if dir_ok {
+            checks.push(check_data_file(&config.data_file(), config.week_start()));
+            checks.extend(check_git(&data_dir, &config.git.remote, no_git));
+        }
+    }
+    checks.push(check_fonts());
+
+    for check in &checks {
+        println!("{check}");
+    }
+
+    let failed = checks.iter().filter(|c| c.status == Status::Fail).count();
+    if failed > 0 {
+        bail!("{failed} check(s) failed");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    #[test]
+    fn config_missing_fails() {
+        let tmp = TempDir::new().unwrap();
+        let (check, config) =
+            check_config(&tmp.path().join("config.toml"), &ConfigOverrides::default());
+        assert_eq!(check.status, Status::Fail);
+        assert!(check.detail.contains("hours init"));
+        assert!(config.is_none());
+    }
+
+    #[test]
+    fn config_malformed_fails() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(&path, "not toml [[[").unwrap();
+        let (check, config) = check_config(&path, &ConfigOverrides::default());
+        assert_eq!(check.status, Status::Fail);
+        assert!(config.is_none());
+    }
+
+    #[test]
+    fn data_dir_checks() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(check_data_dir(tmp.path()).status, Status::Pass);
+        assert!(!tmp.path().join(".hours-doctor.tmp").exists());
+        assert_eq!(
+            check_data_dir(&tmp.path().join("missing")).status,
+            Status::Fail
+        );
+    }
+
+    #[test]
+    fn data_file_checks() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("hours.json");
+
+        std::fs::write(&path, r#"{"weeks":[]}"#).unwrap();
+        assert_eq!(check_data_file(&path, Weekday::Tue).status, Status::Pass);
+
+        let week = r#"{"start":"2025-01-28","end":"2025-02-03","individual_supervision":0.0,"group_supervision":0.0,"direct":1.0,"indirect":0.0}"#;
+        std::fs::write(&path, format!(r#"{{"weeks":[{week},{week}]}}"#)).unwrap();
+        let check = check_data_file(&path, Weekday::Tue);
+        assert_eq!(check.status, Status::Warn);
+        assert!(check.detail.contains("Duplicate week"), "{}", check.detail);
+
+        std::fs::write(&path, "{not json").unwrap();
+        assert_eq!(check_data_file(&path, Weekday::Tue).status, Status::Fail);
+        assert_eq!(
+            check_data_file(&tmp.path().join("missing.json"), Weekday::Tue).status,
+            Status::Fail
+        );
+    }
+
+    #[test]
+    fn git_checks_warn_without_repo_or_remote() {
+        let tmp = TempDir::new().unwrap();
+        let checks = check_git(tmp.path(), "origin", true);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, Status::Warn);
+
+        let checks = check_git(tmp.path(), "origin", false);
+        if checks[0].detail.starts_with("disabled") {
+            return;
+        }
+        assert_eq!(checks[1].name, "repository");
+        assert_eq!(checks[1].status, Status::Warn);
+
+        Command::new("git")
+            .arg("init")
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+        let checks = check_git(tmp.path(), "origin", false);
+        assert_eq!(checks[1].status, Status::Pass);
+        assert_eq!(checks[2].name, "remote");
+        assert_eq!(checks[2].status, Status::Warn);
+    }
+
+    #[test]
+    fn fonts_load() {
+        assert_eq!(check_fonts().status, Status::Pass);
+    }
+
+    #[test]
+    fn display_line() {
+        let check = Check::new("fonts", Status::Pass, "PDF fonts load");
+        assert_eq!(check.to_string(), "pass  fonts      PDF fonts load");
+    }
+}
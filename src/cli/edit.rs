@@ -1,13 +1,20 @@
+use std::fs;
+use std::path::Path;
+
 use anyhow::{bail, Context, Result};
-use chrono::{Local, NaiveDate};
+use chrono::{Local, NaiveDate, Utc};
 use clap::Args;
+use log::info;
+use serde::{Deserialize, Serialize};
 
-use crate::config::Config;
+use crate::cli::{parse_week_arg, shell, status};
+use crate::config::{Config, ConfigOverrides};
 use crate::data::model::{Category, WeekEntry};
 use crate::data::{store, week};
+use crate::display;
 use crate::git;
 use crate::ui;
-use crate::ui::PromptResult;
+use crate::ui::{EditField, PromptResult};
 
 #[derive(Args)]
 #[command(after_help = "\
@@ -18,95 +25,260 @@ Navigation (interactive mode):
   Esc/q       Go back one level
   g           Jump to first item
   G           Jump to last item
+  c           Pick the current week
+  s/r/d/i     Pick Ind Sup / Grp Sup / Direct / Indirect
+  n           Pick the week's note
   ?           Show help overlay
-  Ctrl+C      Exit immediately")]
+  Ctrl+C      Exit immediately
+
+Category flag aliases:
+  --individual-supervision   --ind, --ind-sup, --is
+  --group-supervision        --grp, --grp-sup, --gs
+  --direct                   -d, --dir
+  --indirect                 --indir")]
 pub struct EditArgs {
-    #[arg(long, help = "Tuesday start date of the week (YYYY-MM-DD)")]
+    #[arg(
+        long,
+        help = "Start date of the week (YYYY-MM-DD, on the week start day)"
+    )]
     pub week: Option<String>,
 
     #[arg(
         long,
+        visible_aliases = ["ind", "ind-sup", "is"],
         allow_hyphen_values = true,
+        value_parser = display::parse_hours,
         help = "Individual supervision hours"
     )]
     pub individual_supervision: Option<f64>,
 
-    #[arg(long, allow_hyphen_values = true, help = "Group supervision hours")]
+    #[arg(
+        long,
+        visible_aliases = ["grp", "grp-sup", "gs"],
+        allow_hyphen_values = true,
+        value_parser = display::parse_hours,
+        help = "Group supervision hours"
+    )]
     pub group_supervision: Option<f64>,
 
-    #[arg(long, allow_hyphen_values = true, help = "Direct client contact hours")]
+    #[arg(
+        long,
+        short = 'd',
+        visible_aliases = ["dir"],
+        allow_hyphen_values = true,
+        value_parser = display::parse_hours,
+        help = "Direct client contact hours"
+    )]
     pub direct: Option<f64>,
 
-    #[arg(long, allow_hyphen_values = true, help = "Indirect hours")]
+    #[arg(
+        long,
+        visible_alias = "indir",
+        allow_hyphen_values = true,
+        value_parser = display::parse_hours,
+        help = "Indirect hours"
+    )]
     pub indirect: Option<f64>,
 
+    #[arg(
+        long,
+        value_name = "TEXT",
+        conflicts_with = "note_append",
+        help = "Set the week's note, replacing any existing one; \"\" clears it (requires --non-interactive)"
+    )]
+    pub note: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "TEXT",
+        help = "Append a line to the week's note (requires --non-interactive)"
+    )]
+    pub note_append: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "HOURS",
+        value_parser = display::parse_hours,
+        help = "Direct-hours goal for this week, overriding min_weekly_average (requires --non-interactive)"
+    )]
+    pub week_target: Option<f64>,
+
+    #[arg(
+        long,
+        conflicts_with = "include",
+        help = "Mark the week as a board-recognized break, left out of the weekly average (requires --non-interactive)"
+    )]
+    pub exclude: bool,
+
+    #[arg(
+        long,
+        help = "Count a previously excluded week toward the weekly average again (requires --non-interactive)"
+    )]
+    pub include: bool,
+
     #[arg(long, help = "Run without interactive prompts")]
     pub non_interactive: bool,
+
+    #[arg(
+        long,
+        help = "Leave the week unchanged if a category being set already has hours (requires --non-interactive)"
+    )]
+    pub if_empty: bool,
+
+    #[arg(long, help = "Overwrite existing hours even with --if-empty")]
+    pub force: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "individual_supervision", "group_supervision", "direct", "indirect",
+            "note", "note_append", "week_target", "exclude", "include", "non_interactive",
+        ],
+        help = "Edit the week's four category values together in $EDITOR"
+    )]
+    pub editor: bool,
 }
 
-pub fn run(args: EditArgs, no_git: bool) -> Result<()> {
-    let config = Config::load()?;
+pub fn run(args: EditArgs, overrides: &ConfigOverrides, no_git: bool, quiet: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
     let data_file = config.data_file();
 
     let today = Local::now().date_naive();
 
-    if args.non_interactive {
-        let mut data = store::load(&data_file)?;
+    if args.note.is_some() && !args.non_interactive {
+        bail!("--note requires --non-interactive");
+    }
+
+    if args.note_append.is_some() && !args.non_interactive {
+        bail!("--note-append requires --non-interactive");
+    }
+
+    if args.week_target.is_some() && !args.non_interactive {
+        bail!("--week-target requires --non-interactive");
+    }
 
+    if (args.exclude || args.include) && !args.non_interactive {
+        bail!("--exclude and --include require --non-interactive");
+    }
+
+    if args.if_empty && !args.non_interactive {
+        bail!("--if-empty requires --non-interactive");
+    }
+
+    if args.editor {
         let week_start = match &args.week {
-            Some(w) => {
-                let date = NaiveDate::parse_from_str(w, "%Y-%m-%d")
-                    .with_context(|| format!("Invalid date format: {w}"))?;
-                if !week::is_tuesday(date) {
-                    bail!("Week start date must be a Tuesday, got {date}");
+            Some(w) => parse_week_arg(w, config.week_start())?,
+            None => {
+                let weeks =
+                    week::all_weeks(config.licensure.start_date, today, config.week_start());
+                let data = store::load(&data_file)?;
+                match ui::select_week(
+                    &weeks,
+                    &data,
+                    week::current_week(today, config.week_start()).0,
+                )? {
+                    PromptResult::Value(ws) => ws,
+                    PromptResult::Back | PromptResult::Exit => return Ok(()),
                 }
-                date
             }
-            None => week::current_week(today).0,
         };
+        return edit_in_editor(&config, no_git, quiet, week_start);
+    }
+
+    if args.non_interactive {
+        git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+        let mut data = store::load(&data_file)?;
+
+        let week_start = match &args.week {
+            Some(w) => parse_week_arg(w, config.week_start())?,
+            None => week::current_week(today, config.week_start()).0,
+        };
+
+        if args.if_empty && !args.force {
+            let existing = data.weeks.iter().find(|w| w.start == week_start);
+            let filled = filled_categories(existing, &args);
+            if !filled.is_empty() {
+                eprintln!(
+                    "Week of {week_start} already has {}; left unchanged (--if-empty). \
+                     Use --force to overwrite.",
+                    filled.join(", ")
+                );
+                return Ok(());
+            }
+        }
 
-        let (_, week_end) = week::week_containing(week_start);
+        let now = Utc::now();
+        let (_, week_end) = week::week_containing(week_start, config.week_start());
         let entry = match data.weeks.iter_mut().find(|w| w.start == week_start) {
             Some(entry) => entry,
             None => {
-                data.weeks.push(WeekEntry::new(week_start, week_end));
+                data.weeks
+                    .push(WeekEntry::created(week_start, week_end, now));
                 data.weeks.last_mut().unwrap()
             }
         };
+        entry.touch(now);
+        info!("Editing week of {week_start}");
 
         if let Some(val) = args.individual_supervision {
             if val < 0.0 {
                 bail!("Hours must be >= 0");
             }
-            entry.set(Category::IndividualSupervision, val);
+            entry.set_total(Category::IndividualSupervision, val);
         }
         if let Some(val) = args.group_supervision {
             if val < 0.0 {
                 bail!("Hours must be >= 0");
             }
-            entry.set(Category::GroupSupervision, val);
+            entry.set_total(Category::GroupSupervision, val);
         }
         if let Some(val) = args.direct {
             if val < 0.0 {
                 bail!("Hours must be >= 0");
             }
-            entry.set(Category::Direct, val);
+            entry.set_total(Category::Direct, val);
         }
         if let Some(val) = args.indirect {
             if val < 0.0 {
                 bail!("Hours must be >= 0");
             }
-            entry.set(Category::Indirect, val);
+            entry.set_total(Category::Indirect, val);
+        }
+        if let Some(text) = &args.note {
+            entry.set_note(text);
+        }
+        if let Some(text) = &args.note_append {
+            entry.append_note(text);
+        }
+        if let Some(target) = args.week_target {
+            if target < 0.0 {
+                bail!("Week target must be >= 0");
+            }
+            entry.target = Some(target);
+        }
+        if args.exclude || args.include {
+            entry.excluded = args.exclude;
         }
 
-        store::save(&data_file, &data)?;
-        println!("Edited hours for week of {week_start}");
+        store::save(
+            &data_file,
+            &data,
+            config.data_backups(),
+            config.week_start(),
+        )?;
+        status(quiet, format_args!("Edited hours for week of {week_start}"));
 
         let message = format!("Edit hours for week of {week_start}");
-        git::git_sync(&config.data_dir(), &config.git, &message, no_git)?;
+        git::git_sync(
+            &config.data_dir(),
+            config.data_filename(),
+            &config.git,
+            &message,
+            no_git,
+        )?;
     } else {
-        let weeks = week::all_weeks(config.licensure.start_date, today);
-        let (current_start, _) = week::current_week(today);
+        let weeks = week::all_weeks(config.licensure.start_date, today, config.week_start());
+        let (current_start, _) = week::current_week(today, config.week_start());
 
         'week_loop: loop {
             let data = store::load(&data_file)?;
@@ -117,8 +289,9 @@ pub fn run(args: EditArgs, no_git: bool) -> Result<()> {
             };
 
             'category_loop: loop {
+                git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
                 let data = store::load(&data_file)?;
-                let (_, week_end) = week::week_containing(week_start);
+                let (_, week_end) = week::week_containing(week_start, config.week_start());
 
                 let display_entry = data
                     .weeks
@@ -127,8 +300,25 @@ pub fn run(args: EditArgs, no_git: bool) -> Result<()> {
                     .cloned()
                     .unwrap_or_else(|| WeekEntry::new(week_start, week_end));
 
-                let category = match ui::select_category_with_values(&display_entry)? {
-                    PromptResult::Value(c) => c,
+                let category = match ui::select_edit_field(&display_entry)? {
+                    PromptResult::Value(EditField::Category(c)) => c,
+                    PromptResult::Value(EditField::Note) => {
+                        let Some(text) = ui::input_text(
+                            "Note",
+                            display_entry.note.as_deref(),
+                            ui::TEXT_INPUT_MAX_LEN,
+                        )?
+                        else {
+                            continue 'category_loop;
+                        };
+                        info!("Setting note for week of {week_start}");
+                        let message = format!("Edit note for week of {week_start}");
+                        save_week(&config, no_git, week_start, &message, |entry| {
+                            entry.set_note(&text)
+                        })?;
+                        ui::flash_confirmation(&format!("Updated note for week of {week_start}"))?;
+                        continue 'category_loop;
+                    }
                     PromptResult::Back => continue 'week_loop,
                     PromptResult::Exit => return Ok(()),
                 };
@@ -142,20 +332,11 @@ pub fn run(args: EditArgs, no_git: bool) -> Result<()> {
                     PromptResult::Exit => return Ok(()),
                 };
 
-                let mut data = store::load(&data_file)?;
-                let entry = match data.weeks.iter_mut().find(|w| w.start == week_start) {
-                    Some(entry) => entry,
-                    None => {
-                        data.weeks.push(WeekEntry::new(week_start, week_end));
-                        data.weeks.last_mut().unwrap()
-                    }
-                };
-                entry.set(category, new_val);
-
-                store::save(&data_file, &data)?;
-
+                info!("Setting {category} to {new_val} for week of {week_start}");
                 let message = format!("Edit hours for week of {week_start}");
-                git::git_sync(&config.data_dir(), &config.git, &message, no_git)?;
+                save_week(&config, no_git, week_start, &message, |entry| {
+                    entry.set_total(category, new_val)
+                })?;
 
                 ui::flash_confirmation(&format!(
                     "Set {} to {new_val:.1} hrs for week of {week_start}",
@@ -169,3 +350,208 @@ pub fn run(args: EditArgs, no_git: bool) -> Result<()> {
 
     Ok(())
 }
+
+// The categories this edit would set that already hold hours, for
+// `--if-empty`, e.g. `["direct 10.0 hrs"]`.
+fn filled_categories(entry: Option<&WeekEntry>, args: &EditArgs) -> Vec<String> {
+    let Some(entry) = entry else {
+        return Vec::new();
+    };
+    [
+        (Category::IndividualSupervision, args.individual_supervision),
+        (Category::GroupSupervision, args.group_supervision),
+        (Category::Direct, args.direct),
+        (Category::Indirect, args.indirect),
+    ]
+    .into_iter()
+    .filter(|(category, value)| value.is_some() && entry.get(*category) != 0.0)
+    .map(|(category, _)| format!("{category} {} hrs", display::hours(entry.get(category))))
+    .collect()
+}
+
+// Reloads the data, applies `change` to the week (creating it if needed),
+// then saves and syncs. Used by the interactive loop, which reloads per edit.
+fn save_week(
+    config: &Config,
+    no_git: bool,
+    week_start: NaiveDate,
+    message: &str,
+    change: impl FnOnce(&mut WeekEntry),
+) -> Result<()> {
+    let data_file = config.data_file();
+    let mut data = store::load(&data_file)?;
+    let now = Utc::now();
+    let (_, week_end) = week::week_containing(week_start, config.week_start());
+    let entry = match data.weeks.iter_mut().find(|w| w.start == week_start) {
+        Some(entry) => entry,
+        None => {
+            data.weeks
+                .push(WeekEntry::created(week_start, week_end, now));
+            data.weeks.last_mut().unwrap()
+        }
+    };
+    change(entry);
+    entry.touch(now);
+
+    store::save(
+        &data_file,
+        &data,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        message,
+        no_git,
+    )
+}
+
+// The four category values as written to the editor's temp file.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EditorValues {
+    individual_supervision: f64,
+    group_supervision: f64,
+    direct: f64,
+    indirect: f64,
+}
+
+impl EditorValues {
+    fn from_entry(entry: &WeekEntry) -> Self {
+        EditorValues {
+            individual_supervision: entry.individual_supervision,
+            group_supervision: entry.group_supervision,
+            direct: entry.direct,
+            indirect: entry.indirect,
+        }
+    }
+
+    fn get(&self, category: Category) -> f64 {
+        match category {
+            Category::IndividualSupervision => self.individual_supervision,
+            Category::GroupSupervision => self.group_supervision,
+            Category::Direct => self.direct,
+            Category::Indirect => self.indirect,
+        }
+    }
+}
+
+fn render_editor_file(entry: &WeekEntry) -> Result<String> {
+    let values = toml::to_string(&EditorValues::from_entry(entry))
+        .context("Failed to serialize week for editing")?;
+    Ok(format!(
+        "# Hours for the week of {} – {}. Save and quit to apply;\n\
+         # exit the editor with an error to leave the week unchanged.\n{values}",
+        entry.start, entry.end
+    ))
+}
+
+fn parse_editor_file(text: &str) -> Result<EditorValues> {
+    let values: EditorValues =
+        toml::from_str(text).map_err(|e| anyhow::anyhow!("Failed to parse edited week: {e}"))?;
+    for category in Category::ALL {
+        let value = values.get(category);
+        if !value.is_finite() || value < 0.0 {
+            bail!("Invalid {category} value {value}: hours must be >= 0");
+        }
+    }
+    Ok(values)
+}
+
+// `$EDITOR` may carry arguments (`code --wait`), so it runs through the shell.
+fn launch_editor(editor: &str, path: &Path) -> Result<()> {
+    #[cfg(windows)]
+    let status = shell(&format!("{editor} \"{}\"", path.display())).status();
+    // The path is passed as `$1` so the shell never parses it.
+    #[cfg(not(windows))]
+    let status = shell(&format!("{editor} \"$1\""))
+        .arg("sh")
+        .arg(path)
+        .status();
+    let status = status.with_context(|| format!("Failed to run editor '{editor}'"))?;
+    if !status.success() {
+        bail!("Editor '{editor}' exited with {status}; week left unchanged");
+    }
+    Ok(())
+}
+
+fn edit_in_editor(config: &Config, no_git: bool, quiet: bool, week_start: NaiveDate) -> Result<()> {
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let data = store::load(&config.data_file())?;
+    let (_, week_end) = week::week_containing(week_start, config.week_start());
+    let entry = data
+        .weeks
+        .iter()
+        .find(|w| w.start == week_start)
+        .cloned()
+        .unwrap_or_else(|| WeekEntry::new(week_start, week_end));
+
+    let editor = std::env::var("EDITOR")
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad" } else { "vi" }.to_string());
+    let path = std::env::temp_dir().join(format!(
+        "hours-week-{week_start}-{}.toml",
+        std::process::id()
+    ));
+    fs::write(&path, render_editor_file(&entry)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    info!("Editing week of {week_start} in '{editor}'");
+    let edited = launch_editor(&editor, &path).and_then(|()| {
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+    });
+    let _ = fs::remove_file(&path);
+    let values = parse_editor_file(&edited?)?;
+
+    if values == EditorValues::from_entry(&entry) {
+        status(quiet, format_args!("No changes to week of {week_start}"));
+        return Ok(());
+    }
+
+    let message = format!("Edit hours for week of {week_start}");
+    save_week(config, no_git, week_start, &message, |entry| {
+        for category in Category::ALL {
+            entry.set_total(category, values.get(category));
+        }
+    })?;
+    status(quiet, format_args!("Edited hours for week of {week_start}"));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn editor_file_round_trips_and_validates() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = 12.5;
+        entry.group_supervision = 1.0;
+
+        let text = render_editor_file(&entry).unwrap();
+        assert!(text.starts_with("# Hours for the week of 2025-01-28 – 2025-02-03."));
+        assert_eq!(
+            parse_editor_file(&text).unwrap(),
+            EditorValues::from_entry(&entry)
+        );
+
+        // Whole numbers are fine; TOML writes them without a decimal point.
+        let edited = text.replace("direct = 12.5", "direct = 14");
+        assert_eq!(parse_editor_file(&edited).unwrap().direct, 14.0);
+
+        let err = parse_editor_file(&text.replace("direct = 12.5", "direct = -1"))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "Invalid direct value -1: hours must be >= 0");
+        let err = parse_editor_file(&text.replace("direct = 12.5", "direct = lots"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("Failed to parse edited week:"), "{err}");
+        assert!(parse_editor_file("direct = 1").is_err());
+    }
+}
@@ -1,9 +1,20 @@
-use anyhow::Result;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::process::Stdio;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
 use chrono::Local;
 use clap::Args;
 
-use crate::config::Config;
+use crate::cli::{list, shell, status, summary};
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::HoursData;
+use crate::data::progress::{self, Progress};
 use crate::data::store;
+use crate::export::{csv, ics};
+use crate::git;
 use crate::pdf;
 
 #[derive(Args)]
@@ -11,22 +22,273 @@ pub struct ExportArgs {
     #[arg(long, help = "Override output file path")]
     pub output: Option<String>,
 
-    #[arg(long, help = "Open the PDF after generation")]
+    #[arg(long, help = "Open the report after generation")]
     pub open: bool,
+
+    #[arg(
+        long,
+        default_value = "pdf",
+        help = "Output format (pdf, ics, csv, or exec:<command> to pipe `list --json` through a command)"
+    )]
+    pub format: String,
+
+    #[arg(long, help = "Text file to insert above the PDF header")]
+    pub header_text: Option<String>,
+
+    #[arg(long, help = "Text file to insert below the PDF summary")]
+    pub footer_text: Option<String>,
+
+    #[arg(long, help = "Fail instead of exporting when no hours are logged")]
+    pub fail_on_empty: bool,
+
+    #[arg(long, help = "Lay out PDF pages in landscape orientation")]
+    pub landscape: bool,
+
+    #[arg(
+        long,
+        help = "Commit the report to the data repository, even though exports/ is gitignored"
+    )]
+    pub commit: bool,
+
+    #[arg(long, help = "Print a text preview of the report's weeks and summary")]
+    pub preview: bool,
+
+    #[arg(
+        long,
+        requires = "preview",
+        conflicts_with_all = ["open", "commit"],
+        help = "With --preview, skip writing the report file"
+    )]
+    pub no_write: bool,
+
+    #[arg(long, help = "Append the raw data file to the PDF as an appendix")]
+    pub embed_data: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Add the weekly average over the last N weeks to the PDF summary"
+    )]
+    pub window: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportFormat {
+    Pdf,
+    Ics,
+    Csv,
+    // Shell command that receives `hours list --json` on stdin.
+    Exec(String),
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Ics => "ics",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Exec(_) => "out",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(command) = s.strip_prefix("exec:") {
+            let command = command.trim();
+            if command.is_empty() {
+                bail!("--format exec: needs a command, e.g. exec:\"pandoc -o report.docx\"");
+            }
+            return Ok(ExportFormat::Exec(command.to_string()));
+        }
+        match s {
+            "pdf" => Ok(ExportFormat::Pdf),
+            "ics" => Ok(ExportFormat::Ics),
+            "csv" => Ok(ExportFormat::Csv),
+            _ => Err(anyhow::anyhow!(
+                "Invalid export format '{}'. Valid formats: pdf, ics, csv, exec:<command>",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Exec(command) => write!(f, "exec:{command}"),
+            _ => write!(f, "{}", self.extension()),
+        }
+    }
 }
 
-pub fn run(args: ExportArgs, _no_git: bool) -> Result<()> {
-    let config = Config::load()?;
+// Run `command` through the shell with `input` on stdin. Its stdout goes to
+// `output` when given, else straight to ours; stderr always passes through.
+fn run_exec(command: &str, input: Vec<u8>, output: Option<&Path>) -> Result<()> {
+    let mut child = shell(command)
+        .stdin(Stdio::piped())
+        .stdout(if output.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::inherit()
+        })
+        .spawn()
+        .with_context(|| format!("Failed to run export command `{command}`"))?;
+
+    // Feed stdin from a thread so a command that writes before it finishes
+    // reading can't deadlock against a full stdout pipe.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = std::thread::spawn(move || match stdin.write_all(&input) {
+        // A command may exit without reading everything; its status decides.
+        Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    });
+
+    let result = child
+        .wait_with_output()
+        .with_context(|| format!("Failed to run export command `{command}`"))?;
+    writer
+        .join()
+        .expect("stdin writer panicked")
+        .with_context(|| format!("Failed to write to export command `{command}`"))?;
+
+    match result.status.code() {
+        Some(0) => {}
+        Some(127) => bail!("Export command not found: `{command}`"),
+        Some(code) => bail!("Export command `{command}` failed with exit code {code}"),
+        None => bail!("Export command `{command}` was terminated by a signal"),
+    }
+
+    if let Some(path) = output {
+        std::fs::write(path, &result.stdout)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn read_text_block(path: &str) -> Result<String> {
+    let bytes = std::fs::read(path).with_context(|| format!("Failed to read {path}"))?;
+    String::from_utf8(bytes).map_err(|_| anyhow::anyhow!("{path} is not valid UTF-8 text"))
+}
+
+// Text-only stand-in for the PDF: the list table followed by the summary.
+fn render_preview(data: &HoursData, progress: &Progress) -> String {
+    let table = if data.weeks.is_empty() {
+        "No hours logged yet.".to_string()
+    } else {
+        list::render_table(&data.weeks, true, None, None, false, None)
+    };
+    format!(
+        "{table}\n\n{}",
+        summary::render_text(data, progress, false, false)
+    )
+}
+
+pub fn run(args: ExportArgs, overrides: &ConfigOverrides, no_git: bool, quiet: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
     let data_file = config.data_file();
-    let data = store::load(&data_file)?;
+    let data = store::load_lenient(&data_file, config.week_start())?;
+
+    let format: ExportFormat = args.format.parse()?;
+
+    if args.embed_data && format != ExportFormat::Pdf {
+        bail!("--embed-data only applies to PDF export");
+    }
+    if args.header_text.is_some() && format != ExportFormat::Pdf {
+        bail!("--header-text only applies to PDF export");
+    }
+    if args.footer_text.is_some() && format != ExportFormat::Pdf {
+        bail!("--footer-text only applies to PDF export");
+    }
+    if args.window.is_some() && format != ExportFormat::Pdf {
+        bail!("--window only applies to PDF export");
+    }
+    if args.window == Some(0) {
+        bail!("--window must be >= 1, got 0");
+    }
+
+    if matches!(format, ExportFormat::Exec(_))
+        && args.output.is_none()
+        && (args.open || args.commit)
+    {
+        bail!("--format exec: writes to stdout unless --output is given, so --open and --commit need --output");
+    }
+
+    if args.fail_on_empty && !data.weeks.iter().any(|w| w.total() > 0.0) {
+        bail!(
+            "Nothing to export: no hours are logged in {}",
+            data_file.display()
+        );
+    }
+
+    let options = pdf::ReportOptions {
+        header_text: args
+            .header_text
+            .as_deref()
+            .map(read_text_block)
+            .transpose()?,
+        footer_text: args
+            .footer_text
+            .as_deref()
+            .map(read_text_block)
+            .transpose()?,
+        landscape: args.landscape,
+        precision: config.report.display_precision,
+        embedded_data: if args.embed_data {
+            Some(pdf::EmbeddedData {
+                name: config.data_filename().to_string(),
+                contents: std::fs::read_to_string(&data_file)
+                    .with_context(|| format!("Failed to read {}", data_file.display()))?,
+            })
+        } else {
+            None
+        },
+        window: args.window,
+    };
 
     let today = Local::now().date_naive();
-    let output_path = match &args.output {
-        Some(p) => std::path::PathBuf::from(p),
-        None => {
+
+    if args.preview {
+        let mut progress = Progress::compute(&data, &config.licensure, today);
+        if let Some(weeks) = args.window {
+            progress.set_window(progress::window_average(
+                &data,
+                &config.licensure,
+                today,
+                weeks,
+            ));
+        }
+        println!("{}", render_preview(&data, &progress));
+        if args.no_write {
+            return Ok(());
+        }
+        println!();
+    }
+
+    let exec_input = || -> Result<Vec<u8>> {
+        let weeks = list::json_weeks(
+            &data.weeks,
+            config.licensure.min_weekly_average,
+            None,
+            false,
+        );
+        Ok(serde_json::to_vec_pretty(&weeks)?)
+    };
+
+    let output_path = match (&args.output, &format) {
+        (Some(p), _) => std::path::PathBuf::from(p),
+        // Without --output, the command's stdout is the export.
+        (None, ExportFormat::Exec(command)) => return run_exec(command, exec_input()?, None),
+        (None, _) => {
             let exports_dir = config.data_dir().join("exports");
             std::fs::create_dir_all(&exports_dir)?;
-            exports_dir.join(format!("hours-report-{}.pdf", today.format("%Y-%m-%d")))
+            exports_dir.join(format!(
+                "hours-report-{}.{}",
+                today.format("%Y-%m-%d"),
+                format.extension()
+            ))
         }
     };
 
@@ -34,9 +296,34 @@ pub fn run(args: ExportArgs, _no_git: bool) -> Result<()> {
         std::fs::create_dir_all(parent)?;
     }
 
-    pdf::generate_report(&data, &config.licensure, &output_path)?;
+    match format {
+        ExportFormat::Pdf => {
+            pdf::generate_report(&data, &config.licensure, &options, &output_path)?
+        }
+        ExportFormat::Ics => ics::generate_calendar(&data, &output_path)?,
+        ExportFormat::Csv => csv::generate_csv(&data, &output_path)?,
+        ExportFormat::Exec(command) => run_exec(&command, exec_input()?, Some(&output_path))?,
+    }
 
-    println!("Report saved to {}", output_path.display());
+    status(
+        quiet,
+        format_args!("Report saved to {}", output_path.display()),
+    );
+
+    if args.commit {
+        let message = format!("Add report {}", today.format("%Y-%m-%d"));
+        git::git_commit_export(
+            &config.data_dir(),
+            &output_path,
+            &config.git,
+            &message,
+            no_git,
+        )?;
+        status(
+            quiet,
+            format_args!("Committed {} to the data repository", output_path.display()),
+        );
+    }
 
     if args.open {
         #[cfg(target_os = "macos")]
@@ -55,3 +342,77 @@ pub fn run(args: ExportArgs, _no_git: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_text_block_valid_utf8() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("header.txt");
+        std::fs::write(&path, "Program: Riverside — Cohort 3\n").unwrap();
+        let text = read_text_block(path.to_str().unwrap()).unwrap();
+        assert!(text.contains("Riverside — Cohort 3"));
+    }
+
+    #[test]
+    fn read_text_block_rejects_invalid_utf8() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("header.txt");
+        std::fs::write(&path, [0xff, 0xfe, 0x00]).unwrap();
+        let err = read_text_block(path.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"));
+    }
+
+    #[test]
+    fn read_text_block_missing_file() {
+        assert!(read_text_block("/nonexistent/header.txt").is_err());
+    }
+
+    #[test]
+    fn export_format_from_str() {
+        assert_eq!("pdf".parse::<ExportFormat>().unwrap(), ExportFormat::Pdf);
+        assert_eq!("ics".parse::<ExportFormat>().unwrap(), ExportFormat::Ics);
+        assert_eq!("csv".parse::<ExportFormat>().unwrap(), ExportFormat::Csv);
+        assert!("docx".parse::<ExportFormat>().is_err());
+        assert_eq!(
+            "exec: pandoc -o out.docx".parse::<ExportFormat>().unwrap(),
+            ExportFormat::Exec("pandoc -o out.docx".to_string())
+        );
+        assert!("exec:".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn run_exec_writes_command_stdout() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("out.txt");
+        run_exec("tr a-z A-Z", b"weeks".to_vec(), Some(&path)).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "WEEKS");
+    }
+
+    #[test]
+    fn run_exec_reports_failures() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("out.txt");
+        let err = run_exec("exit 3", Vec::new(), Some(&path)).unwrap_err();
+        assert!(err.to_string().contains("failed with exit code 3"), "{err}");
+        assert!(!path.exists());
+
+        let err = run_exec("hours-no-such-renderer", Vec::new(), None).unwrap_err();
+        assert!(err.to_string().contains("not found"), "{err}");
+    }
+
+    #[test]
+    fn export_format_display_roundtrip() {
+        for format in [
+            ExportFormat::Pdf,
+            ExportFormat::Ics,
+            ExportFormat::Csv,
+            ExportFormat::Exec("cat".to_string()),
+        ] {
+            let parsed: ExportFormat = format.to_string().parse().unwrap();
+            assert_eq!(parsed, format);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use chrono::{Local, NaiveDate, Weekday};
+use clap::Args;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::HoursData;
+use crate::data::{store, week};
+
+#[derive(Args)]
+pub struct GapsArgs {
+    #[arg(long, help = "Output as JSON")]
+    pub json: bool,
+}
+
+// Completed weeks since `start_date` with no entry or a zero total. The
+// in-progress week is not judged yet, and excluded break weeks are expected
+// to be empty.
+fn find_gaps(
+    data: &HoursData,
+    start_date: NaiveDate,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(NaiveDate, NaiveDate)> {
+    week::all_weeks(start_date, today, week_start)
+        .into_iter()
+        .filter(|(_, end)| *end < today)
+        .filter(
+            |(start, _)| match data.weeks.iter().find(|w| w.start == *start) {
+                Some(w) => !w.excluded && w.total() == 0.0,
+                None => true,
+            },
+        )
+        .collect()
+}
+
+pub fn run(args: GapsArgs, overrides: &ConfigOverrides) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data = store::load_lenient(&config.data_file(), config.week_start())?;
+    let today = Local::now().date_naive();
+
+    let gaps = find_gaps(
+        &data,
+        config.licensure.start_date,
+        today,
+        config.week_start(),
+    );
+
+    if args.json {
+        let json: Vec<_> = gaps
+            .iter()
+            .map(|(start, end)| {
+                serde_json::json!({
+                    "start": start.format("%Y-%m-%d").to_string(),
+                    "end": end.format("%Y-%m-%d").to_string(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else if gaps.is_empty() {
+        println!("No gaps: every completed week has hours logged.");
+    } else {
+        println!("{} week(s) with no hours logged:", gaps.len());
+        for (start, end) in &gaps {
+            println!("  {} – {}", start.format("%b %d"), end.format("%b %d, %Y"));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::model::WeekEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn find_gaps_reports_missing_and_zero_weeks() {
+        let mut logged = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        logged.direct = 5.0;
+        let empty = WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10));
+        let mut excluded = WeekEntry::new(date(2025, 2, 18), date(2025, 2, 24));
+        excluded.excluded = true;
+        let data = HoursData::from_weeks(vec![logged, empty, excluded]);
+
+        // Feb 25 – Mar 03 is in progress on Feb 27, so it is not a gap yet.
+        let gaps = find_gaps(&data, date(2025, 1, 28), date(2025, 2, 27), Weekday::Tue);
+        assert_eq!(
+            gaps,
+            vec![
+                (date(2025, 2, 4), date(2025, 2, 10)),
+                (date(2025, 2, 11), date(2025, 2, 17)),
+            ]
+        );
+    }
+}
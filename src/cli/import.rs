@@ -0,0 +1,256 @@
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, Utc, Weekday};
+use clap::Args;
+use log::info;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{Category, HoursData, WeekEntry};
+use crate::data::{store, week};
+use crate::display;
+use crate::git;
+
+#[derive(Args)]
+pub struct ImportArgs {
+    #[arg(long, help = "File to import")]
+    pub file: String,
+
+    #[arg(long, default_value = "csv", help = "Format of the file (csv)")]
+    pub format: String,
+
+    #[arg(
+        long,
+        help = "Overwrite the hours of weeks already logged instead of adding to them"
+    )]
+    pub replace: bool,
+}
+
+// Rows are `start,individual_supervision,group_supervision,direct,indirect`.
+// A header line starting with `start` and blank lines are skipped. Rows for
+// the same week are summed.
+fn parse_csv(text: &str, week_start: Weekday) -> Result<Vec<WeekEntry>> {
+    let mut weeks: Vec<WeekEntry> = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = line.trim();
+        if line.is_empty() || (idx == 0 && line.starts_with("start")) {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() != Category::ALL.len() + 1 {
+            bail!(
+                "Line {line_no}: expected 5 columns \
+                 (start,individual_supervision,group_supervision,direct,indirect), got {}",
+                fields.len()
+            );
+        }
+
+        let start = NaiveDate::parse_from_str(fields[0], "%Y-%m-%d").map_err(|_| {
+            anyhow::anyhow!(
+                "Line {line_no}: invalid date '{}': expected YYYY-MM-DD",
+                fields[0]
+            )
+        })?;
+        if !week::is_week_start(start, week_start) {
+            bail!(
+                "Line {line_no}: {start} is a {}, but weeks start on {}",
+                start.format("%A"),
+                week::weekday_name(week_start)
+            );
+        }
+        let (_, end) = week::week_containing(start, week_start);
+
+        let mut entry = WeekEntry::new(start, end);
+        for (category, field) in Category::ALL.iter().zip(&fields[1..]) {
+            let hours: f64 = field
+                .parse()
+                .ok()
+                .filter(|h: &f64| h.is_finite())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Line {line_no}: invalid {category} value '{field}'")
+                })?;
+            if hours < 0.0 {
+                bail!("Line {line_no}: {category} hours must be >= 0, got {hours}");
+            }
+            entry.set(*category, hours);
+        }
+
+        match weeks.iter_mut().find(|w| w.start == start) {
+            Some(existing) => existing.absorb(&entry),
+            None => weeks.push(entry),
+        }
+    }
+    Ok(weeks)
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct ImportReport {
+    added: usize,
+    updated: usize,
+}
+
+fn import_weeks(
+    data: &mut HoursData,
+    rows: &[WeekEntry],
+    replace: bool,
+    now: chrono::DateTime<Utc>,
+) -> ImportReport {
+    let mut report = ImportReport::default();
+    for row in rows {
+        let entry = match data.weeks.iter_mut().find(|w| w.start == row.start) {
+            Some(entry) => {
+                report.updated += 1;
+                entry
+            }
+            None => {
+                report.added += 1;
+                data.weeks.push(WeekEntry::created(row.start, row.end, now));
+                data.weeks.last_mut().unwrap()
+            }
+        };
+        if replace {
+            // The imported totals win, so a per-day breakdown would no longer add up.
+            entry.days.clear();
+            for category in Category::ALL {
+                entry.set(category, row.get(category));
+            }
+        } else {
+            for category in Category::ALL {
+                entry.add(category, row.get(category));
+            }
+        }
+        entry.touch(now);
+    }
+    report
+}
+
+pub fn run(args: ImportArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    if args.format != "csv" {
+        bail!(
+            "Invalid import format '{}'. Valid formats: csv",
+            args.format
+        );
+    }
+
+    // Loaded first so rows are checked against the configured week start day.
+    let config = Config::load(overrides)?;
+    let text =
+        fs::read_to_string(&args.file).with_context(|| format!("Failed to read {}", args.file))?;
+    let rows = parse_csv(&text, config.week_start())?;
+    if rows.is_empty() {
+        println!("Nothing to import: {} has no rows.", args.file);
+        return Ok(());
+    }
+
+    let data_file = config.data_file();
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let mut data = store::load(&data_file)?;
+    let before = data.total();
+    let report = import_weeks(&mut data, &rows, args.replace, Utc::now());
+
+    info!(
+        "Importing {} week(s) from {} ({} new, {} updated)",
+        rows.len(),
+        args.file,
+        report.added,
+        report.updated
+    );
+    store::save(
+        &data_file,
+        &data,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+
+    let verb = if args.replace { "replaced" } else { "added to" };
+    println!(
+        "Imported {} week(s) from {}: {} new, {} {verb}. Total {} -> {} hrs",
+        rows.len(),
+        args.file,
+        report.added,
+        report.updated,
+        display::hours(before),
+        display::hours(data.total())
+    );
+
+    let message = format!("Import {} week(s) from {}", rows.len(), args.file);
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        &message,
+        no_git,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parse_csv_sums_rows_and_rejects_bad_values() {
+        let text = "start,individual_supervision,group_supervision,direct,indirect\n\
+                    2025-01-28,1,0,10.5,2\n\
+                    \n\
+                    2025-01-28,0,1.5,2,0\n\
+                    2025-02-04,1,0,0,0\n";
+        let weeks = parse_csv(text, Weekday::Tue).unwrap();
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].end, date(2025, 2, 3));
+        assert_eq!(weeks[0].direct, 12.5);
+        assert_eq!(weeks[0].group_supervision, 1.5);
+        assert_eq!(weeks[1].individual_supervision, 1.0);
+
+        let err = parse_csv("2025-01-29,1,0,0,0", Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Line 1: 2025-01-29 is a Wednesday, but weeks start on Tuesday"
+        );
+        let err = parse_csv("2025-01-28,1,0,-2,0", Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "Line 1: direct hours must be >= 0, got -2");
+        let err = parse_csv("2025-01-28,1,0", Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("Line 1: expected 5 columns"), "{err}");
+    }
+
+    #[test]
+    fn import_weeks_accumulates_or_replaces() {
+        let now = Utc::now();
+        let mut existing = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        existing.add_day(date(2025, 1, 30), Category::Direct, 4.0);
+        let data = HoursData::from_weeks(vec![existing]);
+        let rows = parse_csv("2025-01-28,0,0,3,1\n2025-02-04,1,0,0,0", Weekday::Tue).unwrap();
+
+        let mut summed = data.clone();
+        let report = import_weeks(&mut summed, &rows, false, now);
+        assert_eq!(
+            report,
+            ImportReport {
+                added: 1,
+                updated: 1
+            }
+        );
+        assert_eq!(summed.weeks[0].direct, 7.0);
+        assert_eq!(summed.weeks[0].indirect, 1.0);
+        assert_eq!(summed.weeks[0].days.len(), 1);
+        assert_eq!(summed.weeks[1].start, date(2025, 2, 4));
+
+        let mut replaced = data.clone();
+        import_weeks(&mut replaced, &rows, true, now);
+        assert_eq!(replaced.weeks[0].direct, 3.0);
+        assert!(replaced.weeks[0].days.is_empty());
+    }
+}
@@ -1,10 +1,16 @@
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::Path;
 
 use anyhow::{bail, Context, Result};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Weekday};
 use clap::Args;
 
-use crate::config::{Config, DataConfig, GitConfig, LicensureConfig};
+use crate::cli::status;
+use crate::config::{
+    Config, ConfigOverrides, DataConfig, GitConfig, LicensureConfig, MilestonesConfig,
+    ReportConfig, TimerConfig,
+};
 use crate::data::model::HoursData;
 use crate::data::store;
 use crate::data::week;
@@ -13,21 +19,35 @@ use crate::ui;
 
 #[derive(Args)]
 pub struct InitArgs {
-    #[arg(long, help = "Path to data directory")]
-    pub data_dir: Option<String>,
-
     #[arg(long, help = "Git remote URL")]
     pub remote: Option<String>,
 
-    #[arg(long, help = "Licensure start date (YYYY-MM-DD, must be a Tuesday)")]
+    #[arg(
+        long,
+        help = "Licensure start date (YYYY-MM-DD, must fall on the week start day)"
+    )]
     pub start_date: Option<String>,
 
+    #[arg(
+        long,
+        value_name = "DAY",
+        value_parser = week::parse_weekday,
+        help = "Day each week starts on, e.g. Monday [default: Tuesday]"
+    )]
+    pub week_start_day: Option<Weekday>,
+
+    #[arg(
+        long,
+        help = "Clone the remote and adopt its hours.json instead of starting a new repository"
+    )]
+    pub from_remote: bool,
+
     #[arg(long, help = "Run without interactive prompts")]
     pub non_interactive: bool,
 }
 
-pub fn run(args: InitArgs, no_git: bool) -> Result<()> {
-    let config_path = Config::config_path();
+pub fn run(args: InitArgs, overrides: &ConfigOverrides, no_git: bool, quiet: bool) -> Result<()> {
+    let config_path = Config::config_path(overrides);
     if config_path.exists() {
         bail!(
             "Already initialized. Config exists at {}",
@@ -35,9 +55,13 @@ pub fn run(args: InitArgs, no_git: bool) -> Result<()> {
         );
     }
 
+    // For the start date prompt and checks below, before any config exists.
+    let week_start = args.week_start_day.unwrap_or(Weekday::Tue);
+
     let (data_dir, remote_url, start_date) = if args.non_interactive {
-        let data_dir = args
+        let data_dir = overrides
             .data_dir
+            .clone()
             .ok_or_else(|| anyhow::anyhow!("--data-dir is required in non-interactive mode"))?;
         let remote = args
             .remote
@@ -47,18 +71,25 @@ pub fn run(args: InitArgs, no_git: bool) -> Result<()> {
             .ok_or_else(|| anyhow::anyhow!("--start-date is required in non-interactive mode"))?;
         let start = NaiveDate::parse_from_str(&start_str, "%Y-%m-%d")
             .with_context(|| format!("Invalid date format: {start_str}"))?;
-        if !week::is_tuesday(start) {
-            bail!("Start date must be a Tuesday, got {start}");
+        if !week::is_week_start(start, week_start) {
+            bail!(
+                "Start date must be a {}, got {start}",
+                week::weekday_name(week_start)
+            );
         }
         (data_dir, remote, start)
     } else {
-        let data_dir = ui::input_text("Data directory", Some("~/Sync/.hours"))?
+        let data_dir = ui::input_text(
+            "Data directory",
+            Some("~/Sync/.hours"),
+            ui::TEXT_INPUT_MAX_LEN,
+        )?
+        .ok_or_else(|| anyhow::anyhow!("Cancelled"))?;
+
+        let remote = ui::input_text("Git remote URL", None, ui::TEXT_INPUT_MAX_LEN)?
             .ok_or_else(|| anyhow::anyhow!("Cancelled"))?;
 
-        let remote =
-            ui::input_text("Git remote URL", None)?.ok_or_else(|| anyhow::anyhow!("Cancelled"))?;
-
-        let start = ui::input_date("Licensure start date", true)?
+        let start = ui::input_date("Licensure start date", Some(week_start))?
             .ok_or_else(|| anyhow::anyhow!("Cancelled"))?;
 
         (data_dir, remote, start)
@@ -69,10 +100,15 @@ pub fn run(args: InitArgs, no_git: bool) -> Result<()> {
     let config = Config {
         data: DataConfig {
             directory: data_dir,
+            filename: None,
+            backups: None,
         },
         git: GitConfig {
             remote: "origin".to_string(),
             auto_push: true,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         },
         licensure: LicensureConfig {
             start_date,
@@ -80,23 +116,116 @@ pub fn run(args: InitArgs, no_git: bool) -> Result<()> {
             direct_hours_target: 1200,
             min_months: 24,
             min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: args.week_start_day,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
         },
+        milestones: MilestonesConfig::default(),
+        report: ReportConfig::default(),
+        timer: TimerConfig::default(),
+        profiles: BTreeMap::new(),
     };
 
+    let data_path = std::path::PathBuf::from(&data_dir_expanded);
+
+    if args.from_remote {
+        return adopt_remote(
+            &config,
+            &config_path,
+            &data_path,
+            &remote_url,
+            no_git,
+            quiet,
+        );
+    }
+
     config.save(&config_path)?;
-    println!("Config saved to {}", config_path.display());
+    status(
+        quiet,
+        format_args!("Config saved to {}", config_path.display()),
+    );
 
-    let data_path = std::path::PathBuf::from(&data_dir_expanded);
     fs::create_dir_all(&data_path)
         .with_context(|| format!("Failed to create data directory {}", data_path.display()))?;
 
-    let data_file = data_path.join("hours.json");
+    let data_file = data_path.join(config.data_filename());
     let data = HoursData::new();
-    store::save(&data_file, &data)?;
-    println!("Created {}", data_file.display());
+    store::save(
+        &data_file,
+        &data,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+    status(quiet, format_args!("Created {}", data_file.display()));
+
+    git::git_init_and_commit(
+        &data_path,
+        config.data_filename(),
+        &config.git,
+        &remote_url,
+        no_git,
+    )?;
+
+    status(quiet, "Initialized hours tracking.");
+    Ok(())
+}
+
+// Clones before writing any config, so a failed clone leaves nothing behind and
+// `hours init --from-remote` can simply be rerun.
+fn adopt_remote(
+    config: &Config,
+    config_path: &Path,
+    data_path: &Path,
+    remote_url: &str,
+    no_git: bool,
+    quiet: bool,
+) -> Result<()> {
+    git::git_clone(data_path, &config.git.remote, remote_url, no_git)?;
+    status(
+        quiet,
+        format_args!("Cloned {remote_url} into {}", data_path.display()),
+    );
+
+    let data_file = data_path.join(config.data_filename());
+    if data_file.exists() {
+        let data = store::load(&data_file)?;
+        status(
+            quiet,
+            format_args!(
+                "Adopted {} ({} week(s))",
+                data_file.display(),
+                data.weeks.len()
+            ),
+        );
+    } else {
+        // An empty remote gets the same first commit a fresh init would make.
+        store::save(
+            &data_file,
+            &HoursData::new(),
+            config.data_backups(),
+            config.week_start(),
+        )?;
+        status(quiet, format_args!("Created {}", data_file.display()));
+        git::git_init_and_commit(
+            data_path,
+            config.data_filename(),
+            &config.git,
+            remote_url,
+            no_git,
+        )?;
+    }
 
-    git::git_init_and_commit(&data_path, &config.git, &remote_url, no_git)?;
+    config.save(config_path)?;
+    status(
+        quiet,
+        format_args!("Config saved to {}", config_path.display()),
+    );
 
-    println!("Initialized hours tracking.");
+    status(quiet, "Initialized hours tracking.");
     Ok(())
 }
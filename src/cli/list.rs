@@ -1,114 +1,705 @@
-use anyhow::Result;
+use std::io::{BufWriter, Write};
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{Local, NaiveDate, Weekday};
 use clap::Args;
 use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, Attribute, Cell, Table};
 
-use crate::config::Config;
-use crate::data::model::Category;
-use crate::data::store;
+use crate::cli::parse_week_arg;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{Category, WeekEntry, SCHEMA_VERSION};
+use crate::data::{store, week};
+use crate::display;
 
 #[derive(Args)]
 pub struct ListArgs {
     #[arg(long, help = "Output as JSON")]
     pub json: bool,
 
+    #[arg(
+        long,
+        requires = "json",
+        help = "With --json, write compact JSON one week at a time instead of building the whole array"
+    )]
+    pub stream: bool,
+
     #[arg(long, help = "Show only the last N weeks")]
     pub last: Option<usize>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["last", "from", "to"],
+        help = "Show only the week starting on this date (YYYY-MM-DD)"
+    )]
+    pub week: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "YYYY-MM-DD",
+        help = "Show only weeks starting on or after this date"
+    )]
+    pub from: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "YYYY-MM-DD",
+        help = "Show only weeks ending on or before this date"
+    )]
+    pub to: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["last", "week"],
+        help = "Show only the most recent logged week, without the TOTALS row"
+    )]
+    pub latest: bool,
+
+    #[arg(long, help = "Omit the TOTALS row from the table")]
+    pub no_totals: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "json",
+        help = "Output stable tab-separated lines for scripts"
+    )]
+    pub porcelain: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "porcelain",
+        help = "Add a column with the cumulative total through each week"
+    )]
+    pub running_total: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "porcelain",
+        help = "Add a column with each week's direct hours as a percentage of its target"
+    )]
+    pub with_progress: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["stream", "porcelain", "running_total", "with_progress"],
+        help = "Show only this category's hours (e.g. direct, gs, indirect)"
+    )]
+    pub category: Option<String>,
+
+    #[arg(
+        long,
+        help = "Add how long ago each week was (\"last week\", \"3 weeks ago\") to the table"
+    )]
+    pub relative: bool,
+}
+
+// Stable column order: start, end, ind, grp, direct, indirect, total.
+fn porcelain_line(w: &WeekEntry) -> String {
+    [
+        w.start.format("%Y-%m-%d").to_string(),
+        w.end.format("%Y-%m-%d").to_string(),
+        display::hours(w.individual_supervision),
+        display::hours(w.group_supervision),
+        display::hours(w.direct),
+        display::hours(w.indirect),
+        display::hours(w.total()),
+    ]
+    .join("\t")
+}
+
+fn parse_range_date(flag: &str, value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| anyhow!("Invalid {flag} date '{value}': expected YYYY-MM-DD"))
+}
+
+// Either bound may be open; a week must fit entirely inside the range.
+fn in_range(week: &WeekEntry, from: Option<NaiveDate>, to: Option<NaiveDate>) -> bool {
+    from.is_none_or(|from| week.start >= from) && to.is_none_or(|to| week.end <= to)
+}
+
+// Cumulative totals in date order, returned aligned with `weeks` so they stay
+// correct however the weeks are stored or displayed.
+fn running_totals(weeks: &[WeekEntry]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..weeks.len()).collect();
+    order.sort_by_key(|&i| weeks[i].start);
+
+    let mut totals = vec![0.0; weeks.len()];
+    let mut running = 0.0;
+    for i in order {
+        running += weeks[i].total();
+        totals[i] = running;
+    }
+    totals
+}
+
+// "✓ 15.0" when the week's direct hours reached its target, "✗ 25.0" otherwise.
+fn target_cell(week: &WeekEntry, default_target: f64) -> String {
+    let mark = if week.meets_target(default_target) {
+        "✓"
+    } else {
+        "✗"
+    };
+    format!(
+        "{mark} {}",
+        display::hours(week.direct_target(default_target))
+    )
+}
+
+// Direct hours as a percentage of the week's target; None for a zero target.
+fn target_pct(week: &WeekEntry, default_target: f64) -> Option<f64> {
+    let target = week.direct_target(default_target);
+    (target > 0.0).then(|| week.direct / target * 100.0)
+}
+
+fn target_pct_cell(week: &WeekEntry, default_target: f64) -> String {
+    match target_pct(week, default_target) {
+        Some(pct) => format!("{:.0}%", display::round(pct)),
+        None => "—".to_string(),
+    }
+}
+
+// With `relative_to` (today and the week start day, for `--relative`), the
+// distance from the current week follows the dates.
+fn table_week_label(w: &WeekEntry, relative_to: Option<(NaiveDate, Weekday)>) -> String {
+    let mut label = format!(
+        "{} – {}",
+        w.start.format("%b %d"),
+        w.end.format("%b %d, %Y")
+    );
+    if let Some((today, week_start)) = relative_to {
+        label.push_str(&format!(
+            " ({})",
+            week::relative_label(w.start, today, week_start)
+        ));
+    }
+    if w.excluded {
+        label.push_str(" (excluded)");
+    }
+    label
+}
+
+// `--category`: the week and that one category's hours.
+fn render_category_table(
+    weeks: &[WeekEntry],
+    category: Category,
+    totals: bool,
+    relative_to: Option<(NaiveDate, Weekday)>,
+) -> String {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS);
+    table.set_header(vec!["Week", category.display_name()]);
+    for w in weeks {
+        table.add_row(vec![
+            table_week_label(w, relative_to),
+            display::hours(w.get(category)),
+        ]);
+    }
+    if totals {
+        let total = weeks.iter().map(|w| w.get(category)).sum::<f64>();
+        table.add_row(vec![
+            Cell::new("TOTALS").add_attribute(Attribute::Bold),
+            Cell::new(display::hours(total)).add_attribute(Attribute::Bold),
+        ]);
+    }
+    table.to_string()
+}
+
+fn category_json(weeks: &[WeekEntry], category: Category) -> Vec<serde_json::Value> {
+    weeks
+        .iter()
+        .map(|w| {
+            serde_json::json!({
+                "start": w.start.format("%Y-%m-%d").to_string(),
+                "end": w.end.format("%Y-%m-%d").to_string(),
+                "hours": w.get(category),
+            })
+        })
+        .collect()
+}
+
+// The "% of Target" column needs `default_target`, so `with_progress` is
+// ignored without one.
+pub(crate) fn render_table(
+    weeks: &[WeekEntry],
+    totals: bool,
+    running_totals: Option<&[f64]>,
+    default_target: Option<f64>,
+    with_progress: bool,
+    relative_to: Option<(NaiveDate, Weekday)>,
+) -> String {
+    let progress_target = default_target.filter(|_| with_progress);
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS);
+
+    let mut header = vec![
+        "Week",
+        Category::IndividualSupervision.display_name(),
+        Category::GroupSupervision.display_name(),
+        Category::Direct.display_name(),
+        Category::Indirect.display_name(),
+        "Total",
+    ];
+    if running_totals.is_some() {
+        header.push("Running Total");
+    }
+    if default_target.is_some() {
+        header.push("Direct Target");
+    }
+    if progress_target.is_some() {
+        header.push("% of Target");
+    }
+    table.set_header(header);
+
+    let mut total_ind = 0.0;
+    let mut total_grp = 0.0;
+    let mut total_direct = 0.0;
+    let mut total_indirect = 0.0;
+    let mut grand_total = 0.0;
+
+    for (i, w) in weeks.iter().enumerate() {
+        let mut row = vec![
+            table_week_label(w, relative_to),
+            display::hours(w.individual_supervision),
+            display::hours(w.group_supervision),
+            display::hours(w.direct),
+            display::hours(w.indirect),
+            display::hours(w.total()),
+        ];
+        if let Some(running) = running_totals {
+            row.push(display::hours(running[i]));
+        }
+        if let Some(default_target) = default_target {
+            row.push(target_cell(w, default_target));
+        }
+        if let Some(default_target) = progress_target {
+            row.push(target_pct_cell(w, default_target));
+        }
+        table.add_row(row);
+
+        if totals {
+            total_ind += w.individual_supervision;
+            total_grp += w.group_supervision;
+            total_direct += w.direct;
+            total_indirect += w.indirect;
+            grand_total += w.total();
+        }
+    }
+
+    if totals {
+        let mut row = vec![
+            Cell::new("TOTALS").add_attribute(Attribute::Bold),
+            Cell::new(display::hours(total_ind)).add_attribute(Attribute::Bold),
+            Cell::new(display::hours(total_grp)).add_attribute(Attribute::Bold),
+            Cell::new(display::hours(total_direct)).add_attribute(Attribute::Bold),
+            Cell::new(display::hours(total_indirect)).add_attribute(Attribute::Bold),
+            Cell::new(display::hours(grand_total)).add_attribute(Attribute::Bold),
+        ];
+        if running_totals.is_some() {
+            row.push(Cell::new(""));
+        }
+        if default_target.is_some() {
+            row.push(Cell::new(""));
+        }
+        if progress_target.is_some() {
+            row.push(Cell::new(""));
+        }
+        table.add_row(row);
+    }
+
+    table.to_string()
+}
+
+// The `--json` array. `hours export --format exec:` feeds the same shape to its
+// command, so keys here are part of that contract too.
+pub(crate) fn json_weeks(
+    weeks: &[WeekEntry],
+    default_target: f64,
+    running: Option<&[f64]>,
+    with_progress: bool,
+) -> Vec<serde_json::Value> {
+    weeks
+        .iter()
+        .enumerate()
+        .map(|(i, w)| json_week(w, default_target, running.map(|r| r[i]), with_progress))
+        .collect()
+}
+
+// `--json` wraps the weeks in an object carrying `schema_version`, so scripts
+// can tell when the format changes (see `SCHEMA_VERSION`).
+fn json_document(weeks: Vec<serde_json::Value>) -> serde_json::Value {
+    serde_json::json!({
+        "schema_version": SCHEMA_VERSION,
+        "weeks": weeks,
+    })
+}
+
+// The `json_document` opening, then each week as compact JSON, then the
+// closing `]}`, so only one week's object is held at a time however long the
+// history is.
+fn write_json_stream(
+    out: &mut impl Write,
+    weeks: &[WeekEntry],
+    default_target: f64,
+    running: Option<&[f64]>,
+    with_progress: bool,
+) -> Result<()> {
+    write!(out, "{{\"schema_version\":{SCHEMA_VERSION},\"weeks\":[")?;
+    for (i, w) in weeks.iter().enumerate() {
+        if i > 0 {
+            out.write_all(b",")?;
+        }
+        let obj = json_week(w, default_target, running.map(|r| r[i]), with_progress);
+        serde_json::to_writer(&mut *out, &obj)?;
+    }
+    out.write_all(b"]}\n")?;
+    out.flush()?;
+    Ok(())
 }
 
-pub fn run(args: ListArgs) -> Result<()> {
-    let config = Config::load()?;
+fn json_week(
+    w: &WeekEntry,
+    default_target: f64,
+    cumulative_total: Option<f64>,
+    with_progress: bool,
+) -> serde_json::Value {
+    let mut obj = serde_json::json!({
+        "start": w.start.format("%Y-%m-%d").to_string(),
+        "end": w.end.format("%Y-%m-%d").to_string(),
+        "individual_supervision": w.individual_supervision,
+        "group_supervision": w.group_supervision,
+        "direct": w.direct,
+        "indirect": w.indirect,
+        "total": w.total(),
+        "target": w.direct_target(default_target),
+        "target_met": w.meets_target(default_target),
+    });
+    if let Some(created_at) = w.created_at {
+        obj["created_at"] = serde_json::Value::String(created_at.to_rfc3339());
+    }
+    if let Some(updated_at) = w.updated_at {
+        obj["updated_at"] = serde_json::Value::String(updated_at.to_rfc3339());
+    }
+    if let Some(note) = &w.note {
+        obj["note"] = serde_json::Value::String(note.clone());
+    }
+    if w.excluded {
+        obj["excluded"] = serde_json::Value::Bool(true);
+    }
+    if !w.days.is_empty() {
+        obj["days"] = serde_json::json!(w.days);
+    }
+    if let Some(cumulative_total) = cumulative_total {
+        obj["cumulative_total"] = serde_json::json!(cumulative_total);
+    }
+    if with_progress {
+        obj["weekly_target_pct"] =
+            serde_json::json!(target_pct(w, default_target).map(display::round));
+    }
+    obj
+}
+
+pub fn run(args: ListArgs, overrides: &ConfigOverrides) -> Result<()> {
+    if args.last == Some(0) {
+        bail!("--last must be >= 1, got 0");
+    }
+    let category = args
+        .category
+        .as_deref()
+        .map(Category::parse_flexible)
+        .transpose()?;
+
+    // Loaded before `--week` is parsed, which checks the configured week start day.
+    let config = Config::load(overrides)?;
+    let week_start = match &args.week {
+        Some(w) => Some(parse_week_arg(w, config.week_start())?),
+        None => None,
+    };
+    let from = args
+        .from
+        .as_deref()
+        .map(|v| parse_range_date("--from", v))
+        .transpose()?;
+    let to = args
+        .to
+        .as_deref()
+        .map(|v| parse_range_date("--to", v))
+        .transpose()?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if to < from {
+            bail!("--to ({to}) is before --from ({from})");
+        }
+    }
+
     let data_file = config.data_file();
-    let data = store::load(&data_file)?;
+    let data = store::load_lenient(&data_file, config.week_start())?;
+
+    if let Some(start) = week_start {
+        if !data.weeks.iter().any(|w| w.start == start) {
+            bail!("No such week: no hours logged for the week starting {start}");
+        }
+    }
 
     if data.weeks.is_empty() {
+        if args.porcelain {
+            return Ok(());
+        }
         if args.json {
-            println!("[]");
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&json_document(Vec::new()))?
+            );
         } else {
             println!("No hours logged yet. Use `hours add` to start tracking.");
         }
         return Ok(());
     }
 
-    let weeks = if let Some(n) = args.last {
-        let len = data.weeks.len();
-        if n >= len {
-            &data.weeks[..]
+    // The date range applies first; --latest and --last then pick from it.
+    let mut selected: Vec<usize> = (0..data.weeks.len())
+        .filter(|&i| in_range(&data.weeks[i], from, to))
+        .collect();
+    if let Some(start) = week_start {
+        selected.retain(|&i| data.weeks[i].start == start);
+    } else if args.latest {
+        selected.drain(..selected.len().saturating_sub(1));
+    } else if let Some(n) = args.last {
+        selected.drain(..selected.len().saturating_sub(n));
+    }
+
+    if selected.is_empty() {
+        if args.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&json_document(Vec::new()))?
+            );
+        } else if !args.porcelain {
+            println!("No hours in that range");
+        }
+        return Ok(());
+    }
+
+    let weeks: Vec<WeekEntry> = selected.iter().map(|&i| data.weeks[i].clone()).collect();
+    let relative_to = args
+        .relative
+        .then(|| (Local::now().date_naive(), config.week_start()));
+    let weeks = weeks.as_slice();
+    // Running totals always count every earlier week, even ones filtered out.
+    let running = args.running_total.then(|| {
+        let all = running_totals(&data.weeks);
+        selected.iter().map(|&i| all[i]).collect::<Vec<f64>>()
+    });
+
+    if let Some(category) = category {
+        if args.json {
+            let json = json_document(category_json(weeks, category));
+            println!("{}", serde_json::to_string_pretty(&json)?);
         } else {
-            &data.weeks[len - n..]
+            let totals = !args.no_totals && !args.latest;
+            println!(
+                "{}",
+                render_category_table(weeks, category, totals, relative_to)
+            );
         }
+    } else if args.porcelain {
+        for w in weeks {
+            println!("{}", porcelain_line(w));
+        }
+    } else if args.stream {
+        let stdout = std::io::stdout();
+        write_json_stream(
+            &mut BufWriter::new(stdout.lock()),
+            weeks,
+            config.licensure.min_weekly_average,
+            running.as_deref(),
+            args.with_progress,
+        )?;
+    } else if args.json {
+        let json_weeks = json_weeks(
+            weeks,
+            config.licensure.min_weekly_average,
+            running.as_deref(),
+            args.with_progress,
+        );
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json_document(json_weeks))?
+        );
     } else {
-        &data.weeks[..]
-    };
+        println!(
+            "{}",
+            render_table(
+                weeks,
+                !args.no_totals && !args.latest,
+                running.as_deref(),
+                Some(config.licensure.min_weekly_average),
+                args.with_progress,
+                relative_to
+            )
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn running_totals_follow_date_order() {
+        let week = |day: u32, direct: f64| {
+            let start = NaiveDate::from_ymd_opt(2025, 2, day).unwrap();
+            let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+            entry.direct = direct;
+            entry
+        };
+        let weeks = vec![week(11, 3.0), week(4, 2.0), week(18, 1.5)];
+        assert_eq!(running_totals(&weeks), vec![5.0, 2.0, 6.5]);
+        assert!(running_totals(&[]).is_empty());
+    }
+
+    #[test]
+    fn category_table_and_json_show_one_category() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut first = WeekEntry::new(start, start + chrono::Duration::days(6));
+        first.direct = 10.0;
+        first.indirect = 4.0;
+        let next = start + chrono::Duration::days(7);
+        let mut second = WeekEntry::new(next, next + chrono::Duration::days(6));
+        second.direct = 2.5;
+        second.excluded = true;
+        let weeks = vec![first, second];
+
+        let table = render_category_table(&weeks, Category::Direct, true, None);
+        assert!(table.contains("Direct"), "{table}");
+        assert!(!table.contains("Indirect"), "{table}");
+        assert!(
+            table.contains("Feb 04 – Feb 10, 2025 (excluded)"),
+            "{table}"
+        );
+        assert!(table.contains("TOTALS"), "{table}");
+        assert!(table.contains("12.5"), "{table}");
+        assert!(!render_category_table(&weeks, Category::Direct, false, None).contains("TOTALS"));
+        let relative =
+            render_category_table(&weeks, Category::Direct, false, Some((next, Weekday::Tue)));
+        assert!(
+            relative.contains("Jan 28 – Feb 03, 2025 (last week)"),
+            "{relative}"
+        );
+        assert!(
+            relative.contains("Feb 04 – Feb 10, 2025 (current) (excluded)"),
+            "{relative}"
+        );
+
+        let json = category_json(&weeks, Category::Indirect);
+        assert_eq!(
+            json[0],
+            serde_json::json!({"start": "2025-01-28", "end": "2025-02-03", "hours": 4.0})
+        );
+        assert_eq!(json[1]["hours"], 0.0);
+    }
+
+    #[test]
+    fn porcelain_line_columns() {
+        let mut entry = WeekEntry::new(
+            NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
+        );
+        entry.individual_supervision = 1.0;
+        entry.direct = 14.25;
+        entry.indirect = 6.0;
+        assert_eq!(
+            porcelain_line(&entry),
+            "2025-01-28\t2025-02-03\t1.0\t0.0\t14.3\t6.0\t21.3"
+        );
+    }
+
+    #[test]
+    fn target_cell_uses_week_override() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = 10.0;
+        assert_eq!(target_cell(&entry, 15.0), "✗ 15.0");
+
+        entry.target = Some(5.0);
+        assert_eq!(target_cell(&entry, 15.0), "✓ 5.0");
+
+        entry.target = Some(25.0);
+        assert_eq!(target_cell(&entry, 5.0), "✗ 25.0");
+    }
 
-    if args.json {
-        let json_weeks: Vec<serde_json::Value> = weeks
-            .iter()
-            .map(|w| {
-                serde_json::json!({
-                    "start": w.start.format("%Y-%m-%d").to_string(),
-                    "end": w.end.format("%Y-%m-%d").to_string(),
-                    "individual_supervision": w.individual_supervision,
-                    "group_supervision": w.group_supervision,
-                    "direct": w.direct,
-                    "indirect": w.indirect,
-                    "total": w.total(),
-                })
+    #[test]
+    fn json_stream_matches_built_array() {
+        let weeks: Vec<WeekEntry> = (0..3)
+            .map(|i| {
+                let start =
+                    NaiveDate::from_ymd_opt(2025, 1, 28).unwrap() + chrono::Duration::weeks(i);
+                let mut w = WeekEntry::new(start, start + chrono::Duration::days(6));
+                w.direct = i as f64 * 2.5;
+                w
             })
             .collect();
-        println!("{}", serde_json::to_string_pretty(&json_weeks)?);
-    } else {
-        let mut table = Table::new();
-        table
-            .load_preset(UTF8_FULL)
-            .apply_modifier(UTF8_ROUND_CORNERS);
-
-        table.set_header(vec![
-            "Week",
-            Category::IndividualSupervision.display_name(),
-            Category::GroupSupervision.display_name(),
-            Category::Direct.display_name(),
-            Category::Indirect.display_name(),
-            "Total",
-        ]);
+        let running = running_totals(&weeks);
 
-        let mut total_ind = 0.0;
-        let mut total_grp = 0.0;
-        let mut total_direct = 0.0;
-        let mut total_indirect = 0.0;
+        let mut out = Vec::new();
+        write_json_stream(&mut out, &weeks, 15.0, Some(&running), true).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(
+            text.starts_with("{\"schema_version\":1,\"weeks\":[{") && text.ends_with("}]}\n"),
+            "{text}"
+        );
+        assert!(!text.contains(' '), "not compact: {text}");
 
-        for w in weeks {
-            let week_label = format!(
-                "{} – {}",
-                w.start.format("%b %d"),
-                w.end.format("%b %d, %Y")
-            );
-            table.add_row(vec![
-                week_label,
-                format!("{:.1}", w.individual_supervision),
-                format!("{:.1}", w.group_supervision),
-                format!("{:.1}", w.direct),
-                format!("{:.1}", w.indirect),
-                format!("{:.1}", w.total()),
-            ]);
+        let streamed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let built = json_document(json_weeks(&weeks, 15.0, Some(&running), true));
+        assert_eq!(streamed, built);
 
-            total_ind += w.individual_supervision;
-            total_grp += w.group_supervision;
-            total_direct += w.direct;
-            total_indirect += w.indirect;
-        }
+        let mut out = Vec::new();
+        write_json_stream(&mut out, &[], 15.0, None, false).unwrap();
+        assert_eq!(out, b"{\"schema_version\":1,\"weeks\":[]}\n");
+    }
 
-        let grand_total = total_ind + total_grp + total_direct + total_indirect;
-        table.add_row(vec![
-            Cell::new("TOTALS").add_attribute(Attribute::Bold),
-            Cell::new(format!("{total_ind:.1}")).add_attribute(Attribute::Bold),
-            Cell::new(format!("{total_grp:.1}")).add_attribute(Attribute::Bold),
-            Cell::new(format!("{total_direct:.1}")).add_attribute(Attribute::Bold),
-            Cell::new(format!("{total_indirect:.1}")).add_attribute(Attribute::Bold),
-            Cell::new(format!("{grand_total:.1}")).add_attribute(Attribute::Bold),
-        ]);
+    #[test]
+    fn in_range_requires_whole_week_inside_bounds() {
+        let week = WeekEntry::new(
+            NaiveDate::from_ymd_opt(2025, 2, 4).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 10).unwrap(),
+        );
+        let d = |m, day| NaiveDate::from_ymd_opt(2025, m, day);
+        assert!(in_range(&week, None, None));
+        assert!(in_range(&week, d(2, 4), d(2, 10)));
+        assert!(!in_range(&week, d(2, 5), None));
+        assert!(!in_range(&week, None, d(2, 9)));
+    }
 
-        println!("{table}");
+    #[test]
+    fn parse_range_date_names_bad_value() {
+        let err = parse_range_date("--from", "2025-13-01").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid --from date '2025-13-01': expected YYYY-MM-DD"
+        );
     }
 
-    Ok(())
+    #[test]
+    fn target_pct_against_default_and_override() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = 12.0;
+        entry.indirect = 8.0;
+        // Indirect hours don't count toward the direct target.
+        assert_eq!(target_pct(&entry, 15.0), Some(80.0));
+        assert_eq!(target_pct_cell(&entry, 15.0), "80%");
+
+        entry.target = Some(8.0);
+        assert_eq!(target_pct_cell(&entry, 15.0), "150%");
+
+        entry.target = Some(0.0);
+        assert_eq!(target_pct(&entry, 15.0), None);
+        assert_eq!(target_pct_cell(&entry, 15.0), "—");
+    }
 }
@@ -0,0 +1,120 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use clap::Args;
+
+use crate::cli::week_label;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{HoursData, MergeConflict, MergeReport, MergeStrategy};
+use crate::data::store;
+use crate::git;
+use crate::ui;
+
+#[derive(Args)]
+pub struct MergeArgs {
+    #[arg(help = "Path to the other hours.json to merge in")]
+    pub path: String,
+
+    #[arg(
+        long,
+        default_value = "sum",
+        help = "How to resolve weeks present in both files (sum, prefer-theirs, prefer-mine)"
+    )]
+    pub strategy: String,
+
+    #[arg(long, help = "Skip the confirmation prompt for prefer-* strategies")]
+    pub yes: bool,
+}
+
+fn describe_conflict(conflict: &MergeConflict) -> String {
+    format!(
+        "  conflict  {}  mine {:.1}, theirs {:.1} -> {:.1} hrs",
+        week_label(&conflict.merged),
+        conflict.mine.total(),
+        conflict.theirs.total(),
+        conflict.merged.total()
+    )
+}
+
+fn print_report(report: &MergeReport, merged: &HoursData) {
+    for start in &report.added {
+        if let Some(entry) = merged.weeks.iter().find(|w| w.start == *start) {
+            println!(
+                "  added     {}  {:.1} hrs",
+                week_label(entry),
+                entry.total()
+            );
+        }
+    }
+    for conflict in &report.conflicts {
+        println!("{}", describe_conflict(conflict));
+    }
+}
+
+pub fn run(args: MergeArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let strategy: MergeStrategy = args.strategy.parse()?;
+
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let data = store::load(&data_file)?;
+
+    let other_path = Path::new(&args.path);
+    let other = store::read(other_path)?;
+    store::validate(&other, config.week_start())
+        .map_err(|e| anyhow!("{} failed validation: {e}", args.path))?;
+
+    let mut merged = data.clone();
+    let report = merged.merge(&other, strategy);
+
+    if report.added.is_empty() && report.conflicts.is_empty() {
+        println!(
+            "Nothing to merge: {} has no new or differing weeks.",
+            args.path
+        );
+        return Ok(());
+    }
+
+    println!("Merging {} using '{strategy}':", args.path);
+    print_report(&report, &merged);
+
+    if strategy != MergeStrategy::Sum
+        && !report.conflicts.is_empty()
+        && !args.yes
+        && !ui::confirm(&format!(
+            "Apply '{strategy}'? Conflicting values from the other side will be discarded"
+        ))?
+    {
+        println!("Merge cancelled. No changes written.");
+        return Ok(());
+    }
+
+    store::save(
+        &data_file,
+        &merged,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+
+    println!(
+        "Merged {} new week(s) and {} conflict(s).",
+        report.added.len(),
+        report.conflicts.len()
+    );
+
+    let message = format!(
+        "Merge {} ({strategy}): {} added, {} conflicts",
+        args.path,
+        report.added.len(),
+        report.conflicts.len()
+    );
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        &message,
+        no_git,
+    )?;
+
+    Ok(())
+}
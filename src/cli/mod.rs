@@ -1,11 +1,36 @@
 mod add;
+mod backup;
+mod changes;
+mod config_cmd;
+mod dedupe;
+mod doctor;
 mod edit;
 mod export;
+mod gaps;
+mod import;
 mod init;
 mod list;
+mod merge;
+mod remove;
+mod scaffold;
+mod stats;
+mod status;
 mod summary;
+mod undo;
+mod verify;
 
+use std::path::PathBuf;
+use std::process::Command as Process;
+use std::time::Instant;
+
+use anyhow::{bail, Result};
+use chrono::{NaiveDate, Weekday};
 use clap::{Parser, Subcommand};
+use log::{debug, error, info};
+
+use crate::config::ConfigOverrides;
+use crate::data::model::WeekEntry;
+use crate::data::{store, week};
 
 #[derive(Parser)]
 #[command(name = "hours", version, about = "Track counseling licensure hours")]
@@ -15,6 +40,52 @@ pub struct Cli {
 
     #[arg(long, global = true, help = "Disable git operations")]
     pub no_git: bool,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Skip confirmation messages from add, edit, init, and export; errors still print"
+    )]
+    pub quiet: bool,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Data directory to use instead of the configured one (for init: where to create it)"
+    )]
+    pub data_dir: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Config file to use instead of a discovered .hoursrc/hours.toml or the global config"
+    )]
+    pub config: Option<PathBuf>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        help = "Use the targets from [profiles.NAME] in the config instead of [licensure]"
+    )]
+    pub profile: Option<String>,
+
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Append debug logs of each step (config, data, save, git) to this file"
+    )]
+    pub log_file: Option<PathBuf>,
+
+    #[arg(
+        long,
+        global = true,
+        help = "If the data file does not load, restore it from its newest readable backup first"
+    )]
+    pub recover: bool,
 }
 
 #[derive(Subcommand)]
@@ -22,18 +93,236 @@ pub enum Command {
     Init(init::InitArgs),
     Add(add::AddArgs),
     Edit(edit::EditArgs),
+    Remove(remove::RemoveArgs),
+    Undo(undo::UndoArgs),
+    Backup(backup::BackupArgs),
+    Status(status::StatusArgs),
     List(list::ListArgs),
     Summary(summary::SummaryArgs),
+    Gaps(gaps::GapsArgs),
+    Stats(stats::StatsArgs),
     Export(export::ExportArgs),
+    Verify(verify::VerifyArgs),
+    Changes(changes::ChangesArgs),
+    Import(import::ImportArgs),
+    Merge(merge::MergeArgs),
+    Dedupe(dedupe::DedupeArgs),
+    Scaffold(scaffold::ScaffoldArgs),
+    Config(config_cmd::ConfigArgs),
+    Doctor(doctor::DoctorArgs),
+}
+
+pub fn run(cli: Cli) -> Result<()> {
+    crate::logging::init(cli.log_file.as_deref())?;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    info!("hours {}", args.join(" "));
+    let started = Instant::now();
+
+    let result = dispatch(cli);
+    match &result {
+        Ok(()) => debug!("Finished in {:?}", started.elapsed()),
+        Err(e) => error!("Failed after {:?}: {e:#}", started.elapsed()),
+    }
+    result
 }
 
-pub fn run(cli: Cli) -> anyhow::Result<()> {
+fn dispatch(cli: Cli) -> Result<()> {
+    // For init, --data-dir says where to create the data directory instead.
+    let overrides = ConfigOverrides {
+        config_path: cli.config.clone(),
+        profile: cli.profile.clone(),
+        data_dir: cli.data_dir.clone(),
+    };
+
+    if cli.recover && !matches!(cli.command, Command::Init(_)) {
+        recover_data_file(&overrides, cli.quiet)?;
+    }
+
+    let overrides = &overrides;
     match cli.command {
-        Command::Init(args) => init::run(args, cli.no_git),
-        Command::Add(args) => add::run(args, cli.no_git),
-        Command::Edit(args) => edit::run(args, cli.no_git),
-        Command::List(args) => list::run(args),
-        Command::Summary(args) => summary::run(args),
-        Command::Export(args) => export::run(args, cli.no_git),
+        Command::Init(args) => init::run(args, overrides, cli.no_git, cli.quiet),
+        Command::Add(args) => add::run(args, overrides, cli.no_git, cli.quiet),
+        Command::Edit(args) => edit::run(args, overrides, cli.no_git, cli.quiet),
+        Command::Remove(args) => remove::run(args, overrides, cli.no_git),
+        Command::Undo(args) => undo::run(args, overrides, cli.no_git),
+        Command::Backup(args) => backup::run(args, overrides, cli.no_git),
+        Command::Status(args) => status::run(args, overrides, cli.no_git),
+        Command::List(args) => list::run(args, overrides),
+        Command::Summary(args) => summary::run(args, overrides),
+        Command::Gaps(args) => gaps::run(args, overrides),
+        Command::Stats(args) => stats::run(args, overrides),
+        Command::Export(args) => export::run(args, overrides, cli.no_git, cli.quiet),
+        Command::Verify(args) => verify::run(args, overrides),
+        Command::Changes(args) => changes::run(args, overrides, cli.no_git),
+        Command::Import(args) => import::run(args, overrides, cli.no_git),
+        Command::Merge(args) => merge::run(args, overrides, cli.no_git),
+        Command::Dedupe(args) => dedupe::run(args, overrides, cli.no_git),
+        Command::Scaffold(args) => scaffold::run(args, overrides, cli.no_git),
+        Command::Config(args) => config_cmd::run(args, overrides, cli.no_git),
+        Command::Doctor(args) => doctor::run(args, overrides, cli.no_git),
+    }
+}
+
+fn recover_data_file(overrides: &ConfigOverrides, quiet: bool) -> Result<()> {
+    let config = crate::config::Config::load(overrides)?;
+    let data_file = config.data_file();
+    match store::recover(&data_file, config.week_start())? {
+        Some(source) => eprintln!(
+            "Recovered {} from {}",
+            data_file.display(),
+            source.display()
+        ),
+        None => status(
+            quiet,
+            format_args!("{} loads fine; nothing to recover", data_file.display()),
+        ),
+    }
+    Ok(())
+}
+
+// A human-facing confirmation line, skipped with `--quiet`. Requested output
+// (tables, JSON, recaps) is printed directly instead.
+pub fn status(quiet: bool, line: impl std::fmt::Display) {
+    if !quiet {
+        println!("{line}");
+    }
+}
+
+// `command` run through `sh -c` (`cmd /C` on Windows), for the user-supplied
+// editor, timer, and export commands.
+pub(crate) fn shell(command: &str) -> Process {
+    #[cfg(windows)]
+    let (program, flag) = ("cmd", "/C");
+    #[cfg(not(windows))]
+    let (program, flag) = ("sh", "-c");
+    let mut process = Process::new(program);
+    process.args([flag, command]);
+    process
+}
+
+pub fn week_label(entry: &WeekEntry) -> String {
+    format!(
+        "{} – {}",
+        entry.start.format("%b %d"),
+        entry.end.format("%b %d, %Y")
+    )
+}
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+fn looks_like_weekday(input: &str) -> bool {
+    let lower = input.trim().to_lowercase();
+    lower.len() >= 2 && WEEKDAY_NAMES.iter().any(|day| day.starts_with(&lower))
+}
+
+pub fn parse_week_arg(input: &str, week_start: Weekday) -> Result<NaiveDate> {
+    let start_day = week::weekday_name(week_start);
+    if looks_like_weekday(input) {
+        bail!(
+            "Invalid week '{input}': weekday names are not accepted. \
+             Pass the week's {start_day} start date as YYYY-MM-DD, e.g. --week 2025-01-28"
+        );
+    }
+
+    let date = match NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d") {
+        Ok(date) => date,
+        Err(_) => bail!(
+            "Invalid date format: {input}. \
+             Expected the week's {start_day} start date as YYYY-MM-DD, e.g. --week 2025-01-28"
+        ),
+    };
+
+    if !week::is_week_start(date, week_start) {
+        let (containing, _) = week::week_containing(date, week_start);
+        bail!(
+            "Week start date must be a {start_day}, got {date} ({}). \
+             The week containing it starts on {containing}",
+            date.format("%A")
+        );
+    }
+
+    Ok(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_week_arg_accepts_tuesday() {
+        assert_eq!(
+            parse_week_arg("2025-01-28", Weekday::Tue).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_week_arg_weekday_name_gets_guidance() {
+        for input in ["mon", "Tue", "WEDNESDAY", "fri"] {
+            let err = parse_week_arg(input, Weekday::Tue).unwrap_err().to_string();
+            assert!(err.contains("weekday names are not accepted"), "{err}");
+            assert!(err.contains("YYYY-MM-DD"), "{err}");
+            assert!(err.contains("2025-01-28"), "{err}");
+        }
+    }
+
+    #[test]
+    fn parse_week_arg_malformed_date() {
+        for input in ["2025/01/28", "01-28-2025", "next week", "2025-13-01", ""] {
+            let err = parse_week_arg(input, Weekday::Tue).unwrap_err().to_string();
+            assert!(err.starts_with("Invalid date format"), "{err}");
+            assert!(err.contains("e.g. --week 2025-01-28"), "{err}");
+        }
+    }
+
+    #[test]
+    fn parse_week_arg_non_tuesday_suggests_week_start() {
+        let err = parse_week_arg("2025-01-30", Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("must be a Tuesday"), "{err}");
+        assert!(err.contains("Thursday"), "{err}");
+        assert!(err.contains("starts on 2025-01-28"), "{err}");
+
+        let err = parse_week_arg("2025-01-28", Weekday::Mon)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("must be a Monday"), "{err}");
+        assert!(err.contains("starts on 2025-01-27"), "{err}");
+    }
+
+    fn parse_edit(args: &[&str]) -> edit::EditArgs {
+        let cli = Cli::try_parse_from([&["hours", "edit"], args].concat()).unwrap();
+        match cli.command {
+            Command::Edit(args) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn edit_category_aliases() {
+        let args = parse_edit(&["--ind", "1", "--grp", "2", "-d", "3", "--indir", "4"]);
+        assert_eq!(args.individual_supervision, Some(1.0));
+        assert_eq!(args.group_supervision, Some(2.0));
+        assert_eq!(args.direct, Some(3.0));
+        assert_eq!(args.indirect, Some(4.0));
+
+        let args = parse_edit(&["--ind-sup", "1.5", "--gs", "2.5", "--dir", "3.5"]);
+        assert_eq!(args.individual_supervision, Some(1.5));
+        assert_eq!(args.group_supervision, Some(2.5));
+        assert_eq!(args.direct, Some(3.5));
+        assert_eq!(args.indirect, None);
+
+        let args = parse_edit(&["--is", "2", "--indirect", "1"]);
+        assert_eq!(args.individual_supervision, Some(2.0));
+        assert_eq!(args.indirect, Some(1.0));
     }
 }
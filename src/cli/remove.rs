@@ -0,0 +1,124 @@
+use anyhow::{bail, Result};
+use chrono::{Local, NaiveDate};
+use clap::Args;
+use log::info;
+
+use crate::cli::parse_week_arg;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{HoursData, WeekEntry};
+use crate::data::{store, week};
+use crate::display;
+use crate::git;
+use crate::ui;
+use crate::ui::PromptResult;
+
+#[derive(Args)]
+pub struct RemoveArgs {
+    #[arg(
+        long,
+        help = "Start date of the week to delete (YYYY-MM-DD); required with --non-interactive"
+    )]
+    pub week: Option<String>,
+
+    #[arg(long, help = "Run without interactive prompts or confirmation")]
+    pub non_interactive: bool,
+}
+
+fn remove_week(data: &mut HoursData, start: NaiveDate) -> Result<WeekEntry> {
+    match data.weeks.iter().position(|w| w.start == start) {
+        Some(idx) => Ok(data.weeks.remove(idx)),
+        None => bail!("No week starting {start} to remove"),
+    }
+}
+
+pub fn run(args: RemoveArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    // Loaded first so `--week` is checked against the configured week start day.
+    let config = Config::load(overrides)?;
+    let week_start = match &args.week {
+        Some(w) => Some(parse_week_arg(w, config.week_start())?),
+        None if args.non_interactive => bail!("--week is required with --non-interactive"),
+        None => None,
+    };
+
+    let data_file = config.data_file();
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let mut data = store::load(&data_file)?;
+
+    let week_start = match week_start {
+        Some(start) => start,
+        None => {
+            if data.weeks.is_empty() {
+                bail!("No weeks logged yet, nothing to remove");
+            }
+            // Only logged weeks can be removed, so offer just those.
+            let weeks: Vec<(NaiveDate, NaiveDate)> =
+                data.weeks.iter().map(|w| (w.start, w.end)).collect();
+            let (current_start, _) =
+                week::current_week(Local::now().date_naive(), config.week_start());
+            match ui::select_week(&weeks, &data, current_start)? {
+                PromptResult::Value(start) => start,
+                PromptResult::Back | PromptResult::Exit => return Ok(()),
+            }
+        }
+    };
+
+    let removed = remove_week(&mut data, week_start)?;
+
+    if !args.non_interactive {
+        let prompt = format!(
+            "Remove week of {week_start} ({} hrs)?",
+            display::hours(removed.total())
+        );
+        if !ui::confirm(&prompt)? {
+            println!("Nothing removed.");
+            return Ok(());
+        }
+    }
+
+    info!(
+        "Removing week of {week_start} ({} hrs)",
+        display::hours(removed.total())
+    );
+    store::save(
+        &data_file,
+        &data,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+    println!("Removed week of {week_start}");
+
+    let message = format!("Remove week of {week_start}");
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        &message,
+        no_git,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn remove_week_drops_only_the_match() {
+        let mut data = HoursData::from_weeks(vec![
+            WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3)),
+            WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10)),
+        ]);
+        let removed = remove_week(&mut data, date(2025, 1, 28)).unwrap();
+        assert_eq!(removed.start, date(2025, 1, 28));
+        assert_eq!(data.weeks.len(), 1);
+        assert_eq!(data.weeks[0].start, date(2025, 2, 4));
+
+        let err = remove_week(&mut data, date(2025, 1, 28)).unwrap_err();
+        assert_eq!(err.to_string(), "No week starting 2025-01-28 to remove");
+    }
+}
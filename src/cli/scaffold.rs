@@ -0,0 +1,171 @@
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDate, Utc, Weekday};
+use clap::Args;
+use log::info;
+
+use crate::cli::parse_week_arg;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{HoursData, WeekEntry};
+use crate::data::{store, week};
+use crate::git;
+
+#[derive(Args)]
+pub struct ScaffoldArgs {
+    #[arg(
+        long,
+        help = "Start date of the first week (YYYY-MM-DD, on the week start day)"
+    )]
+    pub from: String,
+
+    #[arg(
+        long,
+        help = "Start date of the last week, inclusive (YYYY-MM-DD, on the week start day)"
+    )]
+    pub to: String,
+
+    #[arg(long, help = "Report the weeks that would be created without saving")]
+    pub dry_run: bool,
+}
+
+// Adds a zero-hour entry for every week from `from` through `to` that has no
+// entry yet, returning the new weeks' start dates. Existing weeks are untouched.
+fn scaffold_weeks(
+    data: &mut HoursData,
+    from: NaiveDate,
+    to: NaiveDate,
+    now: DateTime<Utc>,
+    week_start: Weekday,
+) -> Vec<NaiveDate> {
+    let mut created = Vec::new();
+    for (start, end) in week::all_weeks(from, to, week_start) {
+        if !data.weeks.iter().any(|w| w.start == start) {
+            data.weeks.push(WeekEntry::created(start, end, now));
+            created.push(start);
+        }
+    }
+    created
+}
+
+pub fn run(args: ScaffoldArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    // Loaded first so the dates are checked against the configured week start day.
+    let config = Config::load(overrides)?;
+    let from = parse_week_arg(&args.from, config.week_start())?;
+    let to = parse_week_arg(&args.to, config.week_start())?;
+    if to < from {
+        bail!("--to ({to}) is before --from ({from})");
+    }
+
+    let data_file = config.data_file();
+    git::pull_before_write(&config.data_dir(), &config.git, no_git)?;
+    let mut data = store::load(&data_file)?;
+
+    let created = scaffold_weeks(&mut data, from, to, Utc::now(), config.week_start());
+    if created.is_empty() {
+        println!("Every week from {from} to {to} already exists.");
+        return Ok(());
+    }
+
+    for start in &created {
+        println!("  week of {start}");
+    }
+
+    if args.dry_run {
+        println!(
+            "Dry run: {} empty week(s) would be created. No changes written.",
+            created.len()
+        );
+        return Ok(());
+    }
+
+    info!(
+        "Scaffolding {} empty week(s) from {from} to {to}",
+        created.len()
+    );
+    store::save(
+        &data_file,
+        &data,
+        config.data_backups(),
+        config.week_start(),
+    )?;
+    println!("Created {} empty week(s).", created.len());
+
+    let message = format!(
+        "Scaffold {} empty week(s) from {from} to {to}",
+        created.len()
+    );
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        &message,
+        no_git,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn scaffold_fills_only_missing_weeks() {
+        let mut existing = WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10));
+        existing.direct = 12.0;
+        let mut data = HoursData::from_weeks(vec![existing]);
+
+        let created = scaffold_weeks(
+            &mut data,
+            date(2025, 1, 28),
+            date(2025, 2, 18),
+            Utc::now(),
+            Weekday::Tue,
+        );
+        assert_eq!(
+            created,
+            vec![date(2025, 1, 28), date(2025, 2, 11), date(2025, 2, 18)]
+        );
+        assert_eq!(data.weeks.len(), 4);
+        assert!(store::validate(&data, Weekday::Tue).is_ok());
+
+        let kept = data
+            .weeks
+            .iter()
+            .find(|w| w.start == date(2025, 2, 4))
+            .unwrap();
+        assert_eq!(kept.direct, 12.0);
+        assert!(data
+            .weeks
+            .iter()
+            .filter(|w| w.start != date(2025, 2, 4))
+            .all(|w| w.total() == 0.0 && w.entries == 0));
+
+        // Running it again is a no-op.
+        assert!(scaffold_weeks(
+            &mut data,
+            date(2025, 1, 28),
+            date(2025, 2, 18),
+            Utc::now(),
+            Weekday::Tue
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn scaffold_single_week_range() {
+        let mut data = HoursData::new();
+        let created = scaffold_weeks(
+            &mut data,
+            date(2025, 1, 28),
+            date(2025, 1, 28),
+            Utc::now(),
+            Weekday::Tue,
+        );
+        assert_eq!(created, vec![date(2025, 1, 28)]);
+        assert_eq!(data.weeks[0].end, date(2025, 2, 3));
+    }
+}
@@ -0,0 +1,194 @@
+use anyhow::Result;
+use chrono::{Local, NaiveDate};
+use clap::Args;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{HoursData, SCHEMA_VERSION};
+use crate::data::{store, week};
+use crate::display;
+
+#[derive(Args)]
+pub struct StatsArgs {
+    #[arg(long, help = "Output as JSON")]
+    pub json: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct WeeklyStats {
+    weeks: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+    median: f64,
+    best: NaiveDate,
+    worst: NaiveDate,
+}
+
+// Weekly totals over the weeks with any hours logged. Ties for best and
+// worst go to the earliest week. `None` when nothing is logged.
+fn weekly_stats(data: &HoursData) -> Option<WeeklyStats> {
+    let mut totals: Vec<(NaiveDate, f64)> = data
+        .weeks
+        .iter()
+        .map(|w| (w.start, w.total()))
+        .filter(|(_, total)| *total > 0.0)
+        .collect();
+    if totals.is_empty() {
+        return None;
+    }
+    totals.sort_by_key(|(start, _)| *start);
+
+    let (mut best, mut max) = totals[0];
+    let (mut worst, mut min) = totals[0];
+    for &(start, total) in &totals[1..] {
+        if total > max {
+            (best, max) = (start, total);
+        }
+        if total < min {
+            (worst, min) = (start, total);
+        }
+    }
+
+    let mut sorted: Vec<f64> = totals.iter().map(|(_, total)| *total).collect();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    };
+
+    Some(WeeklyStats {
+        weeks: totals.len(),
+        min,
+        max,
+        mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        median,
+        best,
+        worst,
+    })
+}
+
+fn stats_json(stats: Option<&WeeklyStats>, streak: u32) -> serde_json::Value {
+    let date = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    match stats {
+        Some(s) => serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "weeks": s.weeks,
+            "min": display::round(s.min),
+            "max": display::round(s.max),
+            "mean": display::round(s.mean),
+            "median": display::round(s.median),
+            "best_week": date(s.best),
+            "worst_week": date(s.worst),
+            "streak": streak,
+        }),
+        None => serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "weeks": 0,
+            "min": null,
+            "max": null,
+            "mean": null,
+            "median": null,
+            "best_week": null,
+            "worst_week": null,
+            "streak": streak,
+        }),
+    }
+}
+
+fn stats_lines(stats: &WeeklyStats, streak: u32) -> Vec<String> {
+    let week_of = |d: NaiveDate| d.format("%b %d, %Y");
+    vec![
+        format!("Weeks with hours: {}", stats.weeks),
+        format!(
+            "Minimum:          {:>6} hrs (week of {})",
+            display::hours(stats.min),
+            week_of(stats.worst)
+        ),
+        format!(
+            "Maximum:          {:>6} hrs (week of {})",
+            display::hours(stats.max),
+            week_of(stats.best)
+        ),
+        format!("Mean:             {:>6} hrs", display::hours(stats.mean)),
+        format!("Median:           {:>6} hrs", display::hours(stats.median)),
+        format!("Current streak:   {streak} week(s)"),
+    ]
+}
+
+pub fn run(args: StatsArgs, overrides: &ConfigOverrides) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data = store::load_lenient(&config.data_file(), config.week_start())?;
+    let today = Local::now().date_naive();
+
+    let stats = weekly_stats(&data);
+    let streak = week::logging_streak(&data, today, config.week_start());
+
+    if args.json {
+        let json = stats_json(stats.as_ref(), streak);
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        match &stats {
+            Some(stats) => {
+                for line in stats_lines(stats, streak) {
+                    println!("{line}");
+                }
+            }
+            None => println!("No hours logged yet."),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::model::WeekEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn week(start: NaiveDate, direct: f64) -> WeekEntry {
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = direct;
+        entry
+    }
+
+    #[test]
+    fn weekly_stats_skip_empty_weeks() {
+        let data = HoursData::from_weeks(vec![
+            week(date(2025, 2, 4), 10.0),
+            week(date(2025, 1, 28), 4.0),
+            week(date(2025, 2, 11), 0.0),
+            week(date(2025, 2, 18), 10.0),
+            week(date(2025, 2, 25), 6.0),
+        ]);
+        let stats = weekly_stats(&data).unwrap();
+        assert_eq!(
+            stats,
+            WeeklyStats {
+                weeks: 4,
+                min: 4.0,
+                max: 10.0,
+                mean: 7.5,
+                median: 8.0,
+                best: date(2025, 2, 4),
+                worst: date(2025, 1, 28),
+            }
+        );
+
+        let odd = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 1.0),
+            week(date(2025, 2, 4), 9.0),
+            week(date(2025, 2, 11), 3.0),
+        ]);
+        assert_eq!(weekly_stats(&odd).unwrap().median, 3.0);
+
+        let empty = HoursData::from_weeks(vec![week(date(2025, 1, 28), 0.0)]);
+        assert_eq!(weekly_stats(&empty), None);
+        assert_eq!(stats_json(None, 0)["min"], serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use anyhow::Result;
+use clap::Args;
+
+use crate::config::{Config, ConfigOverrides, GitConfig};
+use crate::data::store;
+use crate::git;
+
+#[derive(Args)]
+pub struct StatusArgs {}
+
+fn git_lines(data_dir: &Path, config: &GitConfig, no_git: bool) -> Result<Vec<String>> {
+    if git::is_git_disabled(no_git) {
+        return Ok(vec![
+            "Git:          disabled (--no-git or HOURS_NO_GIT=1)".to_string()
+        ]);
+    }
+    if !git::git_binary_exists() {
+        return Ok(vec!["Git:          not installed".to_string()]);
+    }
+    if !git::is_git_repo(data_dir) {
+        return Ok(vec![format!(
+            "Git:          {} is not a git repository",
+            data_dir.display()
+        )]);
+    }
+
+    let tree = if git::working_tree_clean(data_dir)? {
+        "clean"
+    } else {
+        "uncommitted changes"
+    };
+    let branch = git::remote_branch(data_dir, config);
+    let tracking = format!("{}/{branch}", config.remote);
+    let sync = match git::ahead_behind(data_dir, &config.remote, &branch)? {
+        None => format!("{tracking} not fetched yet"),
+        Some((0, 0)) => format!("up to date with {tracking} (as of the last fetch)"),
+        Some((ahead, behind)) => {
+            format!("{ahead} ahead, {behind} behind {tracking} (as of the last fetch)")
+        }
+    };
+    Ok(vec![
+        format!("Working tree: {tree}"),
+        format!("Remote:       {sync}"),
+    ])
+}
+
+pub fn run(_args: StatusArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    let data = store::load_lenient(&data_file, config.week_start())?;
+
+    println!("Config:       {}", Config::config_path(overrides).display());
+    println!("Data file:    {}", data_file.display());
+    println!("Start date:   {}", config.licensure.start_date);
+    println!("Weeks logged: {}", data.weeks.len());
+    for line in git_lines(&config.data_dir(), &config.git, no_git)? {
+        println!("{line}");
+    }
+    Ok(())
+}
@@ -1,229 +1,816 @@
-use anyhow::Result;
-use chrono::Local;
+use std::fmt;
+use std::io::IsTerminal;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use chrono::{Local, NaiveDate, Weekday};
 use clap::Args;
+use crossterm::style::Stylize;
 
-use crate::config::Config;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::{Category, HoursData, WeekEntry, SCHEMA_VERSION};
+use crate::data::progress::{self, Progress};
 use crate::data::store;
 use crate::data::week;
+use crate::display;
+use crate::ui::chart;
 
 #[derive(Args)]
 pub struct SummaryArgs {
     #[arg(long, help = "Output as JSON")]
     pub json: bool,
+
+    #[arg(long, conflicts_with = "json", help = "Output a one-line CSV snapshot")]
+    pub csv: bool,
+
+    #[arg(
+        long,
+        default_value = "text",
+        conflicts_with_all = ["json", "csv"],
+        help = "Output format (text, json, csv, all)"
+    )]
+    pub format: String,
+
+    #[arg(long, help = "Omit the CSV header row")]
+    pub no_header: bool,
+
+    #[arg(long, help = "Disable colored output")]
+    pub no_color: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["json", "csv"],
+        help = "Append a chart of cumulative hours against the target pace"
+    )]
+    pub graph: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["json", "csv"],
+        help = "Show the inputs and formula behind each number"
+    )]
+    pub explain: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["csv", "graph", "explain"],
+        help = "Report only the most recent logged week against its weekly target"
+    )]
+    pub latest: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["csv", "latest"],
+        help = "Project the standing N weeks out at --forecast-rate direct hours per week"
+    )]
+    pub forecast_weeks: Option<u32>,
+
+    #[arg(
+        long,
+        value_name = "HOURS",
+        requires = "forecast_weeks",
+        value_parser = display::parse_hours,
+        help = "Direct hours per week for --forecast-weeks (default: current weekly average)"
+    )]
+    pub forecast_rate: Option<f64>,
+
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = ["csv", "latest"],
+        help = "Also report the weekly average over only the last N calendar weeks"
+    )]
+    pub window: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryFormat {
+    Text,
+    Json,
+    Csv,
+    All,
 }
 
-fn months_between(start: chrono::NaiveDate, end: chrono::NaiveDate) -> u32 {
-    if end < start {
-        return 0;
+impl FromStr for SummaryFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(SummaryFormat::Text),
+            "json" => Ok(SummaryFormat::Json),
+            "csv" => Ok(SummaryFormat::Csv),
+            "all" => Ok(SummaryFormat::All),
+            _ => Err(anyhow::anyhow!(
+                "Invalid summary format '{}'. Valid formats: text, json, csv, all",
+                s
+            )),
+        }
     }
-    let year_diff = end.year() - start.year();
-    let month_diff = end.month() as i32 - start.month() as i32;
-    let mut months = year_diff * 12 + month_diff;
-    if end.day() < start.day() {
-        months -= 1;
+}
+
+impl fmt::Display for SummaryFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SummaryFormat::Text => "text",
+            SummaryFormat::Json => "json",
+            SummaryFormat::Csv => "csv",
+            SummaryFormat::All => "all",
+        };
+        write!(f, "{name}")
     }
-    months.max(0) as u32
 }
 
-use chrono::Datelike;
+const BAR_WIDTH: usize = 30;
+const METRIC_BAR_WIDTH: usize = 20;
 
-pub fn run(args: SummaryArgs) -> Result<()> {
-    let config = Config::load()?;
-    let data_file = config.data_file();
-    let data = store::load(&data_file)?;
+fn progress_bar(percentage: f64, width: usize) -> String {
+    let pct = display::round(percentage).clamp(0.0, 100.0);
+    let filled = ((pct / 100.0) * width as f64).round() as usize;
+    let mut bar = "=".repeat(filled);
+    if filled < width {
+        bar.push('>');
+        bar.push_str(&" ".repeat(width - filled - 1));
+    }
+    bar
+}
 
-    let today = Local::now().date_naive();
-    let start_date = config.licensure.start_date;
+// Red below a third of the target, yellow below two thirds, green after.
+fn colored_bar(percentage: f64, width: usize, color: bool) -> String {
+    let bar = progress_bar(percentage, width);
+    if !color {
+        return bar;
+    }
+    let pct = display::round(percentage);
+    if pct < 33.0 {
+        bar.red().to_string()
+    } else if pct < 66.0 {
+        bar.yellow().to_string()
+    } else {
+        bar.green().to_string()
+    }
+}
 
-    let total_hours: f64 = data.weeks.iter().map(|w| w.total()).sum::<f64>() + 0.0;
-    let direct_hours: f64 = data.weeks.iter().map(|w| w.direct).sum::<f64>() + 0.0;
+// The first four lines `Progress` prints are the metrics; each gets a bar,
+// padded so the bars line up. Any lines after them are left as they are.
+fn metric_lines(progress: &Progress, color: bool) -> Vec<String> {
+    let percentages = [
+        progress.total_hours.percentage,
+        progress.direct_hours.percentage,
+        progress.months.percentage,
+        progress.weekly_average.percentage,
+    ];
+    let text = progress.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    let width = lines
+        .iter()
+        .take(percentages.len())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| match percentages.get(i) {
+            Some(pct) => format!(
+                "{line:<width$} [{}]",
+                colored_bar(*pct, METRIC_BAR_WIDTH, color)
+            ),
+            None => line.to_string(),
+        })
+        .collect()
+}
 
-    let months = months_between(start_date, today);
+const CSV_HEADER: &str = "date,total_hours,direct_hours,total_pct,direct_pct,weekly_average";
 
-    let (current_week_start, _) = week::current_week(today);
-    let weeks_elapsed = if current_week_start >= start_date {
-        ((current_week_start - start_date).num_days() / 7) + 1
-    } else {
-        1
-    };
+fn csv_row(
+    today: NaiveDate,
+    total_hours: f64,
+    direct_hours: f64,
+    total_pct: f64,
+    direct_pct: f64,
+    weekly_average: f64,
+) -> String {
+    format!(
+        "{},{:.1},{:.1},{:.1},{:.1},{:.1}",
+        today.format("%Y-%m-%d"),
+        display::round(total_hours),
+        display::round(direct_hours),
+        display::round(total_pct),
+        display::round(direct_pct),
+        display::round(weekly_average)
+    )
+}
 
-    let weekly_average = if weeks_elapsed > 0 {
-        direct_hours / weeks_elapsed as f64
+fn render_csv(today: NaiveDate, progress: &Progress, header: bool) -> String {
+    let row = csv_row(
+        today,
+        progress.total_hours.current,
+        progress.direct_hours.current,
+        progress.total_hours.percentage,
+        progress.direct_hours.percentage,
+        progress.weekly_average.current,
+    );
+    if header {
+        format!("{CSV_HEADER}\n{row}")
     } else {
-        0.0
-    };
+        row
+    }
+}
 
-    let total_target = config.licensure.total_hours_target;
-    let direct_target = config.licensure.direct_hours_target;
-    let min_months = config.licensure.min_months;
-    let min_weekly_avg = config.licensure.min_weekly_average;
+fn render_json(progress: &Progress) -> Result<String> {
+    Ok(serde_json::to_string_pretty(progress)?)
+}
 
-    let total_pct = if total_target > 0 {
-        total_hours / total_target as f64 * 100.0
-    } else {
-        0.0
-    };
-    let direct_pct = if direct_target > 0 {
-        direct_hours / direct_target as f64 * 100.0
+pub(crate) fn render_text(
+    data: &HoursData,
+    progress: &Progress,
+    color: bool,
+    explain: bool,
+) -> String {
+    let bar = colored_bar(progress.overall_percentage, BAR_WIDTH, color);
+
+    let mut lines = vec![
+        "Licensure Progress".to_string(),
+        "═".repeat(50),
+        String::new(),
+        format!(
+            "Overall: [{bar}] {:.0}%",
+            display::round(progress.overall_percentage)
+        ),
+        String::new(),
+    ];
+    lines.extend(metric_lines(progress, color));
+    lines.extend([String::new(), "By category:".to_string()]);
+    for category in &progress.by_category {
+        let mut line = format!(
+            "  {:<24}{:>8.1}",
+            category.category.long_name(),
+            display::round(category.current)
+        );
+        if let (Some(target), Some(pct)) = (category.target, category.percentage) {
+            line.push_str(&format!(" / {target:<6} ({:>5.1}%)", display::round(pct)));
+        }
+        lines.push(line);
+    }
+    lines.push(String::new());
+    if explain {
+        lines.push("How these are computed:".to_string());
+        lines.extend(progress.explanations().iter().map(|l| format!("  {l}")));
+        lines.push(String::new());
+    }
+    if let Some(line) = pace_line(progress) {
+        lines.push(line);
+    }
+    lines.push(format!("Weeks logged: {}", progress.weeks_logged));
+    if progress.weekly_targets.completed > 0 {
+        lines.push(format!(
+            "Weekly targets met: {} / {} completed week(s)",
+            progress.weekly_targets.met, progress.weekly_targets.completed
+        ));
+    }
+    if progress.entries.count > 0 {
+        lines.push(format!(
+            "Average per entry: {} hrs ({} entries)",
+            display::hours(progress.entries.average_hours),
+            progress.entries.count
+        ));
+    }
+
+    if let (Some(first), Some(last)) = (data.weeks.first(), data.weeks.last()) {
+        lines.push(format!(
+            "Date range: {} – {}",
+            first.start.format("%b %d, %Y"),
+            last.end.format("%b %d, %Y")
+        ));
+    }
+    if let Some(forecast) = &progress.forecast {
+        lines.push(String::new());
+        lines.push(forecast.to_string());
+    }
+
+    lines.join("\n")
+}
+
+// Averages and pace keep counting weeks past the last one logged, so a stale
+// file reads as a falling average rather than missing data.
+fn stale_warning(
+    latest_start: NaiveDate,
+    today: NaiveDate,
+    threshold: u32,
+    week_start: Weekday,
+) -> Option<String> {
+    if threshold == 0 {
+        return None;
+    }
+    let (current_start, _) = week::current_week(today, week_start);
+    let gap = (current_start - latest_start).num_weeks();
+    (gap > threshold as i64).then(|| {
+        format!(
+            "Warning: the latest logged week ({}) is {gap} weeks behind the current week. \
+             Averages and pace assume those weeks had no hours; log them to keep the numbers accurate.",
+            latest_start.format("%b %d, %Y")
+        )
+    })
+}
+
+// How far the total is from the straight-line target pace, in hours.
+fn pace_line(progress: &Progress) -> Option<String> {
+    let total = &progress.total_hours;
+    if total.target > 0 && total.current >= total.target as f64 {
+        return Some("Total hours target met".to_string());
+    }
+    if progress.as_of < progress.start_date {
+        return Some(format!(
+            "Pace tracking starts {}",
+            progress.start_date.format("%b %d, %Y")
+        ));
+    }
+    let delta = display::round(progress.pace_delta?);
+    Some(if delta > 0.0 {
+        format!("You are {} hours ahead of pace", display::hours(delta))
+    } else if delta < 0.0 {
+        format!("You are {} hours behind pace", display::hours(-delta))
     } else {
-        0.0
-    };
-    let months_pct = if min_months > 0 {
-        months as f64 / min_months as f64 * 100.0
+        "You are right on pace".to_string()
+    })
+}
+
+// The most recent week's hours and its standing against its direct target.
+fn render_latest_text(week: &WeekEntry, default_target: f64) -> String {
+    let target = week.direct_target(default_target);
+    let status = if week.meets_target(default_target) {
+        "met".to_string()
     } else {
-        0.0
+        format!("{} short", display::hours(target - week.direct))
     };
-    let avg_pct = if min_weekly_avg > 0.0 {
-        weekly_average / min_weekly_avg * 100.0
-    } else {
-        0.0
+
+    let mut lines = vec![
+        format!(
+            "Latest week: {} – {}",
+            week.start.format("%b %d"),
+            week.end.format("%b %d, %Y")
+        ),
+        String::new(),
+    ];
+    for category in Category::ALL {
+        lines.push(format!(
+            "{:<10}{:>8}",
+            category.display_name(),
+            display::hours(week.get(category))
+        ));
+    }
+    lines.push(format!(
+        "{:<10}{:>8}",
+        "Total",
+        display::hours(week.total())
+    ));
+    lines.push(String::new());
+    lines.push(format!(
+        "Direct target: {} ({status})",
+        display::hours(target)
+    ));
+    lines.join("\n")
+}
+
+// With nothing logged, only `schema_version` is present.
+fn render_latest_json(week: Option<&WeekEntry>, default_target: f64) -> Result<String> {
+    let Some(week) = week else {
+        let json = serde_json::json!({ "schema_version": SCHEMA_VERSION });
+        return Ok(serde_json::to_string_pretty(&json)?);
     };
+    let target = week.direct_target(default_target);
+    let json = serde_json::json!({
+        "schema_version": SCHEMA_VERSION,
+        "start": week.start,
+        "end": week.end,
+        "individual_supervision": display::round(week.individual_supervision),
+        "group_supervision": display::round(week.group_supervision),
+        "direct": display::round(week.direct),
+        "indirect": display::round(week.indirect),
+        "total": display::round(week.total()),
+        "target": target,
+        "target_met": week.meets_target(default_target),
+        "shortfall": display::round((target - week.direct).max(0.0)),
+    });
+    Ok(serde_json::to_string_pretty(&json)?)
+}
 
-    let weeks_logged = data.weeks.iter().filter(|w| w.total() > 0.0).count();
+fn render_all(
+    data: &HoursData,
+    progress: &Progress,
+    today: NaiveDate,
+    header: bool,
+) -> Result<String> {
+    let sections = [
+        ("text", render_text(data, progress, false, false)),
+        ("json", render_json(progress)?),
+        ("csv", render_csv(today, progress, header)),
+    ];
+    Ok(sections
+        .iter()
+        .map(|(label, body)| format!("```{label}\n{body}\n```"))
+        .collect::<Vec<_>>()
+        .join("\n\n"))
+}
 
-    if args.json {
-        let mut json = serde_json::json!({
-            "total_hours": {
-                "current": round1(total_hours),
-                "target": total_target,
-                "percentage": round1(total_pct),
-            },
-            "direct_hours": {
-                "current": round1(direct_hours),
-                "target": direct_target,
-                "percentage": round1(direct_pct),
-            },
-            "months": {
-                "current": months,
-                "target": min_months,
-                "percentage": round1(months_pct),
-            },
-            "weekly_average": {
-                "current": round1(weekly_average),
-                "target": min_weekly_avg,
-                "percentage": round1(avg_pct),
-            },
-            "weeks_logged": weeks_logged,
-            "start_date": start_date.format("%Y-%m-%d").to_string(),
-        });
-
-        if let Some(last) = data.weeks.last() {
-            json["latest_week_start"] =
-                serde_json::Value::String(last.start.format("%Y-%m-%d").to_string());
-            json["latest_week_end"] =
-                serde_json::Value::String(last.end.format("%Y-%m-%d").to_string());
-        }
+// Running total at the end of each week from the start date through the
+// current week; weeks with nothing logged repeat the previous total.
+fn cumulative_totals(
+    data: &HoursData,
+    start_date: NaiveDate,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<f64> {
+    let mut running = 0.0;
+    week::all_weeks(start_date, today, week_start)
+        .into_iter()
+        .map(|(start, _)| {
+            running += data
+                .weeks
+                .iter()
+                .filter(|w| w.start == start)
+                .map(|w| w.total())
+                .sum::<f64>();
+            running
+        })
+        .collect()
+}
 
-        println!("{}", serde_json::to_string_pretty(&json)?);
+pub fn run(args: SummaryArgs, overrides: &ConfigOverrides) -> Result<()> {
+    let format = if args.json {
+        SummaryFormat::Json
+    } else if args.csv {
+        SummaryFormat::Csv
     } else {
-        println!("Licensure Progress");
-        println!("{}", "═".repeat(50));
-        println!();
-        println!(
-            "Total supervised hours: {:>8.1} / {:<6} ({:>5.1}%)",
-            total_hours, total_target, total_pct
-        );
-        println!(
-            "Direct client hours:   {:>8.1} / {:<6} ({:>5.1}%)",
-            direct_hours, direct_target, direct_pct
-        );
-        println!(
-            "Months of experience:  {:>8}   / {:>4}   ({:>5.1}%)",
-            months, min_months, months_pct
-        );
-        println!(
-            "Weekly average:        {:>8.1} / {:>6.1} ({:>5.1}%)",
-            weekly_average, min_weekly_avg, avg_pct
-        );
-        println!();
-        println!("Weeks logged: {weeks_logged}");
-
-        if !data.weeks.is_empty() {
-            let first = &data.weeks[0];
-            let last = data.weeks.last().unwrap();
-            println!(
-                "Date range: {} – {}",
-                first.start.format("%b %d, %Y"),
-                last.end.format("%b %d, %Y")
-            );
+        args.format.parse()?
+    };
+    if args.no_header && !matches!(format, SummaryFormat::Csv | SummaryFormat::All) {
+        bail!("--no-header only applies to CSV output");
+    }
+    if (args.graph || args.explain) && format != SummaryFormat::Text {
+        bail!("--graph and --explain only apply to text output");
+    }
+    if args.latest && !matches!(format, SummaryFormat::Text | SummaryFormat::Json) {
+        bail!("--latest only applies to text and JSON output");
+    }
+    if args.forecast_weeks.is_some() && format == SummaryFormat::Csv {
+        bail!("--forecast-weeks does not apply to CSV output");
+    }
+    if args.forecast_weeks == Some(0) {
+        bail!("--forecast-weeks must be >= 1, got 0");
+    }
+    if args.window.is_some() && format == SummaryFormat::Csv {
+        bail!("--window does not apply to CSV output");
+    }
+    if args.window == Some(0) {
+        bail!("--window must be >= 1, got 0");
+    }
+    if let Some(rate) = args.forecast_rate {
+        if rate < 0.0 {
+            bail!("--forecast-rate must be >= 0, got {rate}");
         }
     }
 
-    Ok(())
-}
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    let data = store::load_lenient(&data_file, config.week_start())?;
 
-fn round1(val: f64) -> f64 {
-    let r = (val * 10.0).round() / 10.0;
-    if r == 0.0 {
-        0.0
-    } else {
-        r
+    if args.latest {
+        let default_target = config.licensure.min_weekly_average;
+        let output = match (data.weeks.last(), format) {
+            (week, SummaryFormat::Json) => render_latest_json(week, default_target)?,
+            (Some(week), _) => render_latest_text(week, default_target),
+            (None, _) => "No hours logged yet. Use `hours add` to start tracking.".to_string(),
+        };
+        println!("{output}");
+        return Ok(());
     }
+
+    let today = Local::now().date_naive();
+    if let Some(latest) = data.weeks.last() {
+        if let Some(warning) = stale_warning(
+            latest.start,
+            today,
+            config.report.stale_after_weeks,
+            config.week_start(),
+        ) {
+            eprintln!("{warning}");
+        }
+    }
+    let mut progress = Progress::compute(&data, &config.licensure, today);
+    if let Some(weeks) = args.window {
+        progress.set_window(progress::window_average(
+            &data,
+            &config.licensure,
+            today,
+            weeks,
+        ));
+    }
+    if let Some(weeks) = args.forecast_weeks {
+        let rate = args
+            .forecast_rate
+            .unwrap_or(progress.weekly_average.current);
+        progress.forecast = Some(progress.forecast(&config.licensure, weeks, rate));
+    }
+
+    let output = match format {
+        SummaryFormat::Text => {
+            let color = !args.no_color
+                && std::env::var_os("NO_COLOR").is_none()
+                && std::io::stdout().is_terminal();
+            let mut text = render_text(&data, &progress, color, args.explain);
+            if args.graph {
+                let width = crossterm::terminal::size()
+                    .map(|(cols, _)| cols as usize)
+                    .unwrap_or(80);
+                let values = cumulative_totals(
+                    &data,
+                    config.licensure.start_date,
+                    today,
+                    config.week_start(),
+                );
+                text.push_str("\n\n");
+                text.push_str(&chart::render_cumulative(
+                    &values,
+                    config.licensure.total_hours_target as f64,
+                    progress::target_pace(&config.licensure),
+                    width,
+                    color,
+                ));
+            }
+            text
+        }
+        SummaryFormat::Json => render_json(&progress)?,
+        SummaryFormat::Csv => render_csv(today, &progress, !args.no_header),
+        SummaryFormat::All => render_all(&data, &progress, today, !args.no_header)?,
+    };
+    println!("{output}");
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::NaiveDate;
 
     fn date(y: i32, m: u32, d: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(y, m, d).unwrap()
     }
 
     #[test]
-    fn test_months_between_same_date() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 1, 28)), 0);
+    fn test_stale_warning_after_threshold() {
+        let latest = date(2025, 1, 28);
+        // Feb 25 starts the fourth week after Jan 28.
+        let warning = stale_warning(latest, date(2025, 2, 27), 3, Weekday::Tue).unwrap();
+        assert!(
+            warning.contains("(Jan 28, 2025) is 4 weeks behind"),
+            "{warning}"
+        );
+        assert!(stale_warning(latest, date(2025, 2, 24), 3, Weekday::Tue).is_none());
+        assert!(stale_warning(latest, date(2025, 1, 30), 3, Weekday::Tue).is_none());
+        assert!(stale_warning(latest, date(2026, 1, 27), 0, Weekday::Tue).is_none());
     }
 
-    #[test]
-    fn test_months_between_one_month() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 2, 28)), 1);
+    fn latest_week(direct: f64, target: Option<f64>) -> WeekEntry {
+        let mut week = WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10));
+        week.group_supervision = 1.5;
+        week.direct = direct;
+        week.target = target;
+        week
     }
 
     #[test]
-    fn test_months_between_partial_month() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 2, 27)), 0);
+    fn test_latest_text_reports_shortfall() {
+        let text = render_latest_text(&latest_week(12.0, None), 15.0);
+        assert!(
+            text.starts_with("Latest week: Feb 04 – Feb 10, 2025"),
+            "{text}"
+        );
+        assert!(text.contains("Direct        12.0"), "{text}");
+        assert!(text.contains("Total         13.5"), "{text}");
+        assert!(text.ends_with("Direct target: 15.0 (3.0 short)"), "{text}");
     }
 
     #[test]
-    fn test_months_between_several_months() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 6, 28)), 5);
+    fn test_latest_uses_week_target() {
+        let text = render_latest_text(&latest_week(12.0, Some(10.0)), 15.0);
+        assert!(text.ends_with("Direct target: 10.0 (met)"), "{text}");
+
+        let json: serde_json::Value = serde_json::from_str(
+            &render_latest_json(Some(&latest_week(12.0, Some(20.0))), 15.0).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["start"], "2025-02-04");
+        assert_eq!(json["target"], 20.0);
+        assert_eq!(json["target_met"], false);
+        assert_eq!(json["shortfall"], 8.0);
+
+        let empty: serde_json::Value =
+            serde_json::from_str(&render_latest_json(None, 15.0).unwrap()).unwrap();
+        assert_eq!(
+            empty,
+            serde_json::json!({ "schema_version": SCHEMA_VERSION })
+        );
     }
 
     #[test]
-    fn test_months_between_across_years() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2027, 1, 28)), 24);
+    fn test_csv_row_columns() {
+        let row = csv_row(date(2025, 3, 4), 247.04, 156.0, 8.2346, 13.0, 9.75);
+        assert_eq!(row, "2025-03-04,247.0,156.0,8.2,13.0,9.8");
+        assert_eq!(
+            row.split(',').count(),
+            CSV_HEADER.split(',').count(),
+            "row and header must have the same number of columns"
+        );
     }
 
     #[test]
-    fn test_months_between_end_before_start() {
-        assert_eq!(months_between(date(2025, 6, 1), date(2025, 1, 1)), 0);
+    fn test_overall_bar_bounds() {
+        assert_eq!(progress_bar(0.0, 10), ">         ");
+        assert_eq!(progress_bar(42.0, 10), "====>     ");
+        assert_eq!(progress_bar(100.0, 10), "==========");
+        assert_eq!(progress_bar(140.0, 10), "==========");
+        assert_eq!(progress_bar(-5.0, 10).len(), 10);
     }
 
     #[test]
-    fn test_round1() {
-        assert!((round1(8.233) - 8.2).abs() < f64::EPSILON);
-        assert!((round1(102.75) - 102.8).abs() < f64::EPSILON);
-        assert!((round1(0.0) - 0.0).abs() < f64::EPSILON);
+    fn test_metric_lines_align_bars_and_color_by_threshold() {
+        let config = crate::config::LicensureConfig {
+            start_date: date(2025, 1, 28),
+            total_hours_target: 3000,
+            direct_hours_target: 1200,
+            min_months: 24,
+            min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: None,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
+        };
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 9.0;
+        let data = HoursData::from_weeks(vec![entry]);
+        let progress = Progress::compute(&data, &config, date(2025, 2, 3));
+
+        let lines = metric_lines(&progress, false);
+        assert_eq!(lines.len(), 4);
+        let column = lines[0].find('[').unwrap();
+        assert!(
+            lines.iter().all(|l| l.find('[') == Some(column)),
+            "{lines:?}"
+        );
+        assert!(lines[3].ends_with(&format!("[{}]", progress_bar(60.0, 20))));
+
+        assert_eq!(colored_bar(10.0, 10, false), progress_bar(10.0, 10));
+        assert_eq!(
+            colored_bar(10.0, 10, true),
+            progress_bar(10.0, 10).red().to_string()
+        );
+        assert_eq!(
+            colored_bar(60.0, 10, true),
+            progress_bar(60.0, 10).yellow().to_string()
+        );
+        assert_eq!(
+            colored_bar(66.0, 10, true),
+            progress_bar(66.0, 10).green().to_string()
+        );
     }
 
     #[test]
-    fn test_round1_negative_zero_normalized() {
-        let result = round1(-0.0);
-        assert!(result.is_sign_positive(), "round1(-0.0) should be +0.0");
-        assert!((result - 0.0).abs() < f64::EPSILON);
+    fn test_summary_format_from_str() {
+        for format in [
+            SummaryFormat::Text,
+            SummaryFormat::Json,
+            SummaryFormat::Csv,
+            SummaryFormat::All,
+        ] {
+            assert_eq!(format.to_string().parse::<SummaryFormat>().unwrap(), format);
+        }
+        assert!("yaml".parse::<SummaryFormat>().is_err());
+    }
 
-        let result = round1(-0.0000001);
+    #[test]
+    fn test_render_all_contains_every_section() {
+        let progress = Progress::compute(
+            &HoursData::new(),
+            &crate::config::LicensureConfig {
+                start_date: date(2025, 1, 28),
+                total_hours_target: 3000,
+                direct_hours_target: 1200,
+                min_months: 24,
+                min_weekly_average: 15.0,
+                min_monthly_hours: None,
+                months_inclusive: false,
+                week_start_day: None,
+                individual_supervision_target: None,
+                group_supervision_target: None,
+                indirect_target: None,
+                supervisor_name: None,
+                supervisor_license: None,
+            },
+            date(2025, 3, 4),
+        );
+        let out = render_all(&HoursData::new(), &progress, date(2025, 3, 4), true).unwrap();
+        for marker in ["```text\n", "```json\n", "```csv\n"] {
+            assert!(out.contains(marker), "missing {marker:?} in:\n{out}");
+        }
+        assert!(out.contains("Licensure Progress"));
+        assert!(out.contains(CSV_HEADER));
         assert!(
-            result.is_sign_positive(),
-            "round1(-0.0000001) should be +0.0"
+            !out.contains('\u{1b}'),
+            "all mode must not emit color codes"
+        );
+    }
+
+    #[test]
+    fn test_cumulative_totals_fill_gaps() {
+        let mut data = HoursData::new();
+        for (start, hours) in [(date(2025, 1, 28), 10.0), (date(2025, 2, 11), 5.0)] {
+            let mut entry =
+                crate::data::model::WeekEntry::new(start, start + chrono::Duration::days(6));
+            entry.direct = hours;
+            data.weeks.push(entry);
+        }
+        let totals = cumulative_totals(&data, date(2025, 1, 28), date(2025, 2, 19), Weekday::Tue);
+        assert_eq!(totals, vec![10.0, 10.0, 15.0, 15.0]);
+    }
+
+    #[test]
+    fn test_target_pace() {
+        let mut config = crate::config::LicensureConfig {
+            start_date: date(2025, 1, 28),
+            total_hours_target: 1040,
+            direct_hours_target: 1200,
+            min_months: 12,
+            min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: None,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
+        };
+        assert_eq!(progress::target_pace(&config), 20.0);
+        config.min_months = 0;
+        assert_eq!(progress::target_pace(&config), 0.0);
+    }
+
+    fn pace_progress(hours: f64, today: NaiveDate) -> Progress {
+        let mut week = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        week.direct = hours;
+        let data = HoursData::from_weeks(vec![week]);
+        let config = crate::config::LicensureConfig {
+            start_date: date(2025, 1, 28),
+            total_hours_target: 1040,
+            direct_hours_target: 1200,
+            min_months: 12,
+            min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: None,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
+        };
+        Progress::compute(&data, &config, today)
+    }
+
+    #[test]
+    fn test_pace_line() {
+        let ahead = pace_progress(43.5, date(2025, 2, 4));
+        assert_eq!(
+            pace_line(&ahead).as_deref(),
+            Some("You are 23.5 hours ahead of pace")
+        );
+        let behind = pace_progress(12.0, date(2025, 2, 4));
+        assert_eq!(
+            pace_line(&behind).as_deref(),
+            Some("You are 8.0 hours behind pace")
+        );
+        let even = pace_progress(20.0, date(2025, 2, 4));
+        assert_eq!(pace_line(&even).as_deref(), Some("You are right on pace"));
+        let early = pace_progress(0.0, date(2025, 1, 20));
+        assert_eq!(
+            pace_line(&early).as_deref(),
+            Some("Pace tracking starts Jan 28, 2025")
         );
+        let done = pace_progress(1040.0, date(2025, 6, 1));
+        assert_eq!(pace_line(&done).as_deref(), Some("Total hours target met"));
     }
 
     #[test]
     fn test_empty_sum_normalization() {
         let empty: Vec<f64> = vec![];
-        let sum: f64 = empty.iter().sum::<f64>() + 0.0;
+        let sum = crate::data::model::normalize_hours(empty.iter().sum());
         assert!(
             sum.is_sign_positive(),
             "normalized empty sum should be +0.0"
@@ -0,0 +1,43 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use log::info;
+
+use crate::cli::changes;
+use crate::config::{Config, ConfigOverrides};
+use crate::data::store;
+use crate::display;
+use crate::git;
+
+#[derive(Args)]
+pub struct UndoArgs {}
+
+pub fn run(_args: UndoArgs, overrides: &ConfigOverrides, no_git: bool) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    let current = store::load(&data_file)?;
+
+    let Some(restored) = store::restore_backup(&data_file, config.week_start())? else {
+        bail!("Nothing to undo");
+    };
+    info!("Restored {} from its newest backup", data_file.display());
+
+    println!(
+        "Restored the previous version of {}: total {} -> {} hrs",
+        config.data_filename(),
+        display::hours(current.total()),
+        display::hours(restored.total())
+    );
+    for change in restored.changes_since(&current) {
+        println!("{}", changes::describe(&change));
+    }
+
+    git::git_sync(
+        &config.data_dir(),
+        config.data_filename(),
+        &config.git,
+        "Undo last change",
+        no_git,
+    )?;
+
+    Ok(())
+}
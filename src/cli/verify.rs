@@ -0,0 +1,172 @@
+use std::fmt;
+
+use anyhow::{bail, Result};
+use chrono::{Local, NaiveDate, Weekday};
+use clap::Args;
+
+use crate::config::{Config, ConfigOverrides};
+use crate::data::model::HoursData;
+use crate::data::{store, week};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[arg(
+        long,
+        help = "Also flag zero-hour weeks (warning) and weeks outside the tracking period (error)"
+    )]
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{label}: {}", self.message)
+    }
+}
+
+fn strict_findings(
+    data: &HoursData,
+    start_date: NaiveDate,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<Finding> {
+    let (current_start, _) = week::current_week(today, week_start);
+    let mut findings = Vec::new();
+
+    for w in &data.weeks {
+        if w.start < start_date || w.start > current_start {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!(
+                    "Week starting {} is outside the tracking period ({} – {})",
+                    w.start, start_date, current_start
+                ),
+            });
+        } else if w.total() == 0.0 {
+            findings.push(Finding {
+                severity: Severity::Warning,
+                message: format!("Week starting {} has zero hours logged", w.start),
+            });
+        }
+    }
+
+    findings
+}
+
+pub fn run(args: VerifyArgs, overrides: &ConfigOverrides) -> Result<()> {
+    let config = Config::load(overrides)?;
+    let data_file = config.data_file();
+    let data = store::load(&data_file)?;
+
+    store::validate(&data, config.week_start())?;
+
+    let findings = if args.strict {
+        let today = Local::now().date_naive();
+        strict_findings(
+            &data,
+            config.licensure.start_date,
+            today,
+            config.week_start(),
+        )
+    } else {
+        Vec::new()
+    };
+
+    for finding in &findings {
+        eprintln!("{finding}");
+    }
+
+    let errors = findings
+        .iter()
+        .filter(|f| f.severity == Severity::Error)
+        .count();
+    if errors > 0 {
+        bail!("Verification failed with {errors} error(s)");
+    }
+
+    println!(
+        "Verified {} weeks in {}",
+        data.weeks.len(),
+        data_file.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::model::WeekEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn week_with_direct(start: NaiveDate, direct: f64) -> WeekEntry {
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = direct;
+        entry
+    }
+
+    #[test]
+    fn strict_no_findings_for_clean_data() {
+        let data = HoursData::from_weeks(vec![
+            week_with_direct(date(2025, 1, 28), 5.0),
+            week_with_direct(date(2025, 2, 4), 3.0),
+        ]);
+        let findings = strict_findings(&data, date(2025, 1, 28), date(2025, 2, 6), Weekday::Tue);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn strict_zero_week_is_warning() {
+        let data = HoursData::from_weeks(vec![
+            week_with_direct(date(2025, 1, 28), 5.0),
+            week_with_direct(date(2025, 2, 4), 0.0),
+        ]);
+        let findings = strict_findings(&data, date(2025, 1, 28), date(2025, 2, 6), Weekday::Tue);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("2025-02-04"));
+    }
+
+    #[test]
+    fn strict_week_before_start_is_error() {
+        let data = HoursData::from_weeks(vec![week_with_direct(date(2025, 1, 21), 5.0)]);
+        let findings = strict_findings(&data, date(2025, 1, 28), date(2025, 2, 6), Weekday::Tue);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].message.contains("outside the tracking period"));
+    }
+
+    #[test]
+    fn strict_future_week_is_error() {
+        let data = HoursData::from_weeks(vec![week_with_direct(date(2025, 2, 11), 0.0)]);
+        let findings = strict_findings(&data, date(2025, 1, 28), date(2025, 2, 6), Weekday::Tue);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn finding_display_includes_severity() {
+        let finding = Finding {
+            severity: Severity::Warning,
+            message: "something".to_string(),
+        };
+        assert_eq!(finding.to_string(), "warning: something");
+    }
+}
@@ -1,26 +1,56 @@
+use std::collections::BTreeMap;
 use std::env;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use anyhow::{Context, Result};
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate, Weekday};
+use log::debug;
 use serde::{Deserialize, Serialize};
 
+use crate::data::model::Category;
+use crate::data::{store, week};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub data: DataConfig,
     pub git: GitConfig,
     pub licensure: LicensureConfig,
+    #[serde(default)]
+    pub milestones: MilestonesConfig,
+    #[serde(default)]
+    pub report: ReportConfig,
+    #[serde(default)]
+    pub timer: TimerConfig,
+    // Alternative target sets (`[profiles.<name>]`) for other credentials
+    // tracked against the same hours; `--profile` swaps one in for `licensure`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, LicensureConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataConfig {
     pub directory: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backups: Option<u32>,
 }
 
+pub const DEFAULT_DATA_FILENAME: &str = "hours.json";
+pub const DEFAULT_BACKUPS: u32 = 1;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitConfig {
     pub remote: String,
     pub auto_push: bool,
+    #[serde(default)]
+    pub notify_on_push: bool,
+    // Fast-forward from the remote before each sync commit.
+    #[serde(default)]
+    pub auto_pull: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +60,119 @@ pub struct LicensureConfig {
     pub direct_hours_target: u32,
     pub min_months: u32,
     pub min_weekly_average: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_monthly_hours: Option<f64>,
+    #[serde(default)]
+    pub months_inclusive: bool,
+    // Day each week starts on; Tuesday when unset.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "weekday_serde"
+    )]
+    pub week_start_day: Option<Weekday>,
+    // Board minimums for the other categories; unset means no minimum.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub individual_supervision_target: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_supervision_target: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indirect_target: Option<u32>,
+    // Printed above the signature lines at the end of the PDF report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supervisor_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supervisor_license: Option<String>,
+}
+
+// Written as the full name ("Monday"); abbreviations read back too.
+mod weekday_serde {
+    use chrono::Weekday;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::data::week;
+
+    pub fn serialize<S: Serializer>(day: &Option<Weekday>, s: S) -> Result<S::Ok, S::Error> {
+        match day {
+            Some(day) => s.serialize_str(week::weekday_name(*day)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Weekday>, D::Error> {
+        let name = String::deserialize(d)?;
+        week::parse_weekday(&name)
+            .map(Some)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestonesConfig {
+    #[serde(default = "default_milestone_increment")]
+    pub increment: u32,
+}
+
+fn default_milestone_increment() -> u32 {
+    250
+}
+
+impl LicensureConfig {
+    pub fn week_start_day(&self) -> Weekday {
+        self.week_start_day.unwrap_or(Weekday::Tue)
+    }
+
+    // Direct hours always carry a target; the other categories only when the
+    // board sets a minimum for them.
+    pub fn category_target(&self, category: Category) -> Option<u32> {
+        match category {
+            Category::Direct => Some(self.direct_hours_target),
+            Category::IndividualSupervision => self.individual_supervision_target,
+            Category::GroupSupervision => self.group_supervision_target,
+            Category::Indirect => self.indirect_target,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportConfig {
+    #[serde(default = "default_display_precision")]
+    pub display_precision: usize,
+    // `summary` warns once the latest logged week is more than this many weeks
+    // behind the current one; 0 turns the warning off.
+    #[serde(default = "default_stale_after_weeks")]
+    pub stale_after_weeks: u32,
+}
+
+fn default_display_precision() -> usize {
+    1
+}
+
+fn default_stale_after_weeks() -> u32 {
+    3
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            display_precision: default_display_precision(),
+            stale_after_weeks: default_stale_after_weeks(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimerConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+impl Default for MilestonesConfig {
+    fn default() -> Self {
+        MilestonesConfig {
+            increment: default_milestone_increment(),
+        }
+    }
 }
 
 impl Config {
@@ -43,37 +186,144 @@ impl Config {
         }
     }
 
-    pub fn config_path() -> PathBuf {
+    // HOURS_CONFIG_DIR, then the global `--config` flag, then a project-local
+    // file found by walking up from the working directory, then the global
+    // config.
+    pub fn config_path(overrides: &ConfigOverrides) -> PathBuf {
+        if env::var_os("HOURS_CONFIG_DIR").is_none() {
+            if let Some(path) = &overrides.config_path {
+                return path.clone();
+            }
+            if let Some(path) = env::current_dir()
+                .ok()
+                .and_then(|cwd| find_local_config(&cwd))
+            {
+                return path;
+            }
+        }
         Self::config_dir().join("config.toml")
     }
 
-    pub fn load() -> Result<Self> {
-        let path = Self::config_path();
+    pub fn load(overrides: &ConfigOverrides) -> Result<Self> {
+        let path = Self::config_path(overrides);
         if !path.exists() {
             anyhow::bail!("Configuration not found. Run `hours init` to set up.");
         }
-        Self::load_from(&path)
+        let started = Instant::now();
+        let config = Self::load_from(&path, overrides)?;
+        debug!(
+            "Loaded config {} in {:?} (data dir {})",
+            path.display(),
+            started.elapsed(),
+            config.data_dir().display()
+        );
+        Ok(config)
     }
 
-    pub fn load_from(path: &Path) -> Result<Self> {
+    // The file exactly as written, without environment or flag overrides.
+    pub fn read_file(path: &Path) -> Result<Self> {
         let contents = std::fs::read_to_string(path)
             .with_context(|| format!("Failed to read {}", path.display()))?;
-        let mut config: Config = toml::from_str(&contents)
-            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn load_from(path: &Path, overrides: &ConfigOverrides) -> Result<Self> {
+        let mut config = Self::read_file(path)?;
+
+        if let Some(name) = &overrides.profile {
+            config.select_profile(name)?;
+        }
 
         if let Ok(data_dir) = env::var("HOURS_DATA_DIR") {
             config.data.directory = data_dir;
         }
 
+        if let Some(data_dir) = &overrides.data_dir {
+            config.data.directory = data_dir.clone();
+        }
+
         if env::var("HOURS_NO_GIT").ok().as_deref() == Some("1") {
             config.git.auto_push = false;
         }
 
+        if let Some(target) = env_override::<u32>("HOURS_TOTAL_TARGET")? {
+            config.licensure.total_hours_target = target;
+        }
+
+        if let Some(target) = env_override::<u32>("HOURS_DIRECT_TARGET")? {
+            config.licensure.direct_hours_target = target;
+        }
+
+        if let Some(months) = env_override::<u32>("HOURS_MIN_MONTHS")? {
+            config.licensure.min_months = months;
+        }
+
+        if let Some(average) = env_override::<f64>("HOURS_MIN_WEEKLY_AVG")? {
+            if !average.is_finite() || average < 0.0 {
+                anyhow::bail!("Invalid HOURS_MIN_WEEKLY_AVG '{average}': must be >= 0");
+            }
+            config.licensure.min_weekly_average = average;
+        }
+
+        if let Some(minimum) = config.licensure.min_monthly_hours {
+            if !minimum.is_finite() || minimum < 0.0 {
+                anyhow::bail!("Invalid min_monthly_hours '{minimum}': must be >= 0");
+            }
+        }
+
+        let start_day = config.licensure.week_start_day();
+        if config.licensure.start_date.weekday() != start_day {
+            anyhow::bail!(
+                "Invalid start_date {}: it is a {}, but weeks start on {} (week_start_day)",
+                config.licensure.start_date,
+                config.licensure.start_date.format("%A"),
+                week::weekday_name(start_day)
+            );
+        }
+
+        if config.report.display_precision > 4 {
+            anyhow::bail!(
+                "Invalid report.display_precision '{}': must be between 0 and 4",
+                config.report.display_precision
+            );
+        }
+
+        if let Some(filename) = &config.data.filename {
+            let plain = Path::new(filename).file_name() == Some(std::ffi::OsStr::new(filename));
+            if !plain {
+                anyhow::bail!(
+                    "Invalid data.filename '{filename}': must be a file name without directories"
+                );
+            }
+        }
+
         config.data.directory = expand_tilde(&config.data.directory);
 
         Ok(config)
     }
 
+    // Replaces `licensure` with the named profile. Logged weeks are shared, so
+    // a profile cannot move the day weeks start on.
+    pub fn select_profile(&mut self, name: &str) -> Result<()> {
+        let Some(profile) = self.profiles.get(name) else {
+            let available = if self.profiles.is_empty() {
+                "none configured".to_string()
+            } else {
+                self.profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+            };
+            anyhow::bail!("Unknown profile '{name}'. Available profiles: {available}");
+        };
+        if profile.week_start_day() != self.licensure.week_start_day() {
+            anyhow::bail!(
+                "Profile '{name}' sets week_start_day {}, but logged weeks start on {}",
+                week::weekday_name(profile.week_start_day()),
+                week::weekday_name(self.licensure.week_start_day())
+            );
+        }
+        self.licensure = profile.clone();
+        Ok(())
+    }
+
     pub fn save(&self, path: &Path) -> Result<()> {
         let contents = toml::to_string_pretty(self).context("Failed to serialize config")?;
         if let Some(parent) = path.parent() {
@@ -81,17 +331,67 @@ impl Config {
                 format!("Failed to create config directory {}", parent.display())
             })?;
         }
-        std::fs::write(path, contents)
-            .with_context(|| format!("Failed to write config to {}", path.display()))?;
-        Ok(())
+        store::write_atomic(path, contents.as_bytes())
+            .with_context(|| format!("Failed to write config to {}", path.display()))
     }
 
     pub fn data_dir(&self) -> PathBuf {
         PathBuf::from(&self.data.directory)
     }
 
+    pub fn data_filename(&self) -> &str {
+        self.data
+            .filename
+            .as_deref()
+            .unwrap_or(DEFAULT_DATA_FILENAME)
+    }
+
     pub fn data_file(&self) -> PathBuf {
-        self.data_dir().join("hours.json")
+        self.data_dir().join(self.data_filename())
+    }
+
+    // Backup generations `store::save` keeps; 0 disables backups.
+    pub fn data_backups(&self) -> u32 {
+        self.data.backups.unwrap_or(DEFAULT_BACKUPS)
+    }
+
+    // The day logged weeks start on, passed to the week helpers and
+    // `store::validate`.
+    pub fn week_start(&self) -> Weekday {
+        self.licensure.week_start_day()
+    }
+}
+
+const LOCAL_CONFIG_NAMES: [&str; 2] = [".hoursrc", "hours.toml"];
+
+// The nearest `.hoursrc` or `hours.toml` in `start` or any of its parents.
+fn find_local_config(start: &Path) -> Option<PathBuf> {
+    start.ancestors().find_map(|dir| {
+        LOCAL_CONFIG_NAMES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+// Global flags that change how the config is loaded. `cli::dispatch` builds
+// one and hands it to each command, which passes it to `Config::load`.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    // `--config`; beats project-local discovery.
+    pub config_path: Option<PathBuf>,
+    // `--profile`; selects `[profiles.<name>]` before the HOURS_* targets apply.
+    pub profile: Option<String>,
+    // `--data-dir`; wins over HOURS_DATA_DIR.
+    pub data_dir: Option<String>,
+}
+
+fn env_override<T: std::str::FromStr>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().map(Some).map_err(|_| {
+            anyhow::anyhow!("Invalid {name} '{value}': expected a non-negative number")
+        }),
+        _ => Ok(None),
     }
 }
 
@@ -141,7 +441,7 @@ min_weekly_average = 15.0
         env::remove_var("HOURS_DATA_DIR");
         env::remove_var("HOURS_NO_GIT");
 
-        let config = Config::load_from(&path).unwrap();
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
 
         assert!(config.data.directory.contains("Sync/.hours"));
         assert_eq!(config.git.remote, "origin");
@@ -154,13 +454,40 @@ min_weekly_average = 15.0
         assert_eq!(config.licensure.direct_hours_target, 1200);
         assert_eq!(config.licensure.min_months, 24);
         assert_eq!(config.licensure.min_weekly_average, 15.0);
+        assert_eq!(config.milestones.increment, 250);
+        assert!(!config.git.notify_on_push);
+    }
+
+    #[test]
+    fn load_notify_on_push() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let toml = sample_toml().replace(
+            "auto_push = true",
+            "auto_push = true\nnotify_on_push = true",
+        );
+        let path = write_config(tmp.path(), &toml);
+
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert!(config.git.notify_on_push);
+    }
+
+    #[test]
+    fn load_milestone_increment() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let toml = format!("{}\n[milestones]\nincrement = 100\n", sample_toml());
+        let path = write_config(tmp.path(), &toml);
+
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.milestones.increment, 100);
     }
 
     #[test]
     fn load_missing_file_returns_error() {
         let tmp = TempDir::new().unwrap();
         let path = tmp.path().join("nonexistent.toml");
-        let result = Config::load_from(&path);
+        let result = Config::load_from(&path, &ConfigOverrides::default());
         assert!(result.is_err());
     }
 
@@ -168,7 +495,7 @@ min_weekly_average = 15.0
     fn load_malformed_toml_returns_error() {
         let tmp = TempDir::new().unwrap();
         let path = write_config(tmp.path(), "this is not valid toml [[[");
-        let result = Config::load_from(&path);
+        let result = Config::load_from(&path, &ConfigOverrides::default());
         assert!(result.is_err());
     }
 
@@ -179,7 +506,7 @@ min_weekly_average = 15.0
 directory = "~/test"
 "#;
         let path = write_config(tmp.path(), content);
-        let result = Config::load_from(&path);
+        let result = Config::load_from(&path, &ConfigOverrides::default());
         assert!(result.is_err());
     }
 
@@ -193,12 +520,36 @@ directory = "~/test"
         env::set_var("HOURS_DATA_DIR", override_dir.to_str().unwrap());
         env::remove_var("HOURS_NO_GIT");
 
-        let config = Config::load_from(&path).unwrap();
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
         assert_eq!(config.data.directory, override_dir.to_str().unwrap());
 
         env::remove_var("HOURS_DATA_DIR");
     }
 
+    #[test]
+    fn data_dir_override_beats_env() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+        env::set_var(
+            "HOURS_DATA_DIR",
+            tmp.path().join("env_data").to_str().unwrap(),
+        );
+
+        // Each load takes its own overrides, so two in one process can differ.
+        for name in ["first", "second"] {
+            let dir = tmp.path().join(name).to_str().unwrap().to_string();
+            let overrides = ConfigOverrides {
+                data_dir: Some(dir.clone()),
+                ..ConfigOverrides::default()
+            };
+            let config = Config::load_from(&path, &overrides).unwrap();
+            assert_eq!(config.data.directory, dir);
+        }
+
+        env::remove_var("HOURS_DATA_DIR");
+    }
+
     #[test]
     fn env_override_no_git() {
         let _lock = ENV_LOCK.lock().unwrap();
@@ -208,7 +559,7 @@ directory = "~/test"
         env::remove_var("HOURS_DATA_DIR");
         env::set_var("HOURS_NO_GIT", "1");
 
-        let config = Config::load_from(&path).unwrap();
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
         assert!(!config.git.auto_push);
 
         env::remove_var("HOURS_NO_GIT");
@@ -223,12 +574,72 @@ directory = "~/test"
         env::remove_var("HOURS_DATA_DIR");
         env::set_var("HOURS_NO_GIT", "0");
 
-        let config = Config::load_from(&path).unwrap();
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
         assert!(config.git.auto_push);
 
         env::remove_var("HOURS_NO_GIT");
     }
 
+    #[test]
+    fn env_override_targets() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+
+        env::set_var("HOURS_TOTAL_TARGET", "2000");
+        env::set_var("HOURS_DIRECT_TARGET", "800");
+        env::set_var("HOURS_MIN_MONTHS", "18");
+        env::set_var("HOURS_MIN_WEEKLY_AVG", "12.5");
+
+        let config = Config::load_from(&path, &ConfigOverrides::default());
+
+        env::remove_var("HOURS_TOTAL_TARGET");
+        env::remove_var("HOURS_DIRECT_TARGET");
+        env::remove_var("HOURS_MIN_MONTHS");
+        env::remove_var("HOURS_MIN_WEEKLY_AVG");
+
+        let config = config.unwrap();
+        assert_eq!(config.licensure.total_hours_target, 2000);
+        assert_eq!(config.licensure.direct_hours_target, 800);
+        assert_eq!(config.licensure.min_months, 18);
+        assert_eq!(config.licensure.min_weekly_average, 12.5);
+    }
+
+    #[test]
+    fn env_override_targets_unset_or_empty_ignored() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+
+        env::remove_var("HOURS_TOTAL_TARGET");
+        env::set_var("HOURS_DIRECT_TARGET", "");
+
+        let config = Config::load_from(&path, &ConfigOverrides::default());
+        env::remove_var("HOURS_DIRECT_TARGET");
+
+        let config = config.unwrap();
+        assert_eq!(config.licensure.total_hours_target, 3000);
+        assert_eq!(config.licensure.direct_hours_target, 1200);
+    }
+
+    #[test]
+    fn env_override_targets_invalid_rejected() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+
+        env::set_var("HOURS_TOTAL_TARGET", "-5");
+        let result = Config::load_from(&path, &ConfigOverrides::default());
+        env::remove_var("HOURS_TOTAL_TARGET");
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Invalid HOURS_TOTAL_TARGET '-5'"), "{err}");
+
+        env::set_var("HOURS_MIN_WEEKLY_AVG", "-1");
+        let result = Config::load_from(&path, &ConfigOverrides::default());
+        env::remove_var("HOURS_MIN_WEEKLY_AVG");
+        assert!(result.is_err());
+    }
+
     #[test]
     fn tilde_expansion() {
         let expanded = expand_tilde("~/Sync/.hours");
@@ -251,10 +662,15 @@ directory = "~/test"
         let config = Config {
             data: DataConfig {
                 directory: "/tmp/test-data".to_string(),
+                filename: None,
+                backups: None,
             },
             git: GitConfig {
                 remote: "origin".to_string(),
                 auto_push: false,
+                notify_on_push: false,
+                auto_pull: false,
+                branch: None,
             },
             licensure: LicensureConfig {
                 start_date: NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
@@ -262,7 +678,19 @@ directory = "~/test"
                 direct_hours_target: 1200,
                 min_months: 24,
                 min_weekly_average: 15.0,
+                min_monthly_hours: None,
+                months_inclusive: false,
+                week_start_day: None,
+                individual_supervision_target: None,
+                group_supervision_target: None,
+                indirect_target: None,
+                supervisor_name: None,
+                supervisor_license: None,
             },
+            milestones: MilestonesConfig::default(),
+            report: ReportConfig::default(),
+            timer: TimerConfig::default(),
+            profiles: BTreeMap::new(),
         };
 
         config.save(&path).unwrap();
@@ -271,7 +699,7 @@ directory = "~/test"
         env::remove_var("HOURS_DATA_DIR");
         env::remove_var("HOURS_NO_GIT");
 
-        let loaded = Config::load_from(&path).unwrap();
+        let loaded = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
         assert_eq!(loaded.data.directory, "/tmp/test-data");
         assert_eq!(loaded.git.remote, "origin");
         assert!(!loaded.git.auto_push);
@@ -290,10 +718,15 @@ directory = "~/test"
         let config = Config {
             data: DataConfig {
                 directory: "/tmp/test".to_string(),
+                filename: None,
+                backups: None,
             },
             git: GitConfig {
                 remote: "origin".to_string(),
                 auto_push: true,
+                notify_on_push: false,
+                auto_pull: false,
+                branch: None,
             },
             licensure: LicensureConfig {
                 start_date: NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
@@ -301,13 +734,44 @@ directory = "~/test"
                 direct_hours_target: 1200,
                 min_months: 24,
                 min_weekly_average: 15.0,
+                min_monthly_hours: None,
+                months_inclusive: false,
+                week_start_day: None,
+                individual_supervision_target: None,
+                group_supervision_target: None,
+                indirect_target: None,
+                supervisor_name: None,
+                supervisor_license: None,
             },
+            milestones: MilestonesConfig::default(),
+            report: ReportConfig::default(),
+            timer: TimerConfig::default(),
+            profiles: BTreeMap::new(),
         };
 
         config.save(&path).unwrap();
         assert!(path.exists());
     }
 
+    #[test]
+    fn save_is_atomic_and_leaves_no_tmp() {
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+        let config = Config::read_file(&path).unwrap();
+        let tmp_path = tmp.path().join("config.toml.tmp");
+
+        // A truncated file and a stale temp file from an interrupted save.
+        std::fs::write(&path, "[data]\ndirec").unwrap();
+        std::fs::write(&tmp_path, "stale").unwrap();
+
+        config.save(&path).unwrap();
+
+        assert!(!tmp_path.exists());
+        let saved = Config::read_file(&path).unwrap();
+        assert_eq!(saved.data.directory, "~/Sync/.hours");
+        assert_eq!(saved.licensure.start_date, config.licensure.start_date);
+    }
+
     #[test]
     fn config_dir_uses_env_var() {
         let _lock = ENV_LOCK.lock().unwrap();
@@ -317,15 +781,85 @@ directory = "~/test"
         env::remove_var("HOURS_CONFIG_DIR");
     }
 
+    #[test]
+    fn find_local_config_walks_up_from_nested_dir() {
+        let tmp = TempDir::new().unwrap();
+        let project = tmp.path().join("project");
+        let nested = project.join("clients").join("a");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(project.join(".hoursrc"), sample_toml()).unwrap();
+
+        assert_eq!(find_local_config(&nested), Some(project.join(".hoursrc")));
+
+        // The nearest file wins, and `.hoursrc` beats `hours.toml` in one dir.
+        fs::write(nested.join("hours.toml"), sample_toml()).unwrap();
+        assert_eq!(find_local_config(&nested), Some(nested.join("hours.toml")));
+        fs::write(nested.join(".hoursrc"), sample_toml()).unwrap();
+        assert_eq!(find_local_config(&nested), Some(nested.join(".hoursrc")));
+    }
+
+    #[test]
+    fn find_local_config_ignores_directories_and_misses() {
+        let tmp = TempDir::new().unwrap();
+        let nested = tmp.path().join("a").join("b");
+        fs::create_dir_all(nested.join(".hoursrc")).unwrap();
+        assert_eq!(find_local_config(&tmp.path().join("a")), None);
+    }
+
+    #[test]
+    fn config_path_prefers_explicit_path_over_discovery() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("HOURS_CONFIG_DIR");
+        let overrides = ConfigOverrides {
+            config_path: Some(PathBuf::from("/explicit/hours.toml")),
+            ..ConfigOverrides::default()
+        };
+        assert_eq!(
+            Config::config_path(&overrides),
+            PathBuf::from("/explicit/hours.toml")
+        );
+
+        // HOURS_CONFIG_DIR still wins.
+        env::set_var("HOURS_CONFIG_DIR", "/custom/config/path");
+        assert_eq!(
+            Config::config_path(&overrides),
+            PathBuf::from("/custom/config/path/config.toml")
+        );
+        env::remove_var("HOURS_CONFIG_DIR");
+    }
+
+    #[test]
+    fn config_path_falls_back_to_global_without_local_file() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::set_var("HOURS_CONFIG_DIR", "/custom/config/path");
+        assert_eq!(
+            Config::config_path(&ConfigOverrides::default()),
+            PathBuf::from("/custom/config/path/config.toml")
+        );
+        env::remove_var("HOURS_CONFIG_DIR");
+
+        // The crate root has no .hoursrc, so only an ancestor's could be found.
+        let expected = env::current_dir()
+            .ok()
+            .and_then(|cwd| find_local_config(&cwd))
+            .unwrap_or_else(|| Config::config_dir().join("config.toml"));
+        assert_eq!(Config::config_path(&ConfigOverrides::default()), expected);
+    }
+
     #[test]
     fn data_dir_and_data_file() {
         let config = Config {
             data: DataConfig {
                 directory: "/some/data/dir".to_string(),
+                filename: None,
+                backups: None,
             },
             git: GitConfig {
                 remote: "origin".to_string(),
                 auto_push: true,
+                notify_on_push: false,
+                auto_pull: false,
+                branch: None,
             },
             licensure: LicensureConfig {
                 start_date: NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
@@ -333,7 +867,19 @@ directory = "~/test"
                 direct_hours_target: 1200,
                 min_months: 24,
                 min_weekly_average: 15.0,
+                min_monthly_hours: None,
+                months_inclusive: false,
+                week_start_day: None,
+                individual_supervision_target: None,
+                group_supervision_target: None,
+                indirect_target: None,
+                supervisor_name: None,
+                supervisor_license: None,
             },
+            milestones: MilestonesConfig::default(),
+            report: ReportConfig::default(),
+            timer: TimerConfig::default(),
+            profiles: BTreeMap::new(),
         };
 
         assert_eq!(config.data_dir(), PathBuf::from("/some/data/dir"));
@@ -343,6 +889,124 @@ directory = "~/test"
         );
     }
 
+    #[test]
+    fn load_custom_data_filename() {
+        let tmp = TempDir::new().unwrap();
+        let content = sample_toml().replacen("[data]\n", "[data]\nfilename = \"clinic.json\"\n", 1);
+        let path = write_config(tmp.path(), &content);
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.data_filename(), "clinic.json");
+        assert!(config.data_file().ends_with("clinic.json"));
+
+        let content =
+            sample_toml().replacen("[data]\n", "[data]\nfilename = \"../clinic.json\"\n", 1);
+        let path = write_config(tmp.path(), &content);
+        let err = Config::load_from(&path, &ConfigOverrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid data.filename"), "{err}");
+    }
+
+    #[test]
+    fn select_profile_swaps_licensure_targets() {
+        let tmp = TempDir::new().unwrap();
+        let content = format!(
+            "{}\n[profiles.lmft]\nstart_date = \"2025-02-04\"\ntotal_hours_target = 1500\n\
+             direct_hours_target = 500\nmin_months = 24\nmin_weekly_average = 10.0\n\n\
+             [profiles.late]\nstart_date = \"2025-02-05\"\ntotal_hours_target = 1\n\
+             direct_hours_target = 1\nmin_months = 1\nmin_weekly_average = 1.0\n\
+             week_start_day = \"Wednesday\"\n",
+            sample_toml()
+        );
+        let path = write_config(tmp.path(), &content);
+        let mut config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.licensure.total_hours_target, 3000);
+        assert_eq!(config.profiles.len(), 2);
+
+        config.select_profile("lmft").unwrap();
+        assert_eq!(config.licensure.total_hours_target, 1500);
+        assert_eq!(config.licensure.start_date.to_string(), "2025-02-04");
+
+        let err = config.select_profile("lcsw").unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Unknown profile 'lcsw'. Available profiles: late, lmft"
+        );
+        let err = config.select_profile("late").unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Profile 'late' sets week_start_day Wednesday, but logged weeks start on Tuesday"
+        );
+
+        let mut plain = Config::load_from(
+            &write_config(tmp.path(), &sample_toml()),
+            &ConfigOverrides::default(),
+        )
+        .unwrap();
+        let err = plain.select_profile("lmft").unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Unknown profile 'lmft'. Available profiles: none configured"
+        );
+    }
+
+    #[test]
+    fn load_from_selects_profile_override() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let content = format!(
+            "{}\n[profiles.lmft]\nstart_date = \"2025-02-04\"\ntotal_hours_target = 1500\n\
+             direct_hours_target = 500\nmin_months = 24\nmin_weekly_average = 10.0\n",
+            sample_toml()
+        );
+        let path = write_config(tmp.path(), &content);
+        let lmft = ConfigOverrides {
+            profile: Some("lmft".to_string()),
+            ..ConfigOverrides::default()
+        };
+
+        let config = Config::load_from(&path, &lmft).unwrap();
+        assert_eq!(config.licensure.total_hours_target, 1500);
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.licensure.total_hours_target, 3000);
+
+        // Environment targets apply on top of the selected profile.
+        env::set_var("HOURS_DIRECT_TARGET", "700");
+        let config = Config::load_from(&path, &lmft);
+        env::remove_var("HOURS_DIRECT_TARGET");
+        let config = config.unwrap();
+        assert_eq!(config.licensure.total_hours_target, 1500);
+        assert_eq!(config.licensure.direct_hours_target, 700);
+
+        let missing = ConfigOverrides {
+            profile: Some("lpc".to_string()),
+            ..ConfigOverrides::default()
+        };
+        let err = Config::load_from(&path, &missing).unwrap_err().to_string();
+        assert_eq!(err, "Unknown profile 'lpc'. Available profiles: lmft");
+    }
+
+    #[test]
+    fn load_data_backups_defaults_to_one() {
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+        assert_eq!(
+            Config::load_from(&path, &ConfigOverrides::default())
+                .unwrap()
+                .data_backups(),
+            1
+        );
+
+        let content = sample_toml().replacen("[data]\n", "[data]\nbackups = 0\n", 1);
+        let path = write_config(tmp.path(), &content);
+        assert_eq!(
+            Config::load_from(&path, &ConfigOverrides::default())
+                .unwrap()
+                .data_backups(),
+            0
+        );
+    }
+
     #[test]
     fn load_with_custom_defaults() {
         let _lock = ENV_LOCK.lock().unwrap();
@@ -366,7 +1030,7 @@ min_weekly_average = 20.0
         env::remove_var("HOURS_DATA_DIR");
         env::remove_var("HOURS_NO_GIT");
 
-        let config = Config::load_from(&path).unwrap();
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
         assert_eq!(config.data.directory, "/custom/path");
         assert_eq!(config.git.remote, "upstream");
         assert!(!config.git.auto_push);
@@ -378,5 +1042,94 @@ min_weekly_average = 20.0
         assert_eq!(config.licensure.direct_hours_target, 800);
         assert_eq!(config.licensure.min_months, 12);
         assert_eq!(config.licensure.min_weekly_average, 20.0);
+        assert_eq!(config.licensure.min_monthly_hours, None);
+        assert!(!config.licensure.months_inclusive);
+    }
+
+    #[test]
+    fn load_report_display_precision() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(tmp.path(), &sample_toml());
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.report.display_precision, 1);
+
+        let path = write_config(
+            tmp.path(),
+            &format!("{}\n[report]\ndisplay_precision = 2\n", sample_toml()),
+        );
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.report.display_precision, 2);
+
+        let path = write_config(
+            tmp.path(),
+            &format!("{}\n[report]\ndisplay_precision = 9\n", sample_toml()),
+        );
+        let err = Config::load_from(&path, &ConfigOverrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("display_precision"), "{err}");
+    }
+
+    #[test]
+    fn load_months_inclusive() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(
+            tmp.path(),
+            &format!("{}months_inclusive = true\n", sample_toml()),
+        );
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert!(config.licensure.months_inclusive);
+    }
+
+    #[test]
+    fn load_min_monthly_hours() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let path = write_config(
+            tmp.path(),
+            &format!("{}min_monthly_hours = 40.0\n", sample_toml()),
+        );
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.licensure.min_monthly_hours, Some(40.0));
+
+        let path = write_config(
+            tmp.path(),
+            &format!("{}min_monthly_hours = -5.0\n", sample_toml()),
+        );
+        let err = Config::load_from(&path, &ConfigOverrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("min_monthly_hours"), "{err}");
+    }
+
+    #[test]
+    fn load_week_start_day() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let tmp = TempDir::new().unwrap();
+        let config = Config::load_from(
+            &write_config(tmp.path(), &sample_toml()),
+            &ConfigOverrides::default(),
+        )
+        .unwrap();
+        assert_eq!(config.licensure.week_start_day(), Weekday::Tue);
+
+        let monday = sample_toml().replace("2025-01-28", "2025-01-27");
+        let path = write_config(tmp.path(), &format!("{monday}week_start_day = \"mon\"\n"));
+        let config = Config::load_from(&path, &ConfigOverrides::default()).unwrap();
+        assert_eq!(config.licensure.week_start_day(), Weekday::Mon);
+        let saved = toml::to_string_pretty(&config).unwrap();
+        assert!(saved.contains("week_start_day = \"Monday\""), "{saved}");
+
+        // The Tuesday start date no longer lines up with Monday weeks.
+        let path = write_config(
+            tmp.path(),
+            &format!("{}week_start_day = \"Monday\"\n", sample_toml()),
+        );
+        let err = Config::load_from(&path, &ConfigOverrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("weeks start on Monday"), "{err}");
     }
 }
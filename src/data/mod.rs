@@ -1,3 +1,4 @@
 pub mod model;
+pub mod progress;
 pub mod store;
 pub mod week;
@@ -1,21 +1,213 @@
 use std::fmt;
 use std::str::FromStr;
 
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::config::LicensureConfig;
+
+// Version of the `hours.json` layout, also reported as `schema_version` by
+// `list --json` and `summary --json` so scripts can detect format changes.
+// Bump it only for changes an older reader would get wrong: a renamed,
+// removed, or reinterpreted field. Purely additive optional fields keep the
+// number. Every bump needs a step in `store::migrate` that upgrades files
+// written with the previous version.
+pub const SCHEMA_VERSION: u32 = 1;
+
+// Files written before the field existed are version 1.
+fn default_schema_version() -> u32 {
+    1
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HoursData {
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     pub weeks: Vec<WeekEntry>,
 }
 
 impl HoursData {
     pub fn new() -> Self {
-        Self { weeks: Vec::new() }
+        Self::from_weeks(Vec::new())
+    }
+
+    pub fn from_weeks(weeks: Vec<WeekEntry>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            weeks,
+        }
+    }
+
+    pub fn total(&self) -> f64 {
+        normalize_hours(self.weeks.iter().map(|w| w.total()).sum())
+    }
+
+    pub fn changes_since(&self, previous: &HoursData) -> Vec<WeekChange> {
+        let mut changes = Vec::new();
+
+        for entry in &self.weeks {
+            match previous.weeks.iter().find(|w| w.start == entry.start) {
+                None => changes.push(WeekChange::Added(entry.clone())),
+                Some(old) if !old.same_hours(entry) => changes.push(WeekChange::Modified {
+                    before: old.clone(),
+                    after: entry.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+
+        for old in &previous.weeks {
+            if !self.weeks.iter().any(|w| w.start == old.start) {
+                changes.push(WeekChange::Removed(old.clone()));
+            }
+        }
+
+        changes.sort_by_key(|c| c.start());
+        changes
+    }
+
+    pub fn merge(&mut self, other: &HoursData, strategy: MergeStrategy) -> MergeReport {
+        let mut report = MergeReport::default();
+
+        for theirs in &other.weeks {
+            let Some(mine) = self.weeks.iter_mut().find(|w| w.start == theirs.start) else {
+                self.weeks.push(theirs.clone());
+                report.added.push(theirs.start);
+                continue;
+            };
+
+            if mine.same_hours(theirs) {
+                continue;
+            }
+
+            let before = mine.clone();
+            match strategy {
+                MergeStrategy::Sum => mine.absorb(theirs),
+                MergeStrategy::PreferTheirs => *mine = theirs.clone(),
+                MergeStrategy::PreferMine => {}
+            }
+
+            report.conflicts.push(MergeConflict {
+                mine: before,
+                theirs: theirs.clone(),
+                merged: mine.clone(),
+            });
+        }
+
+        self.weeks.sort_by_key(|w| w.start);
+        report
+    }
+
+    // Folds weeks that share a start date into one summed week. Returns one
+    // entry per start date that had duplicates.
+    pub fn dedupe(&mut self) -> Vec<DuplicateMerge> {
+        self.weeks.sort_by_key(|w| w.start);
+
+        let mut merges: Vec<DuplicateMerge> = Vec::new();
+        let mut weeks: Vec<WeekEntry> = Vec::with_capacity(self.weeks.len());
+        for week in self.weeks.drain(..) {
+            match weeks.last_mut() {
+                Some(last) if last.start == week.start => {
+                    last.absorb(&week);
+                    if let Some(note) = &week.note {
+                        last.join_note(note);
+                    }
+                    match merges.last_mut() {
+                        Some(merge) if merge.merged.start == week.start => {
+                            merge.count += 1;
+                            merge.merged = last.clone();
+                        }
+                        _ => merges.push(DuplicateMerge {
+                            count: 2,
+                            merged: last.clone(),
+                        }),
+                    }
+                }
+                _ => weeks.push(week),
+            }
+        }
+        self.weeks = weeks;
+        merges
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateMerge {
+    pub count: usize,
+    pub merged: WeekEntry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    Sum,
+    PreferTheirs,
+    PreferMine,
+}
+
+impl FromStr for MergeStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sum" => Ok(MergeStrategy::Sum),
+            "prefer-theirs" => Ok(MergeStrategy::PreferTheirs),
+            "prefer-mine" => Ok(MergeStrategy::PreferMine),
+            _ => Err(anyhow::anyhow!(
+                "Invalid merge strategy '{}'. Valid strategies: sum, prefer-theirs, prefer-mine",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeStrategy::Sum => write!(f, "sum"),
+            MergeStrategy::PreferTheirs => write!(f, "prefer-theirs"),
+            MergeStrategy::PreferMine => write!(f, "prefer-mine"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeConflict {
+    pub mine: WeekEntry,
+    pub theirs: WeekEntry,
+    pub merged: WeekEntry,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MergeReport {
+    pub added: Vec<NaiveDate>,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+#[derive(Debug, Clone)]
+pub enum WeekChange {
+    Added(WeekEntry),
+    Removed(WeekEntry),
+    Modified { before: WeekEntry, after: WeekEntry },
+}
+
+impl WeekChange {
+    pub fn start(&self) -> NaiveDate {
+        match self {
+            WeekChange::Added(w) | WeekChange::Removed(w) => w.start,
+            WeekChange::Modified { after, .. } => after.start,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WeekChange::Added(_) => "added",
+            WeekChange::Removed(_) => "removed",
+            WeekChange::Modified { .. } => "modified",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeekEntry {
     pub start: NaiveDate,
     pub end: NaiveDate,
@@ -23,6 +215,80 @@ pub struct WeekEntry {
     pub group_supervision: f64,
     pub direct: f64,
     pub indirect: f64,
+    #[serde(default)]
+    pub entries: u32,
+    // Hours logged by those `hours add` operations, so hours set with `edit`
+    // stay out of the per-entry average. Absent in files from before the
+    // field; see `entry_hours`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_hours: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    // Direct-hours goal for this week, overriding `min_weekly_average`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    // Board-recognized break: left out of the weekly-average denominator.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub excluded: bool,
+    // Per-day breakdown from `add --date`, sorted by date, one entry per day.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days: Vec<DayEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayEntry {
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub individual_supervision: f64,
+    #[serde(default)]
+    pub group_supervision: f64,
+    #[serde(default)]
+    pub direct: f64,
+    #[serde(default)]
+    pub indirect: f64,
+}
+
+impl DayEntry {
+    pub fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            individual_supervision: 0.0,
+            group_supervision: 0.0,
+            direct: 0.0,
+            indirect: 0.0,
+        }
+    }
+
+    pub fn get(&self, category: Category) -> f64 {
+        match category {
+            Category::IndividualSupervision => self.individual_supervision,
+            Category::GroupSupervision => self.group_supervision,
+            Category::Direct => self.direct,
+            Category::Indirect => self.indirect,
+        }
+    }
+
+    pub fn set(&mut self, category: Category, value: f64) {
+        let value = normalize_hours(value);
+        match category {
+            Category::IndividualSupervision => self.individual_supervision = value,
+            Category::GroupSupervision => self.group_supervision = value,
+            Category::Direct => self.direct = value,
+            Category::Indirect => self.indirect = value,
+        }
+    }
+
+    pub fn add(&mut self, category: Category, value: f64) {
+        self.set(category, self.get(category) + value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        Category::ALL.iter().all(|c| self.get(*c) == 0.0)
+    }
 }
 
 impl WeekEntry {
@@ -34,9 +300,143 @@ impl WeekEntry {
             group_supervision: 0.0,
             direct: 0.0,
             indirect: 0.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }
+    }
+
+    pub fn created(start: NaiveDate, end: NaiveDate, now: DateTime<Utc>) -> Self {
+        Self {
+            created_at: Some(now),
+            updated_at: Some(now),
+            ..Self::new(start, end)
         }
     }
 
+    pub fn touch(&mut self, now: DateTime<Utc>) {
+        self.updated_at = Some(now);
+    }
+
+    // Replaces the note; blank text clears it.
+    pub fn set_note(&mut self, text: &str) {
+        let text = text.trim();
+        self.note = (!text.is_empty()).then(|| text.to_string());
+    }
+
+    // Returns whether the note changed; blank text is ignored.
+    pub fn append_note(&mut self, text: &str) -> bool {
+        let text = text.trim();
+        if text.is_empty() {
+            return false;
+        }
+        self.note = Some(match self.note.take().filter(|n| !n.trim().is_empty()) {
+            Some(existing) => format!("{}\n{text}", existing.trim_end()),
+            None => text.to_string(),
+        });
+        true
+    }
+
+    pub fn day_sum(&self, category: Category) -> f64 {
+        self.days.iter().map(|d| d.get(category)).sum()
+    }
+
+    // A category total may exceed its days (undated hours) but never fall
+    // short of them.
+    pub fn days_fit(&self, category: Category) -> bool {
+        self.day_sum(category) <= self.get(category) + DAY_SUM_EPSILON
+    }
+
+    // Adds into the day's entry, creating it in date order; totals are left alone.
+    fn merge_day(&mut self, date: NaiveDate, category: Category, value: f64) {
+        let idx = match self.days.binary_search_by_key(&date, |d| d.date) {
+            Ok(idx) => idx,
+            Err(idx) => {
+                self.days.insert(idx, DayEntry::new(date));
+                idx
+            }
+        };
+        self.days[idx].add(category, value);
+    }
+
+    // Logs hours on one day of the week and recomputes the category total as
+    // the sum of the days. Hours the week already had without a date (from
+    // `--week`, `edit`, or files older than daily entries) are carried over on
+    // top, so the top-level totals stay authoritative.
+    pub fn add_day(&mut self, date: NaiveDate, category: Category, value: f64) {
+        let undated = self.get(category) - self.day_sum(category);
+        self.merge_day(date, category, value);
+        self.set(category, undated + self.day_sum(category));
+    }
+
+    // Counts one `hours add` of `hours`.
+    pub fn record_entry(&mut self, hours: f64) {
+        self.added_hours = Some(normalize_hours(self.entry_hours() + hours));
+        self.entries += 1;
+    }
+
+    // Hours logged through `hours add`. Weeks from before `added_hours` was
+    // recorded fall back to their total once they have any entries.
+    pub fn entry_hours(&self) -> f64 {
+        match self.added_hours {
+            Some(hours) => hours,
+            None if self.entries > 0 => self.total(),
+            None => 0.0,
+        }
+    }
+
+    // Sums another record of the same week into this one.
+    pub fn absorb(&mut self, other: &WeekEntry) {
+        // Before the categories change, since a legacy week's fallback is its total.
+        if self.added_hours.is_some() || other.added_hours.is_some() {
+            self.added_hours = Some(normalize_hours(self.entry_hours() + other.entry_hours()));
+        }
+        for category in Category::ALL {
+            self.add(category, other.get(category));
+            for day in &other.days {
+                self.merge_day(day.date, category, day.get(category));
+            }
+        }
+        self.entries += other.entries;
+        self.created_at = match (self.created_at, other.created_at) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.updated_at = self.updated_at.max(other.updated_at);
+        self.target = self.target.or(other.target);
+    }
+
+    // Appends each line of `text` that the note does not already have, so
+    // folding notes `A`, `B`, `A` together gives `A\nB`.
+    pub fn join_note(&mut self, text: &str) {
+        for line in text.lines() {
+            let known = self
+                .note
+                .as_deref()
+                .is_some_and(|note| note.lines().any(|l| l.trim() == line.trim()));
+            if !known {
+                self.append_note(line);
+            }
+        }
+    }
+
+    pub fn direct_target(&self, default: f64) -> f64 {
+        self.target.unwrap_or(default)
+    }
+
+    pub fn meets_target(&self, default: f64) -> bool {
+        self.direct >= self.direct_target(default)
+    }
+
+    pub fn same_hours(&self, other: &WeekEntry) -> bool {
+        Category::ALL.iter().all(|c| self.get(*c) == other.get(*c))
+    }
+
     pub fn total(&self) -> f64 {
         self.individual_supervision + self.group_supervision + self.direct + self.indirect
     }
@@ -51,6 +451,7 @@ impl WeekEntry {
     }
 
     pub fn set(&mut self, category: Category, value: f64) {
+        let value = normalize_hours(value);
         match category {
             Category::IndividualSupervision => self.individual_supervision = value,
             Category::GroupSupervision => self.group_supervision = value,
@@ -60,15 +461,42 @@ impl WeekEntry {
     }
 
     pub fn add(&mut self, category: Category, value: f64) {
-        match category {
-            Category::IndividualSupervision => self.individual_supervision += value,
-            Category::GroupSupervision => self.group_supervision += value,
-            Category::Direct => self.direct += value,
-            Category::Indirect => self.indirect += value,
+        self.set(category, self.get(category) + value);
+    }
+
+    // Sets a total by hand (`edit`). Days logged for the category stay while
+    // the new total still covers them; otherwise they are dropped and all of
+    // the category's hours become undated, so later `add --date` calls build
+    // on the edited total instead of the stale days.
+    pub fn set_total(&mut self, category: Category, value: f64) {
+        self.set(category, value);
+        if !self.days_fit(category) {
+            for day in &mut self.days {
+                day.set(category, 0.0);
+            }
+            self.days.retain(|day| !day.is_empty());
         }
     }
 }
 
+// Float noise from summing day entries, not a real shortfall.
+const DAY_SUM_EPSILON: f64 = 1e-9;
+
+// Four decimal places keeps minute-level entries (1 min = 0.0167 hrs) while
+// dropping float noise like 5.500000000000001 before it reaches hours.json.
+const STORED_SCALE: f64 = 10_000.0;
+
+// Also used for sums of stored values, which turns the -0.0 an empty sum
+// produces into 0.0.
+pub fn normalize_hours(value: f64) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    let rounded = (value * STORED_SCALE).round() / STORED_SCALE;
+    // Adds nothing, but turns -0.0 into 0.0.
+    rounded + 0.0
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Category {
     IndividualSupervision,
@@ -102,6 +530,57 @@ impl Category {
             Category::Indirect => "Indirect",
         }
     }
+
+    pub fn iter_with_targets(
+        licensure: &LicensureConfig,
+    ) -> impl Iterator<Item = (Category, Option<u32>)> + '_ {
+        Category::ALL
+            .iter()
+            .map(move |category| (*category, licensure.category_target(*category)))
+    }
+
+    // Forgiving parse for read-only filters; writes keep the strict `from_str`.
+    // On top of what `from_str` accepts it ignores case, takes `-` or spaces
+    // for `_`, drops a trailing plural `s`, and matches a prefix of the name or
+    // of one of its words ("group", "indiv", "directs"). A prefix that fits
+    // more than one category ("supervision", "ind") is rejected.
+    pub fn parse_flexible(input: &str) -> anyhow::Result<Category> {
+        let normalized = input.trim().to_lowercase().replace(['-', ' '], "_");
+        let singular = normalized.strip_suffix('s').filter(|s| !s.is_empty());
+
+        for candidate in std::iter::once(normalized.as_str()).chain(singular) {
+            if let Ok(category) = candidate.parse::<Category>() {
+                return Ok(category);
+            }
+        }
+        if normalized.is_empty() {
+            return input.parse();
+        }
+
+        for candidate in std::iter::once(normalized.as_str()).chain(singular) {
+            let matches: Vec<Category> = Category::ALL
+                .into_iter()
+                .filter(|category| {
+                    let name = category.to_string();
+                    name.starts_with(candidate)
+                        || name.split('_').any(|word| word.starts_with(candidate))
+                })
+                .collect();
+            match matches[..] {
+                [] => continue,
+                [category] => return Ok(category),
+                _ => {
+                    let names: Vec<String> = matches.iter().map(|c| c.to_string()).collect();
+                    anyhow::bail!(
+                        "Ambiguous category '{input}': could be {}. Type more of the name or use a code (is, gs, d, i)",
+                        names.join(" or ")
+                    );
+                }
+            }
+        }
+
+        input.parse()
+    }
 }
 
 impl FromStr for Category {
@@ -109,12 +588,12 @@ impl FromStr for Category {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "individual_supervision" => Ok(Category::IndividualSupervision),
-            "group_supervision" => Ok(Category::GroupSupervision),
-            "direct" => Ok(Category::Direct),
-            "indirect" => Ok(Category::Indirect),
+            "individual_supervision" | "is" => Ok(Category::IndividualSupervision),
+            "group_supervision" | "gs" => Ok(Category::GroupSupervision),
+            "direct" | "d" => Ok(Category::Direct),
+            "indirect" | "i" => Ok(Category::Indirect),
             _ => Err(anyhow::anyhow!(
-                "Invalid category '{}'. Valid categories: individual_supervision, group_supervision, direct, indirect",
+                "Invalid category '{}'. Valid categories: individual_supervision (is), group_supervision (gs), direct (d), indirect (i)",
                 s
             )),
         }
@@ -137,6 +616,30 @@ mod tests {
     use super::*;
     use chrono::NaiveDate;
 
+    #[test]
+    fn test_add_tenths_accumulates_exactly() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        for _ in 0..10 {
+            entry.add(Category::Direct, 0.1);
+        }
+        assert_eq!(entry.direct, 1.0);
+
+        entry.set(Category::Indirect, 5.500000000000001);
+        assert_eq!(entry.indirect, 5.5);
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"direct\":1.0"), "{json}");
+        assert!(json.contains("\"indirect\":5.5,"), "{json}");
+    }
+
+    #[test]
+    fn test_normalize_hours_keeps_minute_precision() {
+        assert_eq!(normalize_hours(0.25), 0.25);
+        assert_eq!(normalize_hours(1.0 / 60.0), 0.0167);
+        assert!(normalize_hours(-0.00001).is_sign_positive());
+        assert!(normalize_hours(f64::INFINITY).is_infinite());
+    }
+
     #[test]
     fn test_week_entry_total() {
         let entry = WeekEntry {
@@ -146,6 +649,14 @@ mod tests {
             group_supervision: 2.0,
             direct: 14.5,
             indirect: 6.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
         };
         assert!((entry.total() - 23.5).abs() < f64::EPSILON);
     }
@@ -177,6 +688,178 @@ mod tests {
         assert!((entry.get(Category::Direct) - 7.5).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_week_entry_entries_defaults_when_missing() {
+        let json = r#"{"start":"2025-01-28","end":"2025-02-03","individual_supervision":0.0,"group_supervision":0.0,"direct":1.0,"indirect":0.0}"#;
+        let entry: WeekEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.entries, 0);
+    }
+
+    #[test]
+    fn test_record_entry_tracks_added_hours() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.add(Category::Direct, 2.0);
+        entry.record_entry(2.0);
+        // An `edit` changes the total but not the added hours.
+        entry.set(Category::Indirect, 10.0);
+        assert_eq!(entry.entries, 1);
+        assert_eq!(entry.entry_hours(), 2.0);
+    }
+
+    #[test]
+    fn test_entry_hours_falls_back_to_total_for_old_weeks() {
+        let json = r#"{"start":"2025-01-28","end":"2025-02-03","individual_supervision":0.0,"group_supervision":0.0,"direct":4.0,"indirect":1.0,"entries":2}"#;
+        let mut entry: WeekEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.entry_hours(), 5.0);
+        assert!(!serde_json::to_string(&entry)
+            .unwrap()
+            .contains("added_hours"));
+
+        entry.entries = 0;
+        assert_eq!(entry.entry_hours(), 0.0);
+    }
+
+    #[test]
+    fn test_absorb_combines_added_hours() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let end = start + chrono::Duration::days(6);
+        let mut legacy = WeekEntry::new(start, end);
+        legacy.direct = 3.0;
+        legacy.entries = 1;
+        let mut added = WeekEntry::new(start, end);
+        added.add(Category::Direct, 1.5);
+        added.record_entry(1.5);
+        added.indirect = 4.0;
+
+        legacy.absorb(&added);
+        assert_eq!(legacy.entries, 2);
+        assert_eq!(legacy.added_hours, Some(4.5));
+    }
+
+    #[test]
+    fn test_week_entry_timestamps_absent_in_old_files() {
+        let json = r#"{"start":"2025-01-28","end":"2025-02-03","individual_supervision":0.0,"group_supervision":0.0,"direct":1.0,"indirect":0.0}"#;
+        let entry: WeekEntry = serde_json::from_str(json).unwrap();
+        assert!(entry.created_at.is_none());
+        assert!(entry.updated_at.is_none());
+
+        let out = serde_json::to_string(&entry).unwrap();
+        assert!(!out.contains("created_at"));
+        assert!(!out.contains("updated_at"));
+    }
+
+    #[test]
+    fn test_week_entry_created_and_touch() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+        let created = Utc::now();
+        let mut entry = WeekEntry::created(start, end, created);
+        assert_eq!(entry.created_at, Some(created));
+        assert_eq!(entry.updated_at, Some(created));
+
+        let later = created + chrono::Duration::seconds(30);
+        entry.touch(later);
+        assert_eq!(entry.created_at, Some(created));
+        assert_eq!(entry.updated_at, Some(later));
+    }
+
+    #[test]
+    fn test_week_entry_target_overrides_default() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+        let mut entry = WeekEntry::new(start, end);
+        entry.direct = 10.0;
+        assert_eq!(entry.direct_target(15.0), 15.0);
+        assert!(!entry.meets_target(15.0));
+
+        entry.target = Some(8.0);
+        assert_eq!(entry.direct_target(15.0), 8.0);
+        assert!(entry.meets_target(15.0));
+
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"target\":8.0"), "{json}");
+        let untargeted = serde_json::to_string(&WeekEntry::new(start, end)).unwrap();
+        assert!(!untargeted.contains("target"), "{untargeted}");
+    }
+
+    #[test]
+    fn test_week_entry_set_note_replaces_and_clears() {
+        let mut entry = WeekEntry::new(
+            NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
+        );
+        entry.append_note("first");
+        entry.set_note("  replaced  ");
+        assert_eq!(entry.note.as_deref(), Some("replaced"));
+        entry.set_note(" ");
+        assert_eq!(entry.note, None);
+    }
+
+    #[test]
+    fn test_week_entry_add_day_rolls_up_into_totals() {
+        let day = |d| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+        let mut entry = WeekEntry::new(day(28), NaiveDate::from_ymd_opt(2025, 2, 3).unwrap());
+        // Logged for the week before daily entries existed.
+        entry.direct = 3.0;
+
+        entry.add_day(day(30), Category::Direct, 2.0);
+        entry.add_day(day(29), Category::Indirect, 1.5);
+        entry.add_day(day(30), Category::Direct, 1.0);
+
+        assert_eq!(entry.direct, 6.0);
+        assert_eq!(entry.indirect, 1.5);
+        let dates: Vec<_> = entry.days.iter().map(|d| d.date).collect();
+        assert_eq!(dates, vec![day(29), day(30)]);
+        assert_eq!(entry.days[1].direct, 3.0);
+        assert_eq!(entry.day_sum(Category::Direct), 3.0);
+
+        let json = serde_json::to_string(&entry).unwrap();
+        let back: WeekEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, entry);
+        let plain = WeekEntry::new(day(28), NaiveDate::from_ymd_opt(2025, 2, 3).unwrap());
+        assert!(!serde_json::to_string(&plain).unwrap().contains("days"));
+    }
+
+    #[test]
+    fn test_week_entry_set_total_drops_days_it_no_longer_covers() {
+        let day = |d| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+        let mut entry = WeekEntry::new(day(28), NaiveDate::from_ymd_opt(2025, 2, 3).unwrap());
+        entry.add_day(day(30), Category::Direct, 3.0);
+        entry.add_day(day(30), Category::Indirect, 1.0);
+
+        // Raising a total keeps the days; the extra hours are undated.
+        entry.set_total(Category::Direct, 5.0);
+        assert_eq!(entry.day_sum(Category::Direct), 3.0);
+
+        entry.set_total(Category::Direct, 1.0);
+        assert_eq!(entry.direct, 1.0);
+        assert_eq!(entry.day_sum(Category::Direct), 0.0);
+        assert_eq!(entry.days.len(), 1);
+        assert_eq!(entry.days[0].indirect, 1.0);
+
+        entry.add_day(day(31), Category::Direct, 1.0);
+        assert_eq!(entry.direct, 2.0);
+        assert!(Category::ALL.iter().all(|c| entry.days_fit(*c)));
+
+        entry.set_total(Category::Indirect, 0.0);
+        assert_eq!(entry.days.len(), 1);
+        assert_eq!(entry.days[0].date, day(31));
+    }
+
+    #[test]
+    fn test_week_entry_append_note() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+        let mut entry = WeekEntry::new(start, end);
+        assert!(!entry.append_note("   "));
+        assert_eq!(entry.note, None);
+
+        assert!(entry.append_note("  first  "));
+        assert!(entry.append_note("second\n"));
+        assert_eq!(entry.note.as_deref(), Some("first\nsecond"));
+    }
+
     #[test]
     fn test_category_from_str() {
         assert_eq!(
@@ -192,6 +875,98 @@ mod tests {
         assert!("invalid".parse::<Category>().is_err());
     }
 
+    #[test]
+    fn test_category_from_short_code() {
+        assert_eq!(
+            "is".parse::<Category>().unwrap(),
+            Category::IndividualSupervision
+        );
+        assert_eq!(
+            "gs".parse::<Category>().unwrap(),
+            Category::GroupSupervision
+        );
+        assert_eq!("d".parse::<Category>().unwrap(), Category::Direct);
+        assert_eq!("i".parse::<Category>().unwrap(), Category::Indirect);
+        assert!("IS".parse::<Category>().is_err());
+        assert!("ind".parse::<Category>().is_err());
+    }
+
+    #[test]
+    fn test_category_parse_flexible_variants_and_prefixes() {
+        let cases = [
+            ("direct", Category::Direct),
+            ("Directs", Category::Direct),
+            ("dir", Category::Direct),
+            ("indirects", Category::Indirect),
+            ("indir", Category::Indirect),
+            ("group", Category::GroupSupervision),
+            ("Group Supervision", Category::GroupSupervision),
+            ("groups", Category::GroupSupervision),
+            ("individual-supervision", Category::IndividualSupervision),
+            ("indiv", Category::IndividualSupervision),
+            ("IS", Category::IndividualSupervision),
+            (" d ", Category::Direct),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                Category::parse_flexible(input).unwrap(),
+                expected,
+                "{input:?}"
+            );
+        }
+        // The strict parser is unchanged.
+        assert!("directs".parse::<Category>().is_err());
+    }
+
+    #[test]
+    fn test_category_parse_flexible_rejects_ambiguous_and_unknown() {
+        for input in ["supervision", "supervisions", "sup", "ind"] {
+            let err = Category::parse_flexible(input).unwrap_err().to_string();
+            assert!(err.starts_with("Ambiguous category"), "{input:?}: {err}");
+        }
+        let err = Category::parse_flexible("supervision")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("individual_supervision or group_supervision"),
+            "{err}"
+        );
+
+        for input in ["", "client", "x"] {
+            let err = Category::parse_flexible(input).unwrap_err().to_string();
+            assert!(err.starts_with("Invalid category"), "{input:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_category_iter_with_targets_only_direct() {
+        let licensure = LicensureConfig {
+            start_date: NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
+            total_hours_target: 3000,
+            direct_hours_target: 1200,
+            min_months: 24,
+            min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: None,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
+        };
+        let targets: Vec<_> = Category::iter_with_targets(&licensure).collect();
+        assert_eq!(
+            targets,
+            vec![
+                (Category::IndividualSupervision, None),
+                (Category::GroupSupervision, None),
+                (Category::Direct, Some(1200)),
+                (Category::Indirect, None),
+            ]
+        );
+    }
+
     #[test]
     fn test_category_display_roundtrip() {
         for cat in Category::ALL {
@@ -209,20 +984,37 @@ mod tests {
 
     #[test]
     fn test_hours_data_serde_roundtrip() {
-        let data = HoursData {
-            weeks: vec![WeekEntry {
-                start: NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
-                end: NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
-                individual_supervision: 1.0,
-                group_supervision: 2.0,
-                direct: 14.5,
-                indirect: 6.0,
-            }],
-        };
+        let data = HoursData::from_weeks(vec![WeekEntry {
+            start: NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
+            end: NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
+            individual_supervision: 1.0,
+            group_supervision: 2.0,
+            direct: 14.5,
+            indirect: 6.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: Some("Covered for a colleague, heavy caseload".to_string()),
+            target: None,
+            excluded: true,
+            days: Vec::new(),
+        }]);
         let json = serde_json::to_string_pretty(&data).unwrap();
         let deserialized: HoursData = serde_json::from_str(&json).unwrap();
-        assert_eq!(deserialized.weeks.len(), 1);
+        assert_eq!(deserialized.weeks, data.weeks);
         assert!((deserialized.weeks[0].total() - 23.5).abs() < f64::EPSILON);
+
+        // Files written before notes and exclusions existed have neither key.
+        let legacy = r#"{"weeks": [{"start": "2025-01-28", "end": "2025-02-03",
+            "individual_supervision": 1.0, "group_supervision": 0.0,
+            "direct": 2.0, "indirect": 0.0}]}"#;
+        let legacy: HoursData = serde_json::from_str(legacy).unwrap();
+        assert_eq!(legacy.weeks[0].note, None);
+        assert!(!legacy.weeks[0].excluded);
+        let json = serde_json::to_string(&legacy).unwrap();
+        assert!(!json.contains("note"));
+        assert!(!json.contains("excluded"));
     }
 
     #[test]
@@ -233,4 +1025,146 @@ mod tests {
         let deserialized: HoursData = serde_json::from_str(&json).unwrap();
         assert!(deserialized.weeks.is_empty());
     }
+
+    fn week(start: (i32, u32, u32), direct: f64) -> WeekEntry {
+        let start = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = direct;
+        entry
+    }
+
+    #[test]
+    fn test_changes_since_detects_added_removed_modified() {
+        let previous = HoursData::from_weeks(vec![
+            week((2025, 1, 28), 5.0),
+            week((2025, 2, 4), 3.0),
+            week((2025, 2, 11), 1.0),
+        ]);
+        let current = HoursData::from_weeks(vec![
+            week((2025, 1, 28), 5.0),
+            week((2025, 2, 4), 4.5),
+            week((2025, 2, 18), 2.0),
+        ]);
+
+        let changes = current.changes_since(&previous);
+        let kinds: Vec<_> = changes
+            .iter()
+            .map(|c| (c.kind(), c.start().to_string()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("modified", "2025-02-04".to_string()),
+                ("removed", "2025-02-11".to_string()),
+                ("added", "2025-02-18".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changes_since_ignores_metadata_only_changes() {
+        let previous = HoursData::from_weeks(vec![week((2025, 1, 28), 5.0)]);
+        let mut current = previous.clone();
+        current.weeks[0].entries = 3;
+        assert!(current.changes_since(&previous).is_empty());
+    }
+
+    fn merge_fixture() -> (HoursData, HoursData) {
+        let mine = HoursData::from_weeks(vec![week((2025, 1, 28), 5.0), week((2025, 2, 4), 3.0)]);
+        let theirs = HoursData::from_weeks(vec![
+            week((2025, 1, 28), 5.0),
+            week((2025, 2, 4), 2.0),
+            week((2025, 2, 11), 4.0),
+        ]);
+        (mine, theirs)
+    }
+
+    #[test]
+    fn test_merge_sum_adds_conflicting_weeks() {
+        let (mut mine, mut theirs) = merge_fixture();
+        theirs.weeks[1].note = Some("From laptop".to_string());
+        let report = mine.merge(&theirs, MergeStrategy::Sum);
+
+        assert_eq!(
+            report.added,
+            vec![NaiveDate::from_ymd_opt(2025, 2, 11).unwrap()]
+        );
+        assert_eq!(report.conflicts.len(), 1);
+        let directs: Vec<f64> = mine.weeks.iter().map(|w| w.direct).collect();
+        assert_eq!(directs, vec![5.0, 5.0, 4.0]);
+        // Summing hours leaves notes alone.
+        assert_eq!(mine.weeks[1].note, None);
+    }
+
+    #[test]
+    fn test_merge_prefer_theirs_and_mine() {
+        let (mut mine, theirs) = merge_fixture();
+        mine.merge(&theirs, MergeStrategy::PreferTheirs);
+        assert_eq!(mine.weeks[1].direct, 2.0);
+
+        let (mut mine, theirs) = merge_fixture();
+        let report = mine.merge(&theirs, MergeStrategy::PreferMine);
+        assert_eq!(mine.weeks[1].direct, 3.0);
+        assert_eq!(report.conflicts[0].theirs.direct, 2.0);
+        assert_eq!(mine.weeks.len(), 3);
+    }
+
+    #[test]
+    fn test_dedupe_sums_duplicate_weeks() {
+        let mut first = week((2025, 2, 4), 3.0);
+        first.entries = 1;
+        first.note = Some("Intake".to_string());
+        let mut second = week((2025, 2, 4), 2.5);
+        second.indirect = 1.0;
+        second.entries = 2;
+        second.note = Some("Consult".to_string());
+        let mut third = week((2025, 2, 4), 0.5);
+        third.note = Some("Intake\nFollow-up".to_string());
+        let mut data = HoursData::from_weeks(vec![
+            week((2025, 2, 11), 4.0),
+            first,
+            week((2025, 1, 28), 1.0),
+            second,
+            third,
+        ]);
+
+        let merges = data.dedupe();
+
+        assert_eq!(merges.len(), 1);
+        assert_eq!(merges[0].count, 3);
+        let starts: Vec<_> = data.weeks.iter().map(|w| w.start).collect();
+        assert_eq!(
+            starts,
+            vec![
+                NaiveDate::from_ymd_opt(2025, 1, 28).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 2, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 2, 11).unwrap(),
+            ]
+        );
+        let merged = &data.weeks[1];
+        assert_eq!(merged.direct, 6.0);
+        assert_eq!(merged.indirect, 1.0);
+        assert_eq!(merged.entries, 3);
+        assert_eq!(merged.note.as_deref(), Some("Intake\nConsult\nFollow-up"));
+        assert_eq!(&merges[0].merged, merged);
+    }
+
+    #[test]
+    fn test_dedupe_without_duplicates_is_noop() {
+        let mut data =
+            HoursData::from_weeks(vec![week((2025, 1, 28), 1.0), week((2025, 2, 4), 2.0)]);
+        let before = data.clone();
+        assert!(data.dedupe().is_empty());
+        assert_eq!(data.weeks, before.weeks);
+    }
+
+    #[test]
+    fn test_merge_strategy_from_str() {
+        assert_eq!("sum".parse::<MergeStrategy>().unwrap(), MergeStrategy::Sum);
+        assert_eq!(
+            "prefer-theirs".parse::<MergeStrategy>().unwrap(),
+            MergeStrategy::PreferTheirs
+        );
+        assert!("newest".parse::<MergeStrategy>().is_err());
+    }
 }
@@ -0,0 +1,1174 @@
+use std::fmt;
+
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Serialize, Serializer};
+
+use super::model::{normalize_hours, Category, HoursData, WeekEntry, SCHEMA_VERSION};
+use super::week;
+use crate::config::LicensureConfig;
+use crate::display;
+
+fn rounded<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(display::round(*value))
+}
+
+fn rounded_option<S: Serializer>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_f64(display::round(*value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn percentage(current: f64, target: f64) -> f64 {
+    if target > 0.0 {
+        current / target * 100.0
+    } else {
+        0.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HoursProgress {
+    #[serde(serialize_with = "rounded")]
+    pub current: f64,
+    pub target: u32,
+    #[serde(serialize_with = "rounded")]
+    pub percentage: f64,
+}
+
+// Running total for one category. Only categories with a board minimum
+// (`LicensureConfig::category_target`) carry a target and percentage.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct CategoryProgress {
+    #[serde(skip)]
+    pub category: Category,
+    #[serde(serialize_with = "rounded")]
+    pub current: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<u32>,
+    #[serde(
+        serialize_with = "rounded_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub percentage: Option<f64>,
+}
+
+// Serialized as an object keyed by category, in `Category::ALL` order.
+fn category_map<S: Serializer>(
+    categories: &[CategoryProgress],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(categories.iter().map(|c| (c.category.to_string(), c)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MonthsProgress {
+    pub current: u32,
+    pub target: u32,
+    #[serde(serialize_with = "rounded")]
+    pub percentage: f64,
+    #[serde(skip)]
+    pub inclusive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AverageProgress {
+    #[serde(serialize_with = "rounded")]
+    pub current: f64,
+    pub target: f64,
+    #[serde(serialize_with = "rounded")]
+    pub percentage: f64,
+    #[serde(skip)]
+    pub weeks_elapsed: u32,
+    #[serde(skip)]
+    pub weeks_excluded: u32,
+}
+
+// The weekly average over only the most recent `weeks` calendar weeks.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WindowAverage {
+    pub weeks: u32,
+    #[serde(flatten)]
+    pub average: AverageProgress,
+}
+
+// Hours logged so far in today's calendar month, for boards with a monthly quota.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MonthlyProgress {
+    #[serde(serialize_with = "month_key")]
+    pub month: NaiveDate,
+    #[serde(serialize_with = "rounded")]
+    pub current: f64,
+    pub target: f64,
+    #[serde(serialize_with = "rounded")]
+    pub shortfall: f64,
+    #[serde(serialize_with = "rounded")]
+    pub percentage: f64,
+}
+
+fn month_key<S: Serializer>(month: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&month.format("%Y-%m").to_string())
+}
+
+// The in-progress week, zero-filled until something is logged for it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CurrentWeek {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    #[serde(serialize_with = "rounded")]
+    pub individual_supervision: f64,
+    #[serde(serialize_with = "rounded")]
+    pub group_supervision: f64,
+    #[serde(serialize_with = "rounded")]
+    pub direct: f64,
+    #[serde(serialize_with = "rounded")]
+    pub indirect: f64,
+    #[serde(serialize_with = "rounded")]
+    pub total: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl From<&WeekEntry> for CurrentWeek {
+    fn from(entry: &WeekEntry) -> Self {
+        CurrentWeek {
+            start: entry.start,
+            end: entry.end,
+            individual_supervision: entry.individual_supervision,
+            group_supervision: entry.group_supervision,
+            direct: entry.direct,
+            indirect: entry.indirect,
+            total: entry.total(),
+            note: entry.note.clone(),
+        }
+    }
+}
+
+// Completed weeks whose direct hours reached their own target (or the
+// configured weekly average when the week has none).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WeeklyTargets {
+    pub met: usize,
+    pub completed: usize,
+}
+
+// A what-if: `weeks` more weeks of `rate` direct hours each on top of the
+// current totals. Direct hours count toward the total too.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Forecast {
+    pub weeks: u32,
+    #[serde(serialize_with = "rounded")]
+    pub rate: f64,
+    pub date: NaiveDate,
+    pub total_hours: HoursProgress,
+    pub direct_hours: HoursProgress,
+    pub months: MonthsProgress,
+    pub weekly_average: AverageProgress,
+    pub targets_met: ForecastTargets,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ForecastTargets {
+    pub total_hours: bool,
+    pub direct_hours: bool,
+    pub months: bool,
+    pub weekly_average: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EntryStats {
+    pub count: u32,
+    #[serde(serialize_with = "rounded")]
+    pub average_hours: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Progress {
+    // `model::SCHEMA_VERSION`, so `summary --json` consumers can detect format changes.
+    pub schema_version: u32,
+    pub total_hours: HoursProgress,
+    pub direct_hours: HoursProgress,
+    pub months: MonthsProgress,
+    pub weekly_average: AverageProgress,
+    // Both set by `summary --window`; `weekly_average` stays the overall figure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_average_overall: Option<AverageProgress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekly_average_window: Option<WindowAverage>,
+    #[serde(serialize_with = "category_map")]
+    pub by_category: Vec<CategoryProgress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_month: Option<MonthlyProgress>,
+    // The binding constraint: whichever of hours and months is further behind.
+    #[serde(serialize_with = "rounded")]
+    pub overall_percentage: f64,
+    pub weeks_logged: usize,
+    pub weekly_targets: WeeklyTargets,
+    // Hours ahead (positive) or behind (negative) of the straight line from
+    // zero on the start date to the total target after `min_months`. None
+    // before the start date or when `min_months` is 0.
+    #[serde(serialize_with = "rounded_option")]
+    pub pace_delta: Option<f64>,
+    pub current_week: CurrentWeek,
+    pub entries: EntryStats,
+    pub start_date: NaiveDate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_week_start: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_week_end: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forecast: Option<Forecast>,
+    #[serde(skip)]
+    pub as_of: NaiveDate,
+}
+
+impl Progress {
+    pub fn compute(data: &HoursData, config: &LicensureConfig, today: NaiveDate) -> Self {
+        let start_date = config.start_date;
+
+        let total_hours = data.total();
+        let by_category: Vec<CategoryProgress> = Category::iter_with_targets(config)
+            .map(|(category, target)| {
+                let current = normalize_hours(data.weeks.iter().map(|w| w.get(category)).sum());
+                CategoryProgress {
+                    category,
+                    current,
+                    target,
+                    percentage: target.map(|t| percentage(current, t as f64)),
+                }
+            })
+            .collect();
+        let direct_hours = by_category
+            .iter()
+            .find(|c| c.category == Category::Direct)
+            .map_or(0.0, |c| c.current);
+
+        let months = months_between(start_date, today, config.months_inclusive);
+
+        let (current_week_start, current_week_end) =
+            week::current_week(today, config.week_start_day());
+        let current_week = match data.weeks.iter().find(|w| w.start == current_week_start) {
+            Some(entry) => CurrentWeek::from(entry),
+            None => CurrentWeek::from(&WeekEntry::new(current_week_start, current_week_end)),
+        };
+        // Excluded break weeks drop out of the denominator, never below one week.
+        let weeks_excluded = data
+            .weeks
+            .iter()
+            .filter(|w| w.excluded && w.start >= start_date && w.start <= current_week_start)
+            .count() as i64;
+        let weeks_elapsed = if current_week_start >= start_date {
+            (((current_week_start - start_date).num_days() / 7) + 1 - weeks_excluded).max(1)
+        } else {
+            1
+        };
+        let weekly_average = direct_hours / weeks_elapsed as f64;
+
+        let entries_logged: u32 = data.weeks.iter().map(|w| w.entries).sum();
+        let entry_hours = normalize_hours(data.weeks.iter().map(|w| w.entry_hours()).sum());
+        let average_per_entry = if entries_logged > 0 {
+            entry_hours / entries_logged as f64
+        } else {
+            0.0
+        };
+
+        let current_month = config.min_monthly_hours.map(|target| {
+            let month = today.with_day(1).unwrap();
+            // Weeks are attributed to the month their start date falls in.
+            let current = normalize_hours(
+                data.weeks
+                    .iter()
+                    .filter(|w| w.start.year() == today.year() && w.start.month() == today.month())
+                    .map(|w| w.total())
+                    .sum(),
+            );
+            MonthlyProgress {
+                month,
+                current,
+                target,
+                shortfall: (target - current).max(0.0),
+                percentage: percentage(current, target),
+            }
+        });
+
+        let completed: Vec<&WeekEntry> = data
+            .weeks
+            .iter()
+            .filter(|w| w.end < today && !w.excluded)
+            .collect();
+        let weekly_targets = WeeklyTargets {
+            met: completed
+                .iter()
+                .filter(|w| w.meets_target(config.min_weekly_average))
+                .count(),
+            completed: completed.len(),
+        };
+
+        let pace = target_pace(config);
+        let pace_delta = (pace > 0.0 && today >= start_date).then(|| {
+            let weeks = (today - start_date).num_days() as f64 / 7.0;
+            total_hours - (pace * weeks).min(config.total_hours_target as f64)
+        });
+
+        let total_percentage = percentage(total_hours, config.total_hours_target as f64);
+        let months_percentage = percentage(months as f64, config.min_months as f64);
+
+        Progress {
+            schema_version: SCHEMA_VERSION,
+            total_hours: HoursProgress {
+                current: total_hours,
+                target: config.total_hours_target,
+                percentage: total_percentage,
+            },
+            direct_hours: HoursProgress {
+                current: direct_hours,
+                target: config.direct_hours_target,
+                percentage: percentage(direct_hours, config.direct_hours_target as f64),
+            },
+            months: MonthsProgress {
+                current: months,
+                target: config.min_months,
+                percentage: months_percentage,
+                inclusive: config.months_inclusive,
+            },
+            weekly_average: AverageProgress {
+                current: weekly_average,
+                target: config.min_weekly_average,
+                percentage: percentage(weekly_average, config.min_weekly_average),
+                weeks_elapsed: weeks_elapsed as u32,
+                weeks_excluded: weeks_excluded as u32,
+            },
+            weekly_average_overall: None,
+            weekly_average_window: None,
+            by_category,
+            current_month,
+            overall_percentage: total_percentage.min(months_percentage),
+            weeks_logged: data.weeks.iter().filter(|w| w.total() > 0.0).count(),
+            weekly_targets,
+            pace_delta,
+            current_week,
+            entries: EntryStats {
+                count: entries_logged,
+                average_hours: average_per_entry,
+            },
+            start_date,
+            latest_week_start: data.weeks.last().map(|w| w.start),
+            latest_week_end: data.weeks.last().map(|w| w.end),
+            forecast: None,
+            as_of: today,
+        }
+    }
+
+    pub fn set_window(&mut self, window: WindowAverage) {
+        self.weekly_average_overall = Some(self.weekly_average);
+        self.weekly_average_window = Some(window);
+    }
+
+    pub fn forecast(&self, config: &LicensureConfig, weeks: u32, rate: f64) -> Forecast {
+        let added = rate * weeks as f64;
+        let total = self.total_hours.current + added;
+        let direct = self.direct_hours.current + added;
+        let date = self.as_of + chrono::Duration::weeks(weeks.into());
+        let months = months_between(self.start_date, date, config.months_inclusive);
+        let weeks_elapsed = self.weekly_average.weeks_elapsed + weeks;
+        let average = direct / weeks_elapsed as f64;
+
+        Forecast {
+            weeks,
+            rate,
+            date,
+            total_hours: HoursProgress {
+                current: total,
+                target: config.total_hours_target,
+                percentage: percentage(total, config.total_hours_target as f64),
+            },
+            direct_hours: HoursProgress {
+                current: direct,
+                target: config.direct_hours_target,
+                percentage: percentage(direct, config.direct_hours_target as f64),
+            },
+            months: MonthsProgress {
+                current: months,
+                target: config.min_months,
+                percentage: percentage(months as f64, config.min_months as f64),
+                inclusive: config.months_inclusive,
+            },
+            weekly_average: AverageProgress {
+                current: average,
+                target: config.min_weekly_average,
+                percentage: percentage(average, config.min_weekly_average),
+                weeks_elapsed,
+                weeks_excluded: self.weekly_average.weeks_excluded,
+            },
+            targets_met: ForecastTargets {
+                total_hours: total >= config.total_hours_target as f64,
+                direct_hours: direct >= config.direct_hours_target as f64,
+                months: months >= config.min_months,
+                weekly_average: average >= config.min_weekly_average,
+            },
+        }
+    }
+
+    // One line per metric spelling out the inputs and formula behind it, using
+    // the same rounded values the summary prints.
+    pub fn explanations(&self) -> Vec<String> {
+        let hours = display::hours;
+        let pct = |value: f64| format!("{:.1}%", display::round(value));
+
+        let mut lines = vec![
+            format!(
+                "total hours = sum of all categories over {} logged week(s) = {}; {} / {} = {}",
+                self.weeks_logged,
+                hours(self.total_hours.current),
+                hours(self.total_hours.current),
+                self.total_hours.target,
+                pct(self.total_hours.percentage)
+            ),
+            format!(
+                "direct hours = sum of direct hours = {}; {} / {} = {}",
+                hours(self.direct_hours.current),
+                hours(self.direct_hours.current),
+                self.direct_hours.target,
+                pct(self.direct_hours.percentage)
+            ),
+            format!(
+                "months = {} from {} to {} = {}; {} / {} = {}",
+                if self.months.inclusive {
+                    "calendar months, partial month rounded up,"
+                } else {
+                    "full calendar months"
+                },
+                self.start_date,
+                self.as_of,
+                self.months.current,
+                self.months.current,
+                self.months.target,
+                pct(self.months.percentage)
+            ),
+            format!(
+                "weekly average = direct {} / weeks elapsed {}{} = {}; {} / {:.1} = {}",
+                hours(self.direct_hours.current),
+                self.weekly_average.weeks_elapsed,
+                match self.weekly_average.weeks_excluded {
+                    0 => String::new(),
+                    n => format!(" ({n} excluded)"),
+                },
+                hours(self.weekly_average.current),
+                hours(self.weekly_average.current),
+                self.weekly_average.target,
+                pct(self.weekly_average.percentage)
+            ),
+        ];
+        if let Some(month) = &self.current_month {
+            lines.push(format!(
+                "{} so far = sum of weeks starting in {} = {}; shortfall = max({:.1} - {}, 0) = {}",
+                month.month.format("%b %Y"),
+                month.month.format("%B"),
+                hours(month.current),
+                month.target,
+                hours(month.current),
+                hours(month.shortfall)
+            ));
+        }
+        lines.push(format!(
+            "overall = lower of total {} and months {} = {}",
+            pct(self.total_hours.percentage),
+            pct(self.months.percentage),
+            pct(self.overall_percentage)
+        ));
+        lines
+    }
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Total supervised hours: {:>8.1} / {:<6} ({:>5.1}%)",
+            display::round(self.total_hours.current),
+            self.total_hours.target,
+            display::round(self.total_hours.percentage)
+        )?;
+        writeln!(
+            f,
+            "Direct client hours:   {:>8.1} / {:<6} ({:>5.1}%)",
+            display::round(self.direct_hours.current),
+            self.direct_hours.target,
+            display::round(self.direct_hours.percentage)
+        )?;
+        writeln!(
+            f,
+            "Months of experience:  {:>8}   / {:>4}   ({:>5.1}%)",
+            self.months.current,
+            self.months.target,
+            display::round(self.months.percentage)
+        )?;
+        write!(
+            f,
+            "Weekly average:        {:>8.1} / {:>6.1} ({:>5.1}%)",
+            display::round(self.weekly_average.current),
+            self.weekly_average.target,
+            display::round(self.weekly_average.percentage)
+        )?;
+        if let Some(window) = &self.weekly_average_window {
+            write!(
+                f,
+                "\n{:<23}{:>8.1} / {:>6.1} ({:>5.1}%)",
+                format!("Last {} weeks average:", window.weeks),
+                display::round(window.average.current),
+                window.average.target,
+                display::round(window.average.percentage)
+            )?;
+        }
+        if let Some(month) = &self.current_month {
+            let status = if month.shortfall > 0.0 {
+                format!("{} short", display::hours(month.shortfall))
+            } else {
+                "met".to_string()
+            };
+            write!(
+                f,
+                "\n{:<23}{:>8.1} / {:>6.1} ({status})",
+                format!("{} so far:", month.month.format("%b %Y")),
+                display::round(month.current),
+                month.target
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Forecast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let met = |yes: bool| if yes { "met" } else { "not met" };
+        writeln!(
+            f,
+            "Forecast: {} more week(s) at {} hrs/wk (through {})",
+            self.weeks,
+            display::hours(self.rate),
+            self.date.format("%b %d, %Y")
+        )?;
+        writeln!(
+            f,
+            "Total supervised hours: {:>8.1} / {:<6} ({:>5.1}%) {}",
+            display::round(self.total_hours.current),
+            self.total_hours.target,
+            display::round(self.total_hours.percentage),
+            met(self.targets_met.total_hours)
+        )?;
+        writeln!(
+            f,
+            "Direct client hours:   {:>8.1} / {:<6} ({:>5.1}%) {}",
+            display::round(self.direct_hours.current),
+            self.direct_hours.target,
+            display::round(self.direct_hours.percentage),
+            met(self.targets_met.direct_hours)
+        )?;
+        writeln!(
+            f,
+            "Months of experience:  {:>8}   / {:>4}   ({:>5.1}%) {}",
+            self.months.current,
+            self.months.target,
+            display::round(self.months.percentage),
+            met(self.targets_met.months)
+        )?;
+        write!(
+            f,
+            "Weekly average:        {:>8.1} / {:>6.1} ({:>5.1}%) {}",
+            display::round(self.weekly_average.current),
+            self.weekly_average.target,
+            display::round(self.weekly_average.percentage),
+            met(self.targets_met.weekly_average)
+        )
+    }
+}
+
+// Full months from `start` to `end` by day of month. With `inclusive`, a
+// trailing partial month counts as a whole one, as some boards require.
+fn months_between(start: NaiveDate, end: NaiveDate, inclusive: bool) -> u32 {
+    if end < start {
+        return 0;
+    }
+    let year_diff = end.year() - start.year();
+    let month_diff = end.month() as i32 - start.month() as i32;
+    let mut months = year_diff * 12 + month_diff;
+    if end.day() < start.day() {
+        months -= 1;
+    }
+    let full = months.max(0) as u32;
+
+    let partial = start
+        .checked_add_months(Months::new(full))
+        .is_some_and(|anniversary| anniversary < end);
+    if inclusive && partial {
+        full + 1
+    } else {
+        full
+    }
+}
+
+// Direct hours per week over the last `weeks` calendar weeks from
+// `week::all_weeks`, current week included. The window never reaches before
+// the start date, and excluded weeks drop out of it like they do overall.
+pub fn window_average(
+    data: &HoursData,
+    config: &LicensureConfig,
+    today: NaiveDate,
+    weeks: u32,
+) -> WindowAverage {
+    let all = week::all_weeks(config.start_date, today, config.week_start_day());
+    let window = &all[all.len().saturating_sub(weeks as usize)..];
+    let in_window: Vec<&WeekEntry> = match (window.first(), window.last()) {
+        (Some((first, _)), Some((_, last))) => data
+            .weeks
+            .iter()
+            .filter(|w| w.start >= *first && w.start <= *last)
+            .collect(),
+        _ => Vec::new(),
+    };
+    let weeks_excluded = in_window.iter().filter(|w| w.excluded).count() as u32;
+    let weeks_elapsed = (window.len() as u32).saturating_sub(weeks_excluded).max(1);
+    let direct = normalize_hours(in_window.iter().map(|w| w.direct).sum());
+    let current = direct / weeks_elapsed as f64;
+
+    WindowAverage {
+        weeks,
+        average: AverageProgress {
+            current,
+            target: config.min_weekly_average,
+            percentage: percentage(current, config.min_weekly_average),
+            weeks_elapsed,
+            weeks_excluded,
+        },
+    }
+}
+
+// Hours per week needed to reach the total target in exactly `min_months`.
+pub fn target_pace(config: &LicensureConfig) -> f64 {
+    if config.min_months == 0 {
+        return 0.0;
+    }
+    let weeks = config.min_months as f64 * 52.0 / 12.0;
+    config.total_hours_target as f64 / weeks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::model::WeekEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn config() -> LicensureConfig {
+        LicensureConfig {
+            start_date: date(2025, 1, 28),
+            total_hours_target: 3000,
+            direct_hours_target: 1200,
+            min_months: 24,
+            min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: None,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
+        }
+    }
+
+    fn week(start: NaiveDate, direct: f64, indirect: f64, entries: u32) -> WeekEntry {
+        let mut entry = WeekEntry::new(start, start + chrono::Duration::days(6));
+        entry.direct = direct;
+        entry.indirect = indirect;
+        entry.entries = entries;
+        entry
+    }
+
+    #[test]
+    fn test_weekly_targets_use_week_override() {
+        let mut heavy = week(date(2025, 1, 28), 20.0, 0.0, 1);
+        heavy.target = Some(25.0);
+        let mut exams = week(date(2025, 2, 4), 6.0, 0.0, 1);
+        exams.target = Some(5.0);
+        let regular = week(date(2025, 2, 11), 16.0, 0.0, 1);
+        // In progress on `today`, so not judged yet.
+        let current = week(date(2025, 2, 18), 0.0, 0.0, 0);
+        let data = HoursData::from_weeks(vec![heavy, exams, regular, current]);
+
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 20));
+        // 20 < 25 misses despite beating the 15.0 average; 6 >= 5 meets
+        // despite falling short of it.
+        assert_eq!(
+            progress.weekly_targets,
+            WeeklyTargets {
+                met: 2,
+                completed: 3
+            }
+        );
+    }
+
+    fn pace_config() -> LicensureConfig {
+        // 12 months is 52 weeks, so 1040 hours is a 20 hrs/wk pace.
+        LicensureConfig {
+            total_hours_target: 1040,
+            min_months: 12,
+            ..config()
+        }
+    }
+
+    #[test]
+    fn test_pace_delta_ahead() {
+        let data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 30.0, 5.0, 1),
+            week(date(2025, 2, 4), 20.0, 10.0, 1),
+        ]);
+        // Two weeks in: 40 expected, 65 logged.
+        let progress = Progress::compute(&data, &pace_config(), date(2025, 2, 11));
+        assert_eq!(progress.pace_delta, Some(25.0));
+    }
+
+    #[test]
+    fn test_pace_delta_behind_counts_partial_weeks() {
+        let data = HoursData::from_weeks(vec![week(date(2025, 1, 28), 10.0, 0.0, 1)]);
+        // Eleven days in: 11/7 weeks * 20 = 31.4 expected, 10 logged.
+        let progress = Progress::compute(&data, &pace_config(), date(2025, 2, 8));
+        let delta = progress.pace_delta.unwrap();
+        assert_eq!(display::round(delta), -21.4);
+    }
+
+    #[test]
+    fn test_pace_delta_before_start_and_past_window() {
+        let progress = Progress::compute(&HoursData::new(), &pace_config(), date(2025, 1, 27));
+        assert_eq!(progress.pace_delta, None);
+        assert!(serde_json::to_value(&progress).unwrap()["pace_delta"].is_null());
+
+        // Expected hours stop growing at the target once the window has passed.
+        let data = HoursData::from_weeks(vec![week(date(2025, 1, 28), 1100.0, 0.0, 1)]);
+        let progress = Progress::compute(&data, &pace_config(), date(2027, 6, 1));
+        assert_eq!(progress.pace_delta, Some(60.0));
+
+        let mut config = pace_config();
+        config.min_months = 0;
+        let progress = Progress::compute(&data, &config, date(2025, 2, 11));
+        assert_eq!(progress.pace_delta, None);
+    }
+
+    #[test]
+    fn test_forecast_projects_known_standing() {
+        // Two weeks logged with 20 direct and 10 other hours each.
+        let data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 20.0, 10.0, 1),
+            week(date(2025, 2, 4), 20.0, 10.0, 1),
+        ]);
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 10));
+        let forecast = progress.forecast(&config(), 50, 20.0);
+
+        assert_eq!(forecast.date, date(2026, 1, 26));
+        assert_eq!(forecast.total_hours.current, 1060.0);
+        assert_eq!(forecast.direct_hours.current, 1040.0);
+        assert_eq!(forecast.months.current, 11);
+        assert_eq!(forecast.weekly_average.current, 20.0);
+        assert_eq!(
+            forecast.targets_met,
+            ForecastTargets {
+                total_hours: false,
+                direct_hours: false,
+                months: false,
+                weekly_average: true,
+            }
+        );
+
+        let forecast = progress.forecast(&config(), 60, 20.0);
+        assert_eq!(forecast.direct_hours.current, 1240.0);
+        assert!(forecast.targets_met.direct_hours);
+    }
+
+    #[test]
+    fn test_forecast_serializes_under_progress() {
+        let mut progress = Progress::compute(&HoursData::new(), &config(), date(2025, 2, 10));
+        let json = serde_json::to_value(&progress).unwrap();
+        assert!(json.get("forecast").is_none());
+
+        progress.forecast = Some(progress.forecast(&config(), 4, 12.5));
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["forecast"]["weeks"], 4);
+        assert_eq!(json["forecast"]["total_hours"]["current"], 50.0);
+        assert_eq!(json["forecast"]["targets_met"]["weekly_average"], false);
+    }
+
+    #[test]
+    fn test_months_between_same_date() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 1, 28), false),
+            0
+        );
+    }
+
+    #[test]
+    fn test_months_between_one_month() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 2, 28), false),
+            1
+        );
+    }
+
+    #[test]
+    fn test_months_between_partial_month() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 2, 27), false),
+            0
+        );
+    }
+
+    #[test]
+    fn test_months_between_several_months() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 6, 28), false),
+            5
+        );
+    }
+
+    #[test]
+    fn test_months_between_across_years() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2027, 1, 28), false),
+            24
+        );
+    }
+
+    #[test]
+    fn test_months_between_inclusive_rounds_up_partial_month() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 2, 27), false),
+            0
+        );
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 2, 27), true),
+            1
+        );
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 3, 14), false),
+            1
+        );
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 3, 14), true),
+            2
+        );
+    }
+
+    #[test]
+    fn test_months_between_inclusive_exact_and_empty_spans() {
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 2, 28), true),
+            1
+        );
+        assert_eq!(
+            months_between(date(2025, 1, 28), date(2025, 1, 28), true),
+            0
+        );
+        assert_eq!(
+            months_between(date(2025, 1, 31), date(2025, 2, 28), true),
+            1
+        );
+        assert_eq!(months_between(date(2025, 6, 1), date(2025, 1, 1), true), 0);
+    }
+
+    #[test]
+    fn test_compute_respects_months_inclusive() {
+        let mut config = config();
+        let today = date(2025, 3, 14);
+        assert_eq!(
+            Progress::compute(&HoursData::new(), &config, today)
+                .months
+                .current,
+            1
+        );
+        config.months_inclusive = true;
+        assert_eq!(
+            Progress::compute(&HoursData::new(), &config, today)
+                .months
+                .current,
+            2
+        );
+    }
+
+    #[test]
+    fn test_months_between_end_before_start() {
+        assert_eq!(months_between(date(2025, 6, 1), date(2025, 1, 1), false), 0);
+    }
+
+    #[test]
+    fn test_compute_known_data() {
+        let data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 10.0, 5.0, 2),
+            week(date(2025, 2, 4), 8.0, 0.0, 0),
+        ]);
+        // Fifth week of tracking (Feb 25 week), one month in.
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 28));
+
+        assert_eq!(progress.total_hours.current, 23.0);
+        assert_eq!(progress.total_hours.target, 3000);
+        assert!((progress.total_hours.percentage - 23.0 / 30.0).abs() < 1e-9);
+        assert_eq!(progress.direct_hours.current, 18.0);
+        assert!((progress.direct_hours.percentage - 1.5).abs() < 1e-9);
+        assert_eq!(progress.months.current, 1);
+        assert!((progress.weekly_average.current - 3.6).abs() < 1e-9);
+        assert!((progress.weekly_average.percentage - 24.0).abs() < 1e-9);
+        assert_eq!(progress.weeks_logged, 2);
+        assert_eq!(progress.entries.count, 2);
+        assert_eq!(progress.entries.average_hours, 7.5);
+        assert_eq!(progress.latest_week_start, Some(date(2025, 2, 4)));
+        assert_eq!(progress.latest_week_end, Some(date(2025, 2, 10)));
+    }
+
+    #[test]
+    fn test_entry_average_skips_edited_hours() {
+        let mut added = week(date(2025, 1, 28), 0.0, 0.0, 0);
+        added.add(Category::Direct, 3.0);
+        added.record_entry(3.0);
+        added.add(Category::Direct, 1.0);
+        added.record_entry(1.0);
+        // Set with `edit` on top of the two adds.
+        added.set(Category::Indirect, 20.0);
+        let data = HoursData::from_weeks(vec![added, week(date(2025, 2, 4), 8.0, 0.0, 0)]);
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 28));
+
+        assert_eq!(progress.entries.count, 2);
+        assert_eq!(progress.entries.average_hours, 2.0);
+    }
+
+    #[test]
+    fn test_by_category_totals_and_direct_target() {
+        let mut first = week(date(2025, 1, 28), 10.0, 5.0, 1);
+        first.individual_supervision = 1.0;
+        let mut second = week(date(2025, 2, 4), 8.0, 0.0, 1);
+        second.group_supervision = 2.5;
+        let data = HoursData::from_weeks(vec![first, second]);
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 28));
+
+        let totals: Vec<_> = progress
+            .by_category
+            .iter()
+            .map(|c| (c.category, c.current, c.target))
+            .collect();
+        assert_eq!(
+            totals,
+            vec![
+                (Category::IndividualSupervision, 1.0, None),
+                (Category::GroupSupervision, 2.5, None),
+                (Category::Direct, 18.0, Some(1200)),
+                (Category::Indirect, 5.0, None),
+            ]
+        );
+        assert_eq!(progress.direct_hours.current, 18.0);
+
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["by_category"]["group_supervision"]["current"], 2.5);
+        assert_eq!(json["by_category"]["direct"]["percentage"], 1.5);
+        assert!(json["by_category"]["indirect"].get("target").is_none());
+        assert!(json["by_category"]["indirect"].get("percentage").is_none());
+    }
+
+    #[test]
+    fn test_excluded_zero_week_raises_average() {
+        let mut data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 10.0, 5.0, 2),
+            week(date(2025, 2, 4), 0.0, 0.0, 0),
+            week(date(2025, 2, 11), 10.0, 0.0, 0),
+        ]);
+        // Third week of tracking: 20 direct over 3 weeks.
+        let today = date(2025, 2, 14);
+        let before = Progress::compute(&data, &config(), today);
+        assert_eq!(before.weekly_average.weeks_elapsed, 3);
+
+        data.weeks[1].excluded = true;
+        let after = Progress::compute(&data, &config(), today);
+        assert_eq!(after.weekly_average.weeks_elapsed, 2);
+        assert_eq!(after.weekly_average.weeks_excluded, 1);
+        assert_eq!(after.weekly_average.current, 10.0);
+        assert!(after.weekly_average.current > before.weekly_average.current);
+        // The break week no longer counts as a missed weekly target either.
+        assert_eq!(after.weekly_targets.completed, 1);
+    }
+
+    #[test]
+    fn test_window_average_covers_recent_weeks() {
+        let mut data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 30.0, 0.0, 0),
+            week(date(2025, 2, 4), 6.0, 0.0, 0),
+            week(date(2025, 2, 11), 0.0, 0.0, 0),
+            week(date(2025, 2, 18), 12.0, 0.0, 0),
+        ]);
+        let today = date(2025, 2, 20);
+
+        // Last 2 weeks: 12 direct over 2 weeks.
+        let window = window_average(&data, &config(), today, 2);
+        assert_eq!(window.weeks, 2);
+        assert_eq!(window.average.weeks_elapsed, 2);
+        assert_eq!(window.average.current, 6.0);
+        assert_eq!(window.average.percentage, 40.0);
+
+        data.weeks[2].excluded = true;
+        let window = window_average(&data, &config(), today, 2);
+        assert_eq!(window.average.current, 12.0);
+
+        // A window longer than tracking is clipped to the start date.
+        let window = window_average(&data, &config(), today, 52);
+        assert_eq!(window.average.weeks_elapsed, 3);
+        assert_eq!(window.average.current, 16.0);
+
+        let mut progress = Progress::compute(&data, &config(), today);
+        progress.set_window(window);
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["weekly_average_window"]["weeks"], 52);
+        assert_eq!(json["weekly_average_window"]["current"], 16.0);
+        assert_eq!(json["weekly_average_overall"], json["weekly_average"]);
+    }
+
+    #[test]
+    fn test_overall_percentage_is_lower_constraint() {
+        let data = HoursData::from_weeks(vec![week(date(2025, 1, 28), 300.0, 300.0, 1)]);
+        // 600 / 3000 hours = 20%, 6 / 24 months = 25%.
+        let progress = Progress::compute(&data, &config(), date(2025, 7, 28));
+        assert!((progress.overall_percentage - 20.0).abs() < 1e-9);
+        assert_eq!(
+            progress.overall_percentage,
+            progress
+                .total_hours
+                .percentage
+                .min(progress.months.percentage)
+        );
+
+        // 1 / 24 months is now the lagging constraint.
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 28));
+        assert!((progress.overall_percentage - 100.0 / 24.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_current_month_shortfall() {
+        let mut config = config();
+        config.min_monthly_hours = Some(40.0);
+        let data = HoursData::from_weeks(vec![
+            // Starts in February; does not count toward March.
+            week(date(2025, 2, 25), 20.0, 0.0, 1),
+            week(date(2025, 3, 4), 8.0, 2.0, 1),
+            week(date(2025, 3, 11), 6.5, 0.0, 1),
+        ]);
+        let progress = Progress::compute(&data, &config, date(2025, 3, 14));
+        let month = progress.current_month.unwrap();
+        assert_eq!(month.month, date(2025, 3, 1));
+        assert_eq!(month.current, 16.5);
+        assert_eq!(month.shortfall, 23.5);
+        assert!(progress.to_string().contains("Mar 2025 so far:"));
+        assert!(progress.to_string().contains("23.5 short"));
+
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["current_month"]["month"], "2025-03");
+        assert_eq!(json["current_month"]["shortfall"], 23.5);
+
+        config.min_monthly_hours = Some(10.0);
+        let progress = Progress::compute(&data, &config, date(2025, 3, 14));
+        assert_eq!(progress.current_month.unwrap().shortfall, 0.0);
+        assert!(progress.to_string().contains("(met)"));
+    }
+
+    #[test]
+    fn test_current_month_absent_without_minimum() {
+        let progress = Progress::compute(&HoursData::new(), &config(), date(2025, 3, 14));
+        assert!(progress.current_month.is_none());
+        let json = serde_json::to_value(&progress).unwrap();
+        assert!(json.get("current_month").is_none());
+    }
+
+    #[test]
+    fn test_current_week_reflects_logged_hours() {
+        let mut current = week(date(2025, 3, 4), 6.0, 1.5, 2);
+        current.group_supervision = 2.0;
+        let data = HoursData::from_weeks(vec![week(date(2025, 2, 25), 9.0, 0.0, 1), current]);
+        let progress = Progress::compute(&data, &config(), date(2025, 3, 6));
+        assert_eq!(progress.current_week.start, date(2025, 3, 4));
+        assert_eq!(progress.current_week.end, date(2025, 3, 10));
+        assert_eq!(progress.current_week.direct, 6.0);
+        assert_eq!(progress.current_week.group_supervision, 2.0);
+        assert_eq!(progress.current_week.total, 9.5);
+
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["current_week"]["start"], "2025-03-04");
+        assert_eq!(json["current_week"]["indirect"], 1.5);
+        assert_eq!(json["current_week"]["individual_supervision"], 0.0);
+        assert!(json["current_week"].get("note").is_none());
+    }
+
+    #[test]
+    fn test_current_week_zero_filled_when_absent() {
+        let data = HoursData::from_weeks(vec![week(date(2025, 2, 25), 9.0, 0.0, 1)]);
+        let progress = Progress::compute(&data, &config(), date(2025, 3, 10));
+        assert_eq!(progress.current_week.start, date(2025, 3, 4));
+        assert_eq!(progress.current_week.total, 0.0);
+    }
+
+    #[test]
+    fn test_compute_empty_data() {
+        let progress = Progress::compute(&HoursData::new(), &config(), date(2025, 1, 28));
+        assert!(progress.total_hours.current.is_sign_positive());
+        assert_eq!(progress.total_hours.current, 0.0);
+        assert_eq!(progress.weeks_logged, 0);
+        assert_eq!(progress.entries.average_hours, 0.0);
+        assert_eq!(progress.latest_week_start, None);
+    }
+
+    #[test]
+    fn test_compute_zero_targets() {
+        let mut config = config();
+        config.total_hours_target = 0;
+        config.min_weekly_average = 0.0;
+        let data = HoursData::from_weeks(vec![week(date(2025, 1, 28), 10.0, 0.0, 1)]);
+        let progress = Progress::compute(&data, &config, date(2025, 1, 30));
+        assert_eq!(progress.total_hours.percentage, 0.0);
+        assert_eq!(progress.weekly_average.percentage, 0.0);
+    }
+
+    #[test]
+    fn test_serialize_rounds_and_skips_missing_latest_week() {
+        let data = HoursData::from_weeks(vec![week(date(2025, 1, 28), 0.25, 0.0, 0)]);
+        let json =
+            serde_json::to_value(Progress::compute(&data, &config(), date(2025, 1, 30))).unwrap();
+        assert_eq!(json["total_hours"]["current"], 0.3);
+        assert_eq!(json["start_date"], "2025-01-28");
+        assert_eq!(json["latest_week_end"], "2025-02-03");
+
+        let json = serde_json::to_value(Progress::compute(
+            &HoursData::new(),
+            &config(),
+            date(2025, 1, 30),
+        ))
+        .unwrap();
+        assert!(json.get("latest_week_start").is_none());
+    }
+
+    #[test]
+    fn test_explanations_show_operands() {
+        let data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 10.0, 5.0, 2),
+            week(date(2025, 2, 4), 8.0, 0.0, 0),
+        ]);
+        let progress = Progress::compute(&data, &config(), date(2025, 2, 28));
+        let lines = progress.explanations();
+
+        assert!(lines[0].contains("over 2 logged week(s) = 23.0; 23.0 / 3000 = 0.8%"));
+        assert!(lines[2].contains("from 2025-01-28 to 2025-02-28 = 1; 1 / 24 = 4.2%"));
+        assert!(lines[3]
+            .contains("weekly average = direct 18.0 / weeks elapsed 5 = 3.6; 3.6 / 15.0 = 24.0%"));
+        assert!(lines
+            .last()
+            .unwrap()
+            .starts_with("overall = lower of total 0.8% and months 4.2%"));
+
+        let json = serde_json::to_value(&progress).unwrap();
+        assert!(json["weekly_average"].get("weeks_elapsed").is_none());
+        assert!(json.get("as_of").is_none());
+    }
+}
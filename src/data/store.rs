@@ -1,13 +1,117 @@
+use std::fmt;
 use std::fs::{self, File};
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use anyhow::{bail, Context, Result};
-use chrono::{Datelike, Weekday};
+use chrono::Weekday;
+use log::{debug, warn};
 
-use super::model::HoursData;
+use super::model::{Category, HoursData, SCHEMA_VERSION};
+use super::week;
 
+// The data file failed to load, but `candidate` (an interrupted save or a
+// backup generation) still reads. Nothing is rewritten until the user asks
+// with `--recover`.
+#[derive(Debug)]
+pub struct RecoverableError {
+    pub path: PathBuf,
+    pub candidate: PathBuf,
+    pub cause: anyhow::Error,
+}
+
+impl fmt::Display for RecoverableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} could not be loaded ({:#}). {} holds a readable copy; \
+             rerun with --recover to restore it",
+            self.path.display(),
+            self.cause,
+            self.candidate.display()
+        )
+    }
+}
+
+impl std::error::Error for RecoverableError {}
+
+// Never writes. A broken file with a readable backup fails with
+// `RecoverableError`; see `recover`.
 pub fn load(path: &Path) -> Result<HoursData> {
+    let started = Instant::now();
+    // A file from a newer hours is refused outright, never "recovered" from
+    // an older backup over the top of it.
+    match parse(path) {
+        Ok(data) => {
+            let data = migrate(data, path)?;
+            debug!(
+                "Loaded {} ({} weeks) in {:?}",
+                path.display(),
+                data.weeks.len(),
+                started.elapsed()
+            );
+            Ok(data)
+        }
+        Err(cause) => match candidates(path).find(|c| read(c).is_ok()) {
+            Some(candidate) => Err(RecoverableError {
+                path: path.to_path_buf(),
+                candidate,
+                cause,
+            }
+            .into()),
+            None => Err(cause),
+        },
+    }
+}
+
+// `--recover`: when the data file does not load, rewrites it from the first
+// candidate that reads and passes validation, and returns that candidate.
+// `None` when the file already loads.
+pub fn recover(path: &Path, week_start: Weekday) -> Result<Option<PathBuf>> {
+    let err = match parse(path) {
+        Ok(_) => return Ok(None),
+        Err(err) => err,
+    };
+    let Some((data, source)) = candidates(path).find_map(|candidate| {
+        let data = read(&candidate).ok()?;
+        validate(&data, week_start).ok()?;
+        Some((data, candidate))
+    }) else {
+        return Err(err);
+    };
+    warn!(
+        "Recovering {} from {} after: {err:#}",
+        path.display(),
+        source.display()
+    );
+    // No rotation: the broken file is not worth a backup slot.
+    write_atomic(path, serialize(&data, week_start)?.as_bytes())?;
+    Ok(Some(source))
+}
+
+// For read-only commands: data that breaks the week invariants (e.g. imported
+// with a Wednesday week start) is still returned, with a warning, instead of
+// being rejected. Writes keep going through `save`, which validates.
+pub fn load_lenient(path: &Path, week_start: Weekday) -> Result<HoursData> {
+    let data = load(path)?;
+    if let Err(err) = validate(&data, week_start) {
+        eprintln!(
+            "Warning: {} does not match the {}–{} week model ({err}). \
+             Showing it read-only; edits will be rejected until it is fixed.",
+            path.display(),
+            week::weekday_name(week_start),
+            week::weekday_name(week_start.pred())
+        );
+    }
+    Ok(data)
+}
+
+pub fn read(path: &Path) -> Result<HoursData> {
+    migrate(parse(path)?, path)
+}
+
+fn parse(path: &Path) -> Result<HoursData> {
     let content =
         fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
     let data: HoursData = serde_json::from_str(&content)
@@ -15,24 +119,176 @@ pub fn load(path: &Path) -> Result<HoursData> {
     Ok(data)
 }
 
-pub fn save(path: &Path, data: &HoursData) -> Result<()> {
+// Brings data written under an older `schema_version` up to `SCHEMA_VERSION`
+// in memory; the next save writes it out stamped with the new number. Files
+// without the field deserialize as version 1. Each future bump adds a step
+// here, e.g. `if data.schema_version < 2 { ... }`.
+fn migrate(mut data: HoursData, path: &Path) -> Result<HoursData> {
+    if data.schema_version > SCHEMA_VERSION {
+        bail!(
+            "{} has schema_version {}, but this version of hours only understands up to {}. \
+             Upgrade hours before using this file.",
+            path.display(),
+            data.schema_version,
+            SCHEMA_VERSION
+        );
+    }
+    data.schema_version = SCHEMA_VERSION;
+    Ok(data)
+}
+
+// `hours.json` -> `hours.json.tmp`, for whatever the data file is named.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn backup(path: &Path, generation: u32) -> PathBuf {
+    sibling(path, &format!(".bak.{generation}"))
+}
+
+// A leftover `.tmp` means a save was interrupted before the rename, so it
+// holds the newest complete write. Backup generations follow newest first,
+// then a plain `.bak` from before generations were numbered.
+fn candidates(path: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+    let generations = (1..)
+        .map(|n| backup(path, n))
+        .take_while(|candidate| candidate.exists());
+    std::iter::once(sibling(&write_target(path), ".tmp"))
+        .chain(generations)
+        .chain(std::iter::once(sibling(path, ".bak")))
+        .filter(|candidate| candidate.exists())
+}
+
+// Validates `data` and returns it as pretty JSON with weeks sorted by start.
+fn serialize(data: &HoursData, week_start: Weekday) -> Result<String> {
+    validate(data, week_start)?;
+
     let mut data = data.clone();
-    validate_and_sort(&mut data)?;
+    data.schema_version = SCHEMA_VERSION;
+    data.weeks.sort_by_key(|w| w.start);
+
+    let mut json = serde_json::to_string_pretty(&data).context("Failed to serialize data")?;
+    json.push('\n');
+    Ok(json)
+}
+
+// `backups` is how many previous versions to keep as `<path>.bak.1` (newest)
+// through `<path>.bak.N`; 0 keeps none.
+pub fn save(path: &Path, data: &HoursData, backups: u32, week_start: Weekday) -> Result<()> {
+    let started = Instant::now();
+    let json = serialize(data, week_start)?;
+    rotate_backups(path, backups)?;
+    write_atomic(path, json.as_bytes())?;
+    debug!(
+        "Saved {} ({} weeks, {} bytes) in {:?}",
+        path.display(),
+        data.weeks.len(),
+        json.len(),
+        started.elapsed()
+    );
+    Ok(())
+}
+
+// Shifts each generation up one, dropping any past `keep`, then copies the
+// current file into `.bak.1`. Nothing to do before the first save.
+fn rotate_backups(path: &Path, keep: u32) -> Result<()> {
+    if keep == 0 || !path.exists() {
+        return Ok(());
+    }
+
+    // Also clears generations left over from a larger `backups` setting.
+    let mut stale = keep;
+    while backup(path, stale).exists() {
+        let oldest = backup(path, stale);
+        fs::remove_file(&oldest)
+            .with_context(|| format!("Failed to remove {}", oldest.display()))?;
+        stale += 1;
+    }
+
+    for generation in (1..keep).rev() {
+        let from = backup(path, generation);
+        if from.exists() {
+            let to = backup(path, generation + 1);
+            fs::rename(&from, &to).with_context(|| {
+                format!("Failed to rename {} to {}", from.display(), to.display())
+            })?;
+        }
+    }
+
+    let newest = backup(path, 1);
+    fs::copy(path, &newest).with_context(|| {
+        format!(
+            "Failed to back up {} to {}",
+            path.display(),
+            newest.display()
+        )
+    })?;
+    debug!(
+        "Backed up {} to {} (keeping {keep})",
+        path.display(),
+        newest.display()
+    );
+    Ok(())
+}
 
-    let json = serde_json::to_string_pretty(&data).context("Failed to serialize data")?;
+// Puts `.bak.1` back in place of `path` and shifts the older generations down
+// one, so each call steps one save further back. `None` when there is no
+// backup to restore.
+pub fn restore_backup(path: &Path, week_start: Weekday) -> Result<Option<HoursData>> {
+    let newest = backup(path, 1);
+    if !newest.exists() {
+        return Ok(None);
+    }
+    let data = read(&newest)?;
+    let json = serialize(&data, week_start)?;
+    write_atomic(path, json.as_bytes())?;
+    fs::remove_file(&newest).with_context(|| format!("Failed to remove {}", newest.display()))?;
 
-    let tmp_path = path.with_extension("json.tmp");
+    let mut generation = 2;
+    while backup(path, generation).exists() {
+        let from = backup(path, generation);
+        let to = backup(path, generation - 1);
+        fs::rename(&from, &to)
+            .with_context(|| format!("Failed to rename {} to {}", from.display(), to.display()))?;
+        generation += 1;
+    }
+    debug!("Restored {} from {}", path.display(), newest.display());
+    Ok(Some(data))
+}
+
+// Where a write to `path` should land: through a symlinked file to its target
+// (renaming over the link would replace it with a regular file), and with a
+// symlinked directory resolved so the temp file sits beside the real file.
+fn write_target(path: &Path) -> PathBuf {
+    if let Ok(resolved) = fs::canonicalize(path) {
+        return resolved;
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) if !parent.as_os_str().is_empty() => fs::canonicalize(parent)
+            .map(|dir| dir.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+// Writes to `<path>.tmp`, fsyncs, then renames over `path`, so readers see
+// either the old file or the complete new one.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let target = write_target(path);
+    let path = target.as_path();
+    let tmp_path = sibling(path, ".tmp");
 
     let mut file = File::create(&tmp_path)
         .with_context(|| format!("Failed to create {}", tmp_path.display()))?;
-    file.write_all(json.as_bytes())
+    file.write_all(contents)
         .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
-    file.write_all(b"\n")?;
     file.sync_all()
         .with_context(|| format!("Failed to fsync {}", tmp_path.display()))?;
     drop(file);
 
-    fs::rename(&tmp_path, path).with_context(|| {
+    replace_file(&tmp_path, path, |from, to| fs::rename(from, to)).with_context(|| {
         format!(
             "Failed to rename {} to {}",
             tmp_path.display(),
@@ -43,10 +299,60 @@ pub fn save(path: &Path, data: &HoursData) -> Result<()> {
     Ok(())
 }
 
-fn validate_and_sort(data: &mut HoursData) -> Result<()> {
+// Renames `tmp` over `path`. Some synced or network folders report EXDEV even
+// for a same-directory rename; then the contents are copied over `path` and
+// fsynced instead, which is not atomic but still leaves `tmp` as a complete
+// copy for recovery until the copy has landed.
+fn replace_file(
+    tmp: &Path,
+    path: &Path,
+    rename: impl Fn(&Path, &Path) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    match rename(tmp, path) {
+        Err(err) if err.kind() == std::io::ErrorKind::CrossesDevices => {
+            warn!(
+                "Rename of {} crossed devices; copying instead",
+                tmp.display()
+            );
+            fs::copy(tmp, path)?;
+            File::open(path)?.sync_all()?;
+            fs::remove_file(tmp)
+        }
+        result => result,
+    }
+}
+
+// The stored category totals against what they aggregate: each must be
+// finite and cover the hours logged on its days (`WeekEntry::days_fit`).
+// Expects the days themselves to have been checked already.
+pub fn validate_totals(data: &HoursData) -> Result<()> {
     for entry in &data.weeks {
-        if entry.start.weekday() != Weekday::Tue {
-            bail!("Week start {} is not a Tuesday", entry.start);
+        if Category::ALL.iter().any(|c| !entry.get(*c).is_finite()) {
+            bail!("Non-finite hour values in week starting {}", entry.start);
+        }
+        for category in Category::ALL {
+            if !entry.days_fit(category) {
+                bail!(
+                    "{category} total {} for week starting {} is less than its daily entries ({})",
+                    entry.get(category),
+                    entry.start,
+                    entry.day_sum(category)
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+// `week_start` is the configured `licensure.week_start_day`.
+pub fn validate(data: &HoursData, week_start: Weekday) -> Result<()> {
+    for entry in &data.weeks {
+        if !week::is_week_start(entry.start, week_start) {
+            bail!(
+                "Week start {} is not a {}",
+                entry.start,
+                week::weekday_name(week_start)
+            );
         }
 
         let expected_end = entry.start + chrono::Duration::days(6);
@@ -65,13 +371,43 @@ fn validate_and_sort(data: &mut HoursData) -> Result<()> {
         {
             bail!("Negative hour values in week starting {}", entry.start);
         }
+
+        if let Some(target) = entry.target {
+            if !target.is_finite() || target < 0.0 {
+                bail!(
+                    "Invalid target {target} for week starting {}: must be >= 0",
+                    entry.start
+                );
+            }
+        }
+
+        for day in &entry.days {
+            if day.date < entry.start || day.date > entry.end {
+                bail!(
+                    "Day {} is outside the week starting {}",
+                    day.date,
+                    entry.start
+                );
+            }
+            if Category::ALL
+                .iter()
+                .any(|c| !day.get(*c).is_finite() || day.get(*c) < 0.0)
+            {
+                bail!("Invalid hour values on day {}", day.date);
+            }
+        }
     }
+    validate_totals(data)?;
 
-    data.weeks.sort_by_key(|w| w.start);
+    let mut starts: Vec<_> = data.weeks.iter().map(|w| w.start).collect();
+    starts.sort();
 
-    for i in 1..data.weeks.len() {
-        if data.weeks[i].start == data.weeks[i - 1].start {
-            bail!("Duplicate week starting {}", data.weeks[i].start);
+    for i in 1..starts.len() {
+        if starts[i] == starts[i - 1] {
+            bail!(
+                "Duplicate week starting {}. Run `hours dedupe` to merge duplicates",
+                starts[i]
+            );
         }
     }
 
@@ -90,26 +426,40 @@ mod tests {
     }
 
     fn sample_data() -> HoursData {
-        HoursData {
-            weeks: vec![
-                WeekEntry {
-                    start: date(2025, 2, 4),
-                    end: date(2025, 2, 10),
-                    individual_supervision: 1.0,
-                    group_supervision: 0.0,
-                    direct: 10.0,
-                    indirect: 3.0,
-                },
-                WeekEntry {
-                    start: date(2025, 1, 28),
-                    end: date(2025, 2, 3),
-                    individual_supervision: 1.0,
-                    group_supervision: 2.0,
-                    direct: 14.5,
-                    indirect: 6.0,
-                },
-            ],
-        }
+        HoursData::from_weeks(vec![
+            WeekEntry {
+                start: date(2025, 2, 4),
+                end: date(2025, 2, 10),
+                individual_supervision: 1.0,
+                group_supervision: 0.0,
+                direct: 10.0,
+                indirect: 3.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
+            },
+            WeekEntry {
+                start: date(2025, 1, 28),
+                end: date(2025, 2, 3),
+                individual_supervision: 1.0,
+                group_supervision: 2.0,
+                direct: 14.5,
+                indirect: 6.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
+            },
+        ])
     }
 
     #[test]
@@ -118,7 +468,7 @@ mod tests {
         let path = dir.path().join("hours.json");
 
         let data = sample_data();
-        save(&path, &data).unwrap();
+        save(&path, &data, 0, Weekday::Tue).unwrap();
 
         let loaded = load(&path).unwrap();
         assert_eq!(loaded.weeks.len(), 2);
@@ -137,7 +487,7 @@ mod tests {
         // weeks are out of order in sample_data
         assert!(data.weeks[0].start > data.weeks[1].start);
 
-        save(&path, &data).unwrap();
+        save(&path, &data, 0, Weekday::Tue).unwrap();
         let loaded = load(&path).unwrap();
         assert!(loaded.weeks[0].start < loaded.weeks[1].start);
     }
@@ -147,17 +497,55 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("hours.json");
 
-        let data = HoursData {
-            weeks: vec![WeekEntry {
-                start: date(2025, 1, 29), // Wednesday
-                end: date(2025, 2, 4),
-                individual_supervision: 0.0,
-                group_supervision: 0.0,
-                direct: 0.0,
-                indirect: 0.0,
-            }],
-        };
-        assert!(save(&path, &data).is_err());
+        let data = HoursData::from_weeks(vec![WeekEntry {
+            start: date(2025, 1, 29), // Wednesday
+            end: date(2025, 2, 4),
+            individual_supervision: 0.0,
+            group_supervision: 0.0,
+            direct: 0.0,
+            indirect: 0.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
+        assert!(save(&path, &data, 0, Weekday::Tue).is_err());
+    }
+
+    #[test]
+    fn test_save_validates_days_within_week() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.add_day(date(2025, 2, 3), Category::Direct, 2.0);
+        let mut data = HoursData::from_weeks(vec![entry]);
+        save(&path, &data, 0, Weekday::Tue).unwrap();
+
+        data.weeks[0].days[0].date = date(2025, 2, 4);
+        let err = save(&path, &data, 0, Weekday::Tue).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Day 2025-02-04 is outside the week starting 2025-01-28"
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_total_below_its_days() {
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.add_day(date(2025, 1, 30), Category::Direct, 3.0);
+        entry.direct = 2.0;
+        let err = validate(&HoursData::from_weeks(vec![entry]), Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "direct total 2 for week starting 2025-01-28 is less than its daily entries (3)"
+        );
     }
 
     #[test]
@@ -165,17 +553,23 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("hours.json");
 
-        let data = HoursData {
-            weeks: vec![WeekEntry {
-                start: date(2025, 1, 28),
-                end: date(2025, 2, 4), // Wrong: should be Feb 3
-                individual_supervision: 0.0,
-                group_supervision: 0.0,
-                direct: 0.0,
-                indirect: 0.0,
-            }],
-        };
-        assert!(save(&path, &data).is_err());
+        let data = HoursData::from_weeks(vec![WeekEntry {
+            start: date(2025, 1, 28),
+            end: date(2025, 2, 4), // Wrong: should be Feb 3
+            individual_supervision: 0.0,
+            group_supervision: 0.0,
+            direct: 0.0,
+            indirect: 0.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
+        assert!(save(&path, &data, 0, Weekday::Tue).is_err());
     }
 
     #[test]
@@ -183,17 +577,23 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("hours.json");
 
-        let data = HoursData {
-            weeks: vec![WeekEntry {
-                start: date(2025, 1, 28),
-                end: date(2025, 2, 3),
-                individual_supervision: -1.0,
-                group_supervision: 0.0,
-                direct: 0.0,
-                indirect: 0.0,
-            }],
-        };
-        assert!(save(&path, &data).is_err());
+        let data = HoursData::from_weeks(vec![WeekEntry {
+            start: date(2025, 1, 28),
+            end: date(2025, 2, 3),
+            individual_supervision: -1.0,
+            group_supervision: 0.0,
+            direct: 0.0,
+            indirect: 0.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
+        assert!(save(&path, &data, 0, Weekday::Tue).is_err());
     }
 
     #[test]
@@ -201,13 +601,75 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("hours.json");
 
-        let data = HoursData {
-            weeks: vec![
-                WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3)),
-                WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3)),
-            ],
-        };
-        assert!(save(&path, &data).is_err());
+        let data = HoursData::from_weeks(vec![
+            WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3)),
+            WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3)),
+        ]);
+        assert!(save(&path, &data, 0, Weekday::Tue).is_err());
+    }
+
+    #[test]
+    fn test_validate_totals_consistent_data() {
+        assert!(validate_totals(&sample_data()).is_ok());
+
+        // Undated hours on top of the days are fine; float noise in the day
+        // sum is not a shortfall.
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 5.0;
+        entry.add_day(date(2025, 1, 29), Category::Indirect, 0.1);
+        entry.add_day(date(2025, 1, 30), Category::Indirect, 0.2);
+        assert_eq!(entry.indirect, 0.3);
+        assert!(validate_totals(&HoursData::from_weeks(vec![entry])).is_ok());
+    }
+
+    #[test]
+    fn test_validate_totals_rejects_non_finite_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = f64::INFINITY;
+        let data = HoursData::from_weeks(vec![entry]);
+
+        let err = save(&path, &data, 0, Weekday::Tue).unwrap_err();
+        assert!(err.to_string().contains("Non-finite"), "{err}");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_data() {
+        assert!(validate(&sample_data(), Weekday::Tue).is_ok());
+        assert!(validate(&HoursData::new(), Weekday::Tue).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_data_without_writing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+
+        let data = HoursData::from_weeks(vec![
+            WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10)),
+            WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3)),
+            WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10)),
+        ]);
+        let err = validate(&data, Weekday::Tue).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Duplicate week starting 2025-02-04"));
+        assert!(!path.exists());
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn test_validate_rejects_negative_and_misaligned_weeks() {
+        let mut negative = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        negative.direct = -0.5;
+        assert!(validate(&HoursData::from_weeks(vec![negative]), Weekday::Tue).is_err());
+
+        let wednesday = WeekEntry::new(date(2025, 1, 29), date(2025, 2, 4));
+        let data = HoursData::from_weeks(vec![wednesday]);
+        assert!(validate(&data, Weekday::Tue).is_err());
+        assert!(validate(&data, Weekday::Wed).is_ok());
     }
 
     #[test]
@@ -216,7 +678,7 @@ mod tests {
         let path = dir.path().join("hours.json");
 
         let data = HoursData::new();
-        save(&path, &data).unwrap();
+        save(&path, &data, 0, Weekday::Tue).unwrap();
 
         let content = fs::read_to_string(&path).unwrap();
         assert!(content.contains("\"weeks\": []"));
@@ -231,12 +693,183 @@ mod tests {
         let path = dir.path().join("hours.json");
 
         let data = HoursData::new();
-        save(&path, &data).unwrap();
+        save(&path, &data, 0, Weekday::Tue).unwrap();
 
         let tmp_path = path.with_extension("json.tmp");
         assert!(!tmp_path.exists());
     }
 
+    fn data_with_direct(direct: f64) -> HoursData {
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = direct;
+        HoursData::from_weeks(vec![entry])
+    }
+
+    #[test]
+    fn test_save_rotates_backup_generations() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+
+        // Five saves with three generations: the first save has nothing to
+        // back up, and the oldest backup falls off on the fifth.
+        for direct in 1..=5 {
+            save(&path, &data_with_direct(direct as f64), 3, Weekday::Tue).unwrap();
+        }
+
+        let direct_in = |p: &Path| read(p).unwrap().weeks[0].direct;
+        assert_eq!(direct_in(&path), 5.0);
+        assert_eq!(direct_in(&backup(&path, 1)), 4.0);
+        assert_eq!(direct_in(&backup(&path, 2)), 3.0);
+        assert_eq!(direct_in(&backup(&path, 3)), 2.0);
+        assert!(!backup(&path, 4).exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn test_restore_backup_steps_back_one_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        for direct in 1..=3 {
+            save(&path, &data_with_direct(direct as f64), 3, Weekday::Tue).unwrap();
+        }
+
+        let restored = restore_backup(&path, Weekday::Tue).unwrap().unwrap();
+        assert_eq!(restored.weeks[0].direct, 2.0);
+        assert_eq!(read(&path).unwrap().weeks[0].direct, 2.0);
+        assert_eq!(read(&backup(&path, 1)).unwrap().weeks[0].direct, 1.0);
+        assert!(!backup(&path, 2).exists());
+
+        restore_backup(&path, Weekday::Tue).unwrap().unwrap();
+        assert_eq!(read(&path).unwrap().weeks[0].direct, 1.0);
+        assert!(restore_backup(&path, Weekday::Tue).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_save_prunes_generations_beyond_setting() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        for direct in 1..=4 {
+            save(&path, &data_with_direct(direct as f64), 3, Weekday::Tue).unwrap();
+        }
+
+        save(&path, &data_with_direct(5.0), 1, Weekday::Tue).unwrap();
+        assert_eq!(read(&backup(&path, 1)).unwrap().weeks[0].direct, 4.0);
+        assert!(!backup(&path, 2).exists());
+        assert!(!backup(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_save_without_backups() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        save(&path, &data_with_direct(1.0), 0, Weekday::Tue).unwrap();
+        save(&path, &data_with_direct(2.0), 0, Weekday::Tue).unwrap();
+        assert!(!backup(&path, 1).exists());
+    }
+
+    #[test]
+    fn test_rejected_save_does_not_rotate() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        save(&path, &data_with_direct(1.0), 2, Weekday::Tue).unwrap();
+        save(&path, &data_with_direct(2.0), 2, Weekday::Tue).unwrap();
+
+        assert!(save(&path, &data_with_direct(-1.0), 2, Weekday::Tue).is_err());
+        assert_eq!(read(&backup(&path, 1)).unwrap().weeks[0].direct, 1.0);
+        assert!(!backup(&path, 2).exists());
+    }
+
+    #[test]
+    fn test_recover_uses_newest_valid_generation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        save(&backup(&path, 2), &data_with_direct(2.0), 0, Weekday::Tue).unwrap();
+        fs::write(backup(&path, 1), "{").unwrap();
+        fs::write(&path, "broken").unwrap();
+
+        assert_eq!(
+            recover(&path, Weekday::Tue).unwrap(),
+            Some(backup(&path, 2))
+        );
+        assert_eq!(load(&path).unwrap().weeks[0].direct, 2.0);
+        // Recovery rewrites the main file without shifting the generations.
+        assert_eq!(fs::read_to_string(backup(&path, 1)).unwrap(), "{");
+        assert_eq!(recover(&path, Weekday::Tue).unwrap(), None);
+    }
+
+    #[test]
+    fn test_load_names_recoverable_copy_without_writing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        save(&backup(&path, 1), &data_with_direct(2.0), 0, Weekday::Tue).unwrap();
+        fs::write(&path, "broken").unwrap();
+
+        let err = load(&path).unwrap_err();
+        let recoverable = err.downcast_ref::<RecoverableError>().unwrap();
+        assert_eq!(recoverable.candidate, backup(&path, 1));
+        assert!(err.to_string().contains("rerun with --recover"), "{err}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "broken");
+    }
+
+    #[test]
+    fn test_replace_file_falls_back_to_copy_across_devices() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        let tmp = dir.path().join("hours.json.tmp");
+        fs::write(&path, "old").unwrap();
+        fs::write(&tmp, "new").unwrap();
+
+        let cross_device =
+            |_: &Path, _: &Path| Err(std::io::Error::from(std::io::ErrorKind::CrossesDevices));
+        replace_file(&tmp, &path, cross_device).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(!tmp.exists());
+
+        // Other failures are reported, not papered over.
+        fs::write(&tmp, "newer").unwrap();
+        let denied =
+            |_: &Path, _: &Path| Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert!(replace_file(&tmp, &path, denied).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_through_symlinked_dir_and_file() {
+        use std::os::unix::fs::symlink;
+
+        let real = tempfile::tempdir().unwrap();
+        let links = tempfile::tempdir().unwrap();
+        let linked_dir = links.path().join("data");
+        symlink(real.path(), &linked_dir).unwrap();
+
+        save(
+            &linked_dir.join("hours.json"),
+            &sample_data(),
+            1,
+            Weekday::Tue,
+        )
+        .unwrap();
+        assert_eq!(
+            read(&real.path().join("hours.json")).unwrap().weeks.len(),
+            2
+        );
+
+        // A symlinked file keeps pointing at its target after a save.
+        let linked_file = links.path().join("hours.json");
+        symlink(real.path().join("hours.json"), &linked_file).unwrap();
+        save(&linked_file, &data_with_direct(7.0), 0, Weekday::Tue).unwrap();
+        assert!(fs::symlink_metadata(&linked_file)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(
+            read(&real.path().join("hours.json")).unwrap().weeks[0].direct,
+            7.0
+        );
+        assert!(!real.path().join("hours.json.tmp").exists());
+    }
+
     #[test]
     fn test_load_nonexistent_file() {
         let dir = tempfile::tempdir().unwrap();
@@ -252,22 +885,144 @@ mod tests {
         assert!(load(&path).is_err());
     }
 
+    #[test]
+    fn test_load_stamps_unversioned_file_and_save_writes_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        fs::write(&path, r#"{"weeks":[]}"#).unwrap();
+
+        let data = load(&path).unwrap();
+        assert_eq!(data.schema_version, SCHEMA_VERSION);
+        save(&path, &data, 0, Weekday::Tue).unwrap();
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["schema_version"], SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_load_refuses_newer_schema_without_recovering() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        save(&backup(&path, 1), &sample_data(), 0, Weekday::Tue).unwrap();
+        let newer = format!(r#"{{"schema_version":{},"weeks":[]}}"#, SCHEMA_VERSION + 1);
+        fs::write(&path, &newer).unwrap();
+
+        let err = load(&path).unwrap_err().to_string();
+        assert!(err.contains("only understands up to"), "{err}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), newer);
+    }
+
+    #[test]
+    fn test_custom_filename_roundtrip_uses_matching_tmp() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clinic");
+
+        save(&path, &sample_data(), 0, Weekday::Tue).unwrap();
+        assert!(!dir.path().join("clinic.tmp").exists());
+        assert_eq!(load(&path).unwrap().weeks.len(), 2);
+
+        // Recovery looks for `<name>.tmp`, not a `.json.tmp` derived from it.
+        fs::remove_file(&path).unwrap();
+        save(
+            &dir.path().join("clinic.tmp"),
+            &sample_data(),
+            0,
+            Weekday::Tue,
+        )
+        .unwrap();
+        assert_eq!(
+            recover(&path, Weekday::Tue).unwrap(),
+            Some(dir.path().join("clinic.tmp"))
+        );
+        assert_eq!(load(&path).unwrap().weeks.len(), 2);
+    }
+
+    #[test]
+    fn test_recover_from_bak() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        let bak_path = path.with_extension("json.bak");
+
+        save(&bak_path, &sample_data(), 0, Weekday::Tue).unwrap();
+        fs::write(&path, "{\"weeks\": [").unwrap();
+
+        assert!(load(&path).is_err());
+        assert_eq!(recover(&path, Weekday::Tue).unwrap(), Some(bak_path));
+
+        // The main file is rewritten so later loads don't need the backup.
+        let reread = read(&path).unwrap();
+        assert_eq!(reread.weeks.len(), 2);
+    }
+
+    #[test]
+    fn test_recover_missing_file_from_tmp() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        let tmp_path = path.with_extension("json.tmp");
+
+        let json = serde_json::to_string_pretty(&sample_data()).unwrap();
+        fs::write(&tmp_path, json).unwrap();
+
+        assert!(load(&path).is_err());
+        assert!(!path.exists());
+        recover(&path, Weekday::Tue).unwrap();
+        assert_eq!(load(&path).unwrap().weeks.len(), 2);
+        assert!(!tmp_path.exists());
+    }
+
+    #[test]
+    fn test_load_ignores_corrupt_backups() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        fs::write(&path, "not valid json").unwrap();
+        fs::write(path.with_extension("json.tmp"), "{").unwrap();
+        fs::write(path.with_extension("json.bak"), "also broken").unwrap();
+
+        let err = load(&path).unwrap_err();
+        assert!(err.downcast_ref::<RecoverableError>().is_none());
+        assert!(err.to_string().contains("Failed to parse"));
+        assert!(recover(&path, Weekday::Tue).is_err());
+    }
+
+    #[test]
+    fn test_load_lenient_accepts_wednesday_weeks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hours.json");
+        fs::write(
+            &path,
+            r#"{"weeks":[{"start":"2025-01-29","end":"2025-02-04","individual_supervision":0.0,"group_supervision":0.0,"direct":4.0,"indirect":0.0}]}"#,
+        )
+        .unwrap();
+
+        let data = load_lenient(&path, Weekday::Tue).unwrap();
+        assert_eq!(data.weeks.len(), 1);
+        assert_eq!(data.weeks[0].start, date(2025, 1, 29));
+        assert!(validate(&data, Weekday::Tue).is_err());
+        assert!(save(&path, &data, 0, Weekday::Tue).is_err());
+    }
+
     #[test]
     fn test_save_preserves_values() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("hours.json");
 
-        let data = HoursData {
-            weeks: vec![WeekEntry {
-                start: date(2025, 1, 28),
-                end: date(2025, 2, 3),
-                individual_supervision: 1.5,
-                group_supervision: 2.25,
-                direct: 14.75,
-                indirect: 6.0,
-            }],
-        };
-        save(&path, &data).unwrap();
+        let data = HoursData::from_weeks(vec![WeekEntry {
+            start: date(2025, 1, 28),
+            end: date(2025, 2, 3),
+            individual_supervision: 1.5,
+            group_supervision: 2.25,
+            direct: 14.75,
+            indirect: 6.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
+        save(&path, &data, 0, Weekday::Tue).unwrap();
         let loaded = load(&path).unwrap();
 
         let w = &loaded.weeks[0];
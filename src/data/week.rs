@@ -1,19 +1,49 @@
+use anyhow::{bail, Result};
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
-pub fn week_containing(date: NaiveDate) -> (NaiveDate, NaiveDate) {
-    let weekday_num = date.weekday().num_days_from_monday(); // Mon=0, Tue=1, ..., Sun=6
-    let days_since_tuesday = (weekday_num + 6) % 7; // Tue=0, Wed=1, ..., Mon=6
-    let start = date - Duration::days(days_since_tuesday as i64);
+use super::model::HoursData;
+
+pub fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+// Full or abbreviated English names, any case ("Monday", "mon").
+pub fn parse_weekday(input: &str) -> Result<Weekday> {
+    match input.trim().parse::<Weekday>() {
+        Ok(day) => Ok(day),
+        Err(_) => {
+            bail!("Invalid week start day '{input}': expected a weekday name such as Tuesday")
+        }
+    }
+}
+
+// Weeks start on `start_day`, normally `licensure.week_start_day`.
+pub fn week_containing(date: NaiveDate, start_day: Weekday) -> (NaiveDate, NaiveDate) {
+    let days_since_start =
+        (date.weekday().num_days_from_monday() + 7 - start_day.num_days_from_monday()) % 7;
+    let start = date - Duration::days(days_since_start as i64);
     let end = start + Duration::days(6);
     (start, end)
 }
 
-pub fn current_week(today: NaiveDate) -> (NaiveDate, NaiveDate) {
-    week_containing(today)
+pub fn current_week(today: NaiveDate, start_day: Weekday) -> (NaiveDate, NaiveDate) {
+    week_containing(today, start_day)
 }
 
-pub fn all_weeks(start_date: NaiveDate, today: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
-    let (current_start, _) = week_containing(today);
+pub fn all_weeks(
+    start_date: NaiveDate,
+    today: NaiveDate,
+    start_day: Weekday,
+) -> Vec<(NaiveDate, NaiveDate)> {
+    let (current_start, _) = week_containing(today, start_day);
     let mut weeks = Vec::new();
     let mut week_start = start_date;
     while week_start <= current_start {
@@ -24,8 +54,58 @@ pub fn all_weeks(start_date: NaiveDate, today: NaiveDate) -> Vec<(NaiveDate, Nai
     weeks
 }
 
-pub fn is_tuesday(date: NaiveDate) -> bool {
-    date.weekday() == Weekday::Tue
+// Maps `days` consecutive days starting at `start` to the weeks they fall in,
+// returning each week's start date and how many of the days land in it.
+pub fn days_by_week(
+    start: NaiveDate,
+    days: u32,
+    start_day: Weekday,
+) -> Result<Vec<(NaiveDate, u32)>> {
+    let mut buckets: Vec<(NaiveDate, u32)> = Vec::new();
+    for offset in 0..days {
+        let Some(day) = start.checked_add_signed(Duration::days(offset as i64)) else {
+            bail!("{days} days from {start} run past the latest supported date");
+        };
+        let (week_start, _) = week_containing(day, start_day);
+        match buckets.last_mut() {
+            Some((last, count)) if *last == week_start => *count += 1,
+            _ => buckets.push((week_start, 1)),
+        }
+    }
+    Ok(buckets)
+}
+
+pub fn is_week_start(date: NaiveDate, start_day: Weekday) -> bool {
+    date.weekday() == start_day
+}
+
+// How far the week starting `start` is from the current week: "current",
+// "last week", "3 weeks ago", or "next week"/"in 2 weeks" for future weeks.
+pub fn relative_label(start: NaiveDate, today: NaiveDate, start_day: Weekday) -> String {
+    let (current_start, _) = current_week(today, start_day);
+    match (current_start - start).num_days() / 7 {
+        0 => "current".to_string(),
+        1 => "last week".to_string(),
+        -1 => "next week".to_string(),
+        n if n < 0 => format!("in {} weeks", -n),
+        n => format!("{n} weeks ago"),
+    }
+}
+
+// Consecutive weeks with hours logged, counting back from the current week.
+// An empty current week means the streak is 0.
+pub fn logging_streak(data: &HoursData, today: NaiveDate, start_day: Weekday) -> u32 {
+    let (mut start, _) = current_week(today, start_day);
+    let mut streak = 0;
+    while data
+        .weeks
+        .iter()
+        .any(|w| w.start == start && w.total() > 0.0)
+    {
+        streak += 1;
+        start -= Duration::days(7);
+    }
+    streak
 }
 
 #[cfg(test)]
@@ -39,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_week_containing_tuesday() {
-        let (start, end) = week_containing(date(2025, 1, 28));
+        let (start, end) = week_containing(date(2025, 1, 28), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
         assert_eq!(start.weekday(), Weekday::Tue);
@@ -48,49 +128,49 @@ mod tests {
 
     #[test]
     fn test_week_containing_thursday() {
-        let (start, end) = week_containing(date(2025, 1, 30));
+        let (start, end) = week_containing(date(2025, 1, 30), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
     }
 
     #[test]
     fn test_week_containing_monday() {
-        let (start, end) = week_containing(date(2025, 2, 3));
+        let (start, end) = week_containing(date(2025, 2, 3), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
     }
 
     #[test]
     fn test_week_containing_next_tuesday() {
-        let (start, end) = week_containing(date(2025, 2, 4));
+        let (start, end) = week_containing(date(2025, 2, 4), Weekday::Tue);
         assert_eq!(start, date(2025, 2, 4));
         assert_eq!(end, date(2025, 2, 10));
     }
 
     #[test]
     fn test_week_containing_wednesday() {
-        let (start, end) = week_containing(date(2025, 1, 29));
+        let (start, end) = week_containing(date(2025, 1, 29), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
     }
 
     #[test]
     fn test_week_containing_sunday() {
-        let (start, end) = week_containing(date(2025, 2, 2));
+        let (start, end) = week_containing(date(2025, 2, 2), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
     }
 
     #[test]
     fn test_week_containing_saturday() {
-        let (start, end) = week_containing(date(2025, 2, 1));
+        let (start, end) = week_containing(date(2025, 2, 1), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
     }
 
     #[test]
     fn test_week_containing_friday() {
-        let (start, end) = week_containing(date(2025, 1, 31));
+        let (start, end) = week_containing(date(2025, 1, 31), Weekday::Tue);
         assert_eq!(start, date(2025, 1, 28));
         assert_eq!(end, date(2025, 2, 3));
     }
@@ -98,14 +178,17 @@ mod tests {
     #[test]
     fn test_current_week_is_same_as_week_containing() {
         let today = date(2025, 1, 30);
-        assert_eq!(current_week(today), week_containing(today));
+        assert_eq!(
+            current_week(today, Weekday::Tue),
+            week_containing(today, Weekday::Tue)
+        );
     }
 
     #[test]
     fn test_all_weeks_single_week() {
         let start = date(2025, 1, 28);
         let today = date(2025, 1, 30);
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         assert_eq!(weeks.len(), 1);
         assert_eq!(weeks[0].0, date(2025, 1, 28));
         assert_eq!(weeks[0].1, date(2025, 2, 3));
@@ -115,7 +198,7 @@ mod tests {
     fn test_all_weeks_multiple_weeks() {
         let start = date(2025, 1, 28);
         let today = date(2025, 2, 12); // Wed of 3rd week
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         assert_eq!(weeks.len(), 3);
         assert_eq!(weeks[0].0, date(2025, 1, 28));
         assert_eq!(weeks[1].0, date(2025, 2, 4));
@@ -126,7 +209,7 @@ mod tests {
     fn test_all_weeks_today_is_start() {
         let start = date(2025, 1, 28);
         let today = date(2025, 1, 28);
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         assert_eq!(weeks.len(), 1);
     }
 
@@ -134,7 +217,7 @@ mod tests {
     fn test_all_weeks_today_is_monday_end_of_week() {
         let start = date(2025, 1, 28);
         let today = date(2025, 2, 3); // Monday, end of first week
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         assert_eq!(weeks.len(), 1);
     }
 
@@ -142,7 +225,7 @@ mod tests {
     fn test_all_weeks_today_is_next_tuesday() {
         let start = date(2025, 1, 28);
         let today = date(2025, 2, 4); // Tuesday, start of second week
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         assert_eq!(weeks.len(), 2);
     }
 
@@ -150,7 +233,7 @@ mod tests {
     fn test_all_weeks_start_always_tuesday() {
         let start = date(2025, 1, 28);
         let today = date(2025, 3, 15);
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         for (s, e) in &weeks {
             assert_eq!(s.weekday(), Weekday::Tue);
             assert_eq!(e.weekday(), Weekday::Mon);
@@ -162,18 +245,53 @@ mod tests {
     fn test_all_weeks_consecutive() {
         let start = date(2025, 1, 28);
         let today = date(2025, 3, 15);
-        let weeks = all_weeks(start, today);
+        let weeks = all_weeks(start, today, Weekday::Tue);
         for i in 1..weeks.len() {
             assert_eq!(weeks[i].0 - weeks[i - 1].0, Duration::days(7));
         }
     }
 
     #[test]
-    fn test_is_tuesday() {
-        assert!(is_tuesday(date(2025, 1, 28)));
-        assert!(!is_tuesday(date(2025, 1, 29)));
-        assert!(!is_tuesday(date(2025, 1, 27)));
-        assert!(is_tuesday(date(2025, 2, 4)));
+    fn test_is_week_start() {
+        assert!(is_week_start(date(2025, 1, 28), Weekday::Tue));
+        assert!(!is_week_start(date(2025, 1, 29), Weekday::Tue));
+        assert!(!is_week_start(date(2025, 1, 27), Weekday::Tue));
+        assert!(is_week_start(date(2025, 2, 4), Weekday::Tue));
+        assert!(is_week_start(date(2025, 1, 27), Weekday::Mon));
+        assert!(!is_week_start(date(2025, 1, 28), Weekday::Mon));
+    }
+
+    #[test]
+    fn test_week_containing_other_start_days() {
+        // Monday–Sunday weeks.
+        for d in 27..=31 {
+            assert_eq!(
+                week_containing(date(2025, 1, d), Weekday::Mon),
+                (date(2025, 1, 27), date(2025, 2, 2))
+            );
+        }
+        assert_eq!(
+            week_containing(date(2025, 2, 2), Weekday::Mon),
+            (date(2025, 1, 27), date(2025, 2, 2))
+        );
+        // Sunday–Saturday weeks.
+        assert_eq!(
+            week_containing(date(2025, 2, 1), Weekday::Sun),
+            (date(2025, 1, 26), date(2025, 2, 1))
+        );
+        assert_eq!(
+            week_containing(date(2025, 2, 2), Weekday::Sun),
+            (date(2025, 2, 2), date(2025, 2, 8))
+        );
+    }
+
+    #[test]
+    fn test_parse_weekday_names() {
+        assert_eq!(parse_weekday("Monday").unwrap(), Weekday::Mon);
+        assert_eq!(parse_weekday("tue").unwrap(), Weekday::Tue);
+        assert_eq!(weekday_name(parse_weekday("SUNDAY").unwrap()), "Sunday");
+        let err = parse_weekday("Funday").unwrap_err().to_string();
+        assert!(err.contains("Invalid week start day 'Funday'"), "{err}");
     }
 
     #[test]
@@ -182,14 +300,94 @@ mod tests {
         let expected_start = date(2025, 1, 28);
         let expected_end = date(2025, 2, 3);
         for d in 28..=31 {
-            let (s, e) = week_containing(date(2025, 1, d));
+            let (s, e) = week_containing(date(2025, 1, d), Weekday::Tue);
             assert_eq!(s, expected_start, "Failed for Jan {}", d);
             assert_eq!(e, expected_end, "Failed for Jan {}", d);
         }
         for d in 1..=3 {
-            let (s, e) = week_containing(date(2025, 2, d));
+            let (s, e) = week_containing(date(2025, 2, d), Weekday::Tue);
             assert_eq!(s, expected_start, "Failed for Feb {}", d);
             assert_eq!(e, expected_end, "Failed for Feb {}", d);
         }
     }
+
+    #[test]
+    fn days_by_week_within_one_week() {
+        assert_eq!(
+            days_by_week(date(2025, 1, 29), 3, Weekday::Tue).unwrap(),
+            vec![(date(2025, 1, 28), 3)]
+        );
+    }
+
+    #[test]
+    fn days_by_week_crosses_monday_boundary() {
+        // Sun Feb 2 + Mon Feb 3 belong to the Jan 28 week, Tue Feb 4 starts the next.
+        assert_eq!(
+            days_by_week(date(2025, 2, 2), 3, Weekday::Tue).unwrap(),
+            vec![(date(2025, 1, 28), 2), (date(2025, 2, 4), 1)]
+        );
+    }
+
+    #[test]
+    fn days_by_week_zero_days() {
+        assert!(days_by_week(date(2025, 2, 2), 0, Weekday::Tue)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn days_by_week_errors_past_the_last_date() {
+        let err = days_by_week(NaiveDate::MAX, 2, Weekday::Tue)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("run past the latest supported date"), "{err}");
+    }
+
+    #[test]
+    fn relative_label_counts_whole_weeks() {
+        let today = date(2025, 2, 27); // Thursday of the Feb 25 week
+        assert_eq!(
+            relative_label(date(2025, 2, 25), today, Weekday::Tue),
+            "current"
+        );
+        assert_eq!(
+            relative_label(date(2025, 2, 18), today, Weekday::Tue),
+            "last week"
+        );
+        assert_eq!(
+            relative_label(date(2025, 1, 28), today, Weekday::Tue),
+            "4 weeks ago"
+        );
+        assert_eq!(
+            relative_label(date(2025, 3, 4), today, Weekday::Tue),
+            "next week"
+        );
+        assert_eq!(
+            relative_label(date(2025, 3, 11), today, Weekday::Tue),
+            "in 2 weeks"
+        );
+    }
+
+    #[test]
+    fn logging_streak_stops_at_first_empty_week() {
+        use crate::data::model::WeekEntry;
+
+        let week = |start: NaiveDate, direct: f64| {
+            let mut entry = WeekEntry::new(start, start + Duration::days(6));
+            entry.direct = direct;
+            entry
+        };
+        let data = HoursData::from_weeks(vec![
+            week(date(2025, 1, 28), 4.0),
+            week(date(2025, 2, 4), 0.0),
+            week(date(2025, 2, 11), 2.0),
+            week(date(2025, 2, 18), 3.0),
+        ]);
+
+        assert_eq!(logging_streak(&data, date(2025, 2, 20), Weekday::Tue), 2);
+        assert_eq!(logging_streak(&data, date(2025, 2, 11), Weekday::Tue), 1);
+        assert_eq!(logging_streak(&data, date(2025, 2, 5), Weekday::Tue), 0);
+        // Nothing logged yet in the week of Feb 25.
+        assert_eq!(logging_streak(&data, date(2025, 2, 26), Weekday::Tue), 0);
+    }
 }
@@ -0,0 +1,128 @@
+// Every view sums with full precision and rounds exactly once through these
+// helpers, so list, summary, and the PDF never disagree in the last digit.
+pub fn round(val: f64) -> f64 {
+    let r = (val * 10.0).round() / 10.0;
+    if r == 0.0 {
+        0.0
+    } else {
+        r
+    }
+}
+
+pub fn hours(val: f64) -> String {
+    format!("{:.1}", round(val))
+}
+
+// Same half-away-from-zero rounding as `round`, at any precision, with commas
+// grouping the integer part (2450.5 -> "2,450.5") for the printed report.
+pub fn format_hours(val: f64, precision: usize) -> String {
+    let scale = 10f64.powi(precision as i32);
+    let rounded = (val * scale).round() / scale;
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    let formatted = format!("{:.*}", precision, rounded.abs());
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::new();
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if rounded < 0.0 { "-" } else { "" };
+    match frac_part {
+        Some(frac_part) => format!("{sign}{grouped}.{frac_part}"),
+        None => format!("{sign}{grouped}"),
+    }
+}
+
+// Accepts a comma as the decimal separator ("3,5") for locales that write it
+// that way. A comma followed by exactly three digits could be a thousands
+// separator, and mixing ',' with '.' is a thousands-grouped number, so both
+// are rejected rather than guessed at.
+pub fn parse_hours(input: &str) -> anyhow::Result<f64> {
+    let trimmed = input.trim();
+    let normalized = match trimmed.split_once(',') {
+        None => trimmed.to_string(),
+        Some((int_part, frac_part)) => {
+            if frac_part.contains(',') || trimmed.contains('.') || frac_part.len() == 3 {
+                anyhow::bail!(
+                    "Ambiguous hours '{trimmed}': use a single ',' or '.' as the decimal separator, with no thousands separators"
+                );
+            }
+            format!("{int_part}.{frac_part}")
+        }
+    };
+    normalized.parse::<f64>().map_err(|_| {
+        anyhow::anyhow!("Invalid hours '{trimmed}': expected a number like 3.5 or 3,5")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hours_accepts_comma_decimal() {
+        assert_eq!(parse_hours("3,5").unwrap(), 3.5);
+        assert_eq!(parse_hours(" 0,25 ").unwrap(), 0.25);
+        assert_eq!(parse_hours("-1,5").unwrap(), -1.5);
+        assert_eq!(parse_hours("3.5").unwrap(), 3.5);
+        assert_eq!(parse_hours("12").unwrap(), 12.0);
+    }
+
+    #[test]
+    fn parse_hours_rejects_ambiguous_and_invalid() {
+        for input in ["1,234.5", "1.234,5", "1,234", "1,2,3"] {
+            let err = parse_hours(input).unwrap_err().to_string();
+            assert!(err.starts_with("Ambiguous hours"), "{input}: {err}");
+        }
+        for input in ["", "abc", "3,x", ","] {
+            assert!(parse_hours(input).is_err(), "{input:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn round_values() {
+        assert!((round(8.233) - 8.2).abs() < f64::EPSILON);
+        assert!((round(102.75) - 102.8).abs() < f64::EPSILON);
+        assert!((round(0.0) - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn round_negative_zero_normalized() {
+        assert!(round(-0.0).is_sign_positive());
+        assert!(round(-0.0000001).is_sign_positive());
+    }
+
+    #[test]
+    fn hours_rounds_half_away_from_zero() {
+        // `{:.1}` alone would print 0.25 as "0.2" (ties-to-even on the binary value).
+        assert_eq!(hours(0.25), "0.3");
+        assert_eq!(hours(2.45), "2.5");
+        assert_eq!(hours(-0.0), "0.0");
+        assert_eq!(hours(14.5), "14.5");
+    }
+
+    #[test]
+    fn format_hours_groups_thousands() {
+        assert_eq!(format_hours(2450.5, 1), "2,450.5");
+        assert_eq!(format_hours(1234567.0, 1), "1,234,567.0");
+        assert_eq!(format_hours(999.95, 1), "1,000.0");
+        assert_eq!(format_hours(12.0, 1), "12.0");
+    }
+
+    #[test]
+    fn format_hours_precision() {
+        assert_eq!(format_hours(2450.5, 0), "2,451");
+        assert_eq!(format_hours(3000.0, 0), "3,000");
+        assert_eq!(format_hours(0.125, 2), "0.13");
+        assert_eq!(format_hours(0.25, 1), hours(0.25));
+        assert_eq!(format_hours(-0.0, 1), "0.0");
+        assert_eq!(format_hours(-1500.25, 1), "-1,500.3");
+    }
+}
@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::data::model::HoursData;
+
+const HEADER: &str = "start,end,individual_supervision,group_supervision,direct,indirect,total";
+
+// Same dates and unrounded values as `list --json`, so a spreadsheet sums to
+// exactly what the CLI reports.
+pub fn render_csv(data: &HoursData) -> String {
+    let mut weeks: Vec<_> = data.weeks.iter().collect();
+    weeks.sort_by_key(|w| w.start);
+
+    let mut out = String::from(HEADER);
+    out.push('\n');
+    for w in weeks {
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            w.start.format("%Y-%m-%d"),
+            w.end.format("%Y-%m-%d"),
+            w.individual_supervision,
+            w.group_supervision,
+            w.direct,
+            w.indirect,
+            w.total()
+        ));
+    }
+    out
+}
+
+pub fn generate_csv(data: &HoursData, output_path: &Path) -> Result<()> {
+    std::fs::write(output_path, render_csv(data))
+        .with_context(|| format!("Failed to write CSV to {}", output_path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::model::WeekEntry;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn render_csv_sorts_rows_under_header() {
+        let mut later = WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10));
+        later.direct = 5.0;
+        let mut earlier = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        earlier.individual_supervision = 1.0;
+        earlier.group_supervision = 2.0;
+        earlier.direct = 14.5;
+        earlier.indirect = 1.0;
+        let data = HoursData::from_weeks(vec![later, earlier]);
+
+        assert_eq!(
+            render_csv(&data),
+            "start,end,individual_supervision,group_supervision,direct,indirect,total\n\
+             2025-01-28,2025-02-03,1,2,14.5,1,18.5\n\
+             2025-02-04,2025-02-10,0,0,5,0,5\n"
+        );
+    }
+
+    #[test]
+    fn render_csv_empty_data_is_header_only() {
+        let csv = render_csv(&HoursData::from_weeks(vec![]));
+        assert_eq!(csv, format!("{HEADER}\n"));
+    }
+}
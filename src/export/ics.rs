@@ -0,0 +1,188 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDateTime, Utc};
+
+use crate::data::model::{Category, HoursData, WeekEntry};
+
+const PRODID: &str = "-//hours//Counseling Licensure Hours//EN";
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn event_description(entry: &WeekEntry) -> String {
+    Category::ALL
+        .iter()
+        .map(|c| format!("{}: {:.1}", c.display_name(), entry.get(*c)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn push_line(out: &mut String, line: &str) {
+    out.push_str(line);
+    out.push_str("\r\n");
+}
+
+fn push_event(out: &mut String, entry: &WeekEntry, stamp: NaiveDateTime) {
+    // All-day events use an exclusive DTEND, so the Monday end date needs one extra day.
+    let dtend = entry.end + Duration::days(1);
+
+    push_line(out, "BEGIN:VEVENT");
+    push_line(
+        out,
+        &format!("UID:hours-{}@hours", entry.start.format("%Y-%m-%d")),
+    );
+    push_line(out, &format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")));
+    push_line(
+        out,
+        &format!("DTSTART;VALUE=DATE:{}", entry.start.format("%Y%m%d")),
+    );
+    push_line(out, &format!("DTEND;VALUE=DATE:{}", dtend.format("%Y%m%d")));
+    push_line(
+        out,
+        &format!(
+            "SUMMARY:{}",
+            escape_text(&format!("Supervision: {:.1} hrs", entry.total()))
+        ),
+    );
+    push_line(
+        out,
+        &format!("DESCRIPTION:{}", escape_text(&event_description(entry))),
+    );
+    push_line(out, "TRANSP:TRANSPARENT");
+    push_line(out, "END:VEVENT");
+}
+
+pub fn render_calendar(data: &HoursData, stamp: NaiveDateTime) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{PRODID}"));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+
+    for entry in data.weeks.iter().filter(|w| w.total() > 0.0) {
+        push_event(&mut out, entry, stamp);
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+pub fn generate_calendar(data: &HoursData, output_path: &Path) -> Result<()> {
+    let contents = render_calendar(data, Utc::now().naive_utc());
+    std::fs::write(output_path, contents)
+        .with_context(|| format!("Failed to write calendar to {}", output_path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use tempfile::TempDir;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn stamp() -> NaiveDateTime {
+        date(2025, 3, 1).and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn sample_data() -> HoursData {
+        HoursData::from_weeks(vec![
+            WeekEntry {
+                start: date(2025, 1, 28),
+                end: date(2025, 2, 3),
+                individual_supervision: 1.0,
+                group_supervision: 2.0,
+                direct: 14.5,
+                indirect: 1.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
+            },
+            WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10)),
+            WeekEntry {
+                start: date(2025, 2, 11),
+                end: date(2025, 2, 17),
+                individual_supervision: 0.0,
+                group_supervision: 0.0,
+                direct: 5.0,
+                indirect: 0.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
+            },
+        ])
+    }
+
+    #[test]
+    fn render_calendar_one_event_per_non_zero_week() {
+        let ics = render_calendar(&sample_data(), stamp());
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(ics.matches("END:VEVENT").count(), 2);
+        assert!(!ics.contains("DTSTART;VALUE=DATE:20250204"));
+    }
+
+    #[test]
+    fn render_calendar_dates_match_weeks() {
+        let ics = render_calendar(&sample_data(), stamp());
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250128\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20250204\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250211\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20250218\r\n"));
+        assert!(ics.contains("DTSTAMP:20250301T120000Z\r\n"));
+    }
+
+    #[test]
+    fn render_calendar_summary_has_total() {
+        let ics = render_calendar(&sample_data(), stamp());
+        assert!(ics.contains("SUMMARY:Supervision: 18.5 hrs\r\n"));
+        assert!(ics.contains("SUMMARY:Supervision: 5.0 hrs\r\n"));
+    }
+
+    #[test]
+    fn render_calendar_empty_data() {
+        let ics = render_calendar(&HoursData::new(), stamp());
+        assert!(!ics.contains("BEGIN:VEVENT"));
+        assert!(ics.contains("VERSION:2.0"));
+    }
+
+    #[test]
+    fn escape_text_special_characters() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn generate_calendar_writes_file() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("hours.ics");
+        generate_calendar(&sample_data(), &path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("BEGIN:VEVENT"));
+    }
+}
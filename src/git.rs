@@ -1,18 +1,22 @@
+use std::io::{IsTerminal, Write};
 use std::path::Path;
 use std::process::Command;
+use std::time::{Duration, Instant};
 
 use anyhow::{bail, Context, Result};
+use log::{debug, info, warn};
 
 use crate::config::GitConfig;
+use crate::ui::spinner::Spinner;
 
-fn is_git_disabled(no_git_flag: bool) -> bool {
+pub fn is_git_disabled(no_git_flag: bool) -> bool {
     if no_git_flag {
         return true;
     }
     std::env::var("HOURS_NO_GIT").ok().as_deref() == Some("1")
 }
 
-fn git_binary_exists() -> bool {
+pub fn git_binary_exists() -> bool {
     Command::new("git")
         .arg("--version")
         .stdout(std::process::Stdio::null())
@@ -22,12 +26,19 @@ fn git_binary_exists() -> bool {
 }
 
 fn run_git(data_dir: &Path, args: &[&str]) -> Result<std::process::Output> {
+    let started = Instant::now();
     let output = Command::new("git")
         .arg("-C")
         .arg(data_dir)
         .args(args)
         .output()
         .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    debug!(
+        "git {} exited with {} in {:?}",
+        args.join(" "),
+        output.status,
+        started.elapsed()
+    );
     Ok(output)
 }
 
@@ -40,13 +51,18 @@ fn run_git_checked(data_dir: &Path, args: &[&str]) -> Result<()> {
     Ok(())
 }
 
-fn is_git_repo(data_dir: &Path) -> bool {
+pub fn is_git_repo(data_dir: &Path) -> bool {
     run_git(data_dir, &["rev-parse", "--git-dir"])
         .map(|o| o.status.success())
         .unwrap_or(false)
 }
 
-pub fn git_init(data_dir: &Path, remote_name: &str, remote_url: &str) -> Result<()> {
+pub fn git_init(
+    data_dir: &Path,
+    remote_name: &str,
+    remote_url: &str,
+    branch: Option<&str>,
+) -> Result<()> {
     if !git_binary_exists() {
         bail!("git is not installed. Install git and try again.");
     }
@@ -56,6 +72,12 @@ pub fn git_init(data_dir: &Path, remote_name: &str, remote_url: &str) -> Result<
 
     if !is_git_repo(data_dir) {
         run_git_checked(data_dir, &["init"])?;
+        // Nothing is committed yet, so pointing HEAD at the configured branch
+        // renames the initial branch (works on gits without `init -b`).
+        if let Some(branch) = branch {
+            let head = format!("refs/heads/{branch}");
+            run_git_checked(data_dir, &["symbolic-ref", "HEAD", &head])?;
+        }
     }
 
     let remote_check = run_git(data_dir, &["remote", "get-url", remote_name])?;
@@ -64,17 +86,89 @@ pub fn git_init(data_dir: &Path, remote_name: &str, remote_url: &str) -> Result<
     }
 
     let gitignore_path = data_dir.join(".gitignore");
-    std::fs::write(&gitignore_path, "*.tmp\nexports/\n").context("Failed to write .gitignore")?;
+    std::fs::write(&gitignore_path, "*.tmp\n*.bak.*\nexports/\nbackups/\n")
+        .context("Failed to write .gitignore")?;
 
     Ok(())
 }
 
-pub fn git_commit(data_dir: &Path, message: &str) -> Result<()> {
+// Returns the remote's URL once `git ls-remote` confirms it answers. Prompts
+// are disabled so a missing credential fails instead of hanging.
+pub fn check_remote(data_dir: &Path, remote_name: &str) -> Result<String> {
+    let url = run_git(data_dir, &["remote", "get-url", remote_name])?;
+    if !url.status.success() {
+        bail!("No remote named '{remote_name}' is configured");
+    }
+    let url = String::from_utf8_lossy(&url.stdout).trim().to_string();
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(data_dir)
+        .args(["ls-remote", "--heads", remote_name])
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .context("Failed to run git ls-remote")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!(
+            "Remote '{remote_name}' ({url}) is unreachable: {}",
+            stderr.trim()
+        );
+    }
+    Ok(url)
+}
+
+const CLONE_ATTEMPTS: u32 = 3;
+
+fn has_entries(dir: &Path) -> Result<bool> {
+    match std::fs::read_dir(dir) {
+        Ok(mut entries) => Ok(entries.next().is_some()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", dir.display())),
+    }
+}
+
+pub fn git_clone(data_dir: &Path, remote_name: &str, remote_url: &str, no_git: bool) -> Result<()> {
+    if is_git_disabled(no_git) {
+        bail!("Cannot clone {remote_url}: git is disabled (--no-git or HOURS_NO_GIT=1)");
+    }
+    if !git_binary_exists() {
+        bail!("git is not installed. Install git and try again.");
+    }
+    if has_entries(data_dir)? {
+        bail!(
+            "Data directory {} is not empty. Clone into a new or empty directory",
+            data_dir.display()
+        );
+    }
+
+    let mut last_error = String::new();
+    for attempt in 1..=CLONE_ATTEMPTS {
+        let output = Command::new("git")
+            .args(["clone", "--origin", remote_name, "--", remote_url])
+            .arg(data_dir)
+            .output()
+            .context("Failed to run git clone")?;
+        if output.status.success() {
+            return Ok(());
+        }
+        last_error = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        // git removes a failed clone's files, so the next attempt starts clean.
+        if attempt < CLONE_ATTEMPTS {
+            eprintln!("Clone attempt {attempt} failed, retrying...");
+            std::thread::sleep(Duration::from_secs(attempt.into()));
+        }
+    }
+
+    bail!("Failed to clone {remote_url} after {CLONE_ATTEMPTS} attempts: {last_error}")
+}
+
+pub fn git_commit(data_dir: &Path, filename: &str, message: &str) -> Result<()> {
     if !is_git_repo(data_dir) {
         bail!("Data directory is not a git repository. Run 'hours init' to set up.");
     }
 
-    run_git_checked(data_dir, &["add", "hours.json"])?;
+    run_git_checked(data_dir, &["add", "--", filename])?;
 
     if data_dir.join(".gitignore").exists() {
         let _ = run_git(data_dir, &["add", ".gitignore"]);
@@ -92,6 +186,76 @@ pub fn git_commit(data_dir: &Path, message: &str) -> Result<()> {
     Ok(())
 }
 
+fn git_add_force(data_dir: &Path, file: &Path) -> Result<()> {
+    let file = file.to_string_lossy();
+    run_git_checked(data_dir, &["add", "-f", "--", &file])
+}
+
+// Commits a single generated file, even if .gitignore excludes it (exports/ is
+// ignored by default).
+pub fn git_commit_export(
+    data_dir: &Path,
+    file: &Path,
+    config: &GitConfig,
+    message: &str,
+    no_git: bool,
+) -> Result<()> {
+    if is_git_disabled(no_git) {
+        bail!("Cannot commit the report because git is disabled (--no-git or HOURS_NO_GIT=1)");
+    }
+
+    if !git_binary_exists() {
+        bail!("git is not installed. Install git and try again.");
+    }
+
+    if !is_git_repo(data_dir) {
+        bail!("Data directory is not a git repository. Run 'hours init' to set up.");
+    }
+
+    let canonical_dir = data_dir
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", data_dir.display()))?;
+    let canonical_file = file
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", file.display()))?;
+    let relative = match canonical_file.strip_prefix(&canonical_dir) {
+        Ok(relative) => relative,
+        Err(_) => bail!(
+            "Cannot commit {}: it is outside the data directory {}",
+            file.display(),
+            data_dir.display()
+        ),
+    };
+
+    git_add_force(data_dir, relative)?;
+    let relative = relative.to_string_lossy();
+    run_git_checked(data_dir, &["commit", "-m", message, "--", &relative])?;
+
+    push_if_enabled(data_dir, config)
+}
+
+pub fn git_show_file(data_dir: &Path, rev: &str, file: &str, no_git: bool) -> Result<String> {
+    if is_git_disabled(no_git) {
+        bail!("Git history is unavailable because git is disabled (--no-git or HOURS_NO_GIT=1)");
+    }
+
+    if !git_binary_exists() {
+        bail!("git is not installed. Install git and try again.");
+    }
+
+    if !is_git_repo(data_dir) {
+        bail!("Data directory is not a git repository. Run 'hours init' to set up.");
+    }
+
+    let spec = format!("{rev}:{file}");
+    let output = run_git(data_dir, &["show", &spec])?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("Could not read {file} at revision {rev}: {}", stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 fn current_branch(data_dir: &Path) -> Result<String> {
     let output = run_git(data_dir, &["rev-parse", "--abbrev-ref", "HEAD"])?;
     if !output.status.success() {
@@ -100,20 +264,89 @@ fn current_branch(data_dir: &Path) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
-pub fn git_push(data_dir: &Path, remote: &str) -> Result<()> {
+// Pushes the local branch to the remote branch of the same name, or to
+// `config.branch` when set (`local:configured`).
+fn push_args(config: &GitConfig, local: &str) -> Vec<String> {
+    let refspec = match &config.branch {
+        Some(remote_branch) => format!("{local}:{remote_branch}"),
+        None => local.to_string(),
+    };
+    vec![
+        "push".to_string(),
+        "-u".to_string(),
+        config.remote.clone(),
+        refspec,
+    ]
+}
+
+pub fn git_push(data_dir: &Path, config: &GitConfig) -> Result<()> {
     let branch = current_branch(data_dir).unwrap_or_else(|_| "main".to_string());
-    let output = run_git(data_dir, &["push", "-u", remote, &branch])?;
+    let args = push_args(config, &branch);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let spinner = Spinner::start(&format!("Pushing to {}...", config.remote));
+    let output = run_git(data_dir, &args);
+    drop(spinner);
+    let output = output?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!("Push to {} failed: {}", config.remote, stderr.trim());
         eprintln!(
             "Warning: git push failed: {}. Data saved locally.",
             stderr.trim()
         );
     }
+    if config.notify_on_push {
+        notify_push_finished(output.status.success());
+    }
     Ok(())
 }
 
-pub fn git_sync(data_dir: &Path, config: &GitConfig, message: &str, no_git: bool) -> Result<()> {
+// Only ring/notify when someone is watching a terminal; scripted runs stay silent.
+fn notify_push_finished(succeeded: bool) {
+    if !std::io::stdout().is_terminal() {
+        return;
+    }
+
+    let message = if succeeded {
+        "git push finished"
+    } else {
+        "git push failed"
+    };
+
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+
+    #[cfg(target_os = "macos")]
+    {
+        let _ = Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "display notification \"{message}\" with title \"hours\""
+            ))
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn();
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let _ = Command::new("notify-send")
+            .args(["hours", message])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn();
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    let _ = message;
+}
+
+pub fn git_sync(
+    data_dir: &Path,
+    filename: &str,
+    config: &GitConfig,
+    message: &str,
+    no_git: bool,
+) -> Result<()> {
     if is_git_disabled(no_git) {
         return Ok(());
     }
@@ -127,15 +360,118 @@ pub fn git_sync(data_dir: &Path, config: &GitConfig, message: &str, no_git: bool
         bail!("Data directory is not a git repository. Run 'hours init' to set up.");
     }
 
-    git_commit(data_dir, message)?;
+    info!("Committing {filename}: {message}");
+    git_commit(data_dir, filename, message)?;
+
+    push_if_enabled(data_dir, config)
+}
+
+// With `auto_pull`, write commands call this before loading the data file, so
+// the fast-forward lands on a clean tree rather than failing over the file the
+// command is about to save. Git being disabled, missing, or not set up is left
+// for the `git_sync` that follows to report.
+pub fn pull_before_write(data_dir: &Path, config: &GitConfig, no_git: bool) -> Result<()> {
+    if !config.auto_pull
+        || is_git_disabled(no_git)
+        || !git_binary_exists()
+        || !is_git_repo(data_dir)
+    {
+        return Ok(());
+    }
+    if has_remote(data_dir)? {
+        git_pull(data_dir, config)?;
+    }
+    Ok(())
+}
+
+fn has_remote(data_dir: &Path) -> Result<bool> {
+    let remote_check = run_git(data_dir, &["remote"])?;
+    Ok(!String::from_utf8_lossy(&remote_check.stdout)
+        .trim()
+        .is_empty())
+}
+
+// The branch on the remote that syncs pull from and push to.
+pub fn remote_branch(data_dir: &Path, config: &GitConfig) -> String {
+    match &config.branch {
+        Some(branch) => branch.clone(),
+        None => current_branch(data_dir).unwrap_or_else(|_| "main".to_string()),
+    }
+}
+
+pub fn working_tree_clean(data_dir: &Path) -> Result<bool> {
+    let output = run_git(data_dir, &["status", "--porcelain"])?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git status failed: {}", stderr.trim());
+    }
+    Ok(output.stdout.iter().all(u8::is_ascii_whitespace))
+}
+
+// Commits HEAD has that `<remote>/<branch>` lacks, and the reverse, as of the
+// last fetch. None when there is no remote-tracking branch yet.
+pub fn ahead_behind(data_dir: &Path, remote: &str, branch: &str) -> Result<Option<(u32, u32)>> {
+    let tracking = format!("refs/remotes/{remote}/{branch}");
+    let exists = run_git(data_dir, &["rev-parse", "--verify", "--quiet", &tracking])?;
+    if !exists.status.success() {
+        return Ok(None);
+    }
+    let range = format!("HEAD...{tracking}");
+    let output = run_git(data_dir, &["rev-list", "--left-right", "--count", &range])?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git rev-list failed: {}", stderr.trim());
+    }
+    parse_left_right(&String::from_utf8_lossy(&output.stdout)).map(Some)
+}
+
+fn parse_left_right(output: &str) -> Result<(u32, u32)> {
+    let counts: Vec<u32> = output
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .with_context(|| format!("Unexpected git rev-list output '{}'", output.trim()))?;
+    match counts[..] {
+        [ahead, behind] => Ok((ahead, behind)),
+        _ => bail!("Unexpected git rev-list output '{}'", output.trim()),
+    }
+}
+
+// Fast-forwards onto `remote_branch`. Returns whether it succeeded. A
+// diverged history, or local edits the pull would overwrite, only warns:
+// nothing is merged and the data file is left as it is, so the following
+// commit and push behave as before.
+pub fn git_pull(data_dir: &Path, config: &GitConfig) -> Result<bool> {
+    let branch = remote_branch(data_dir, config);
+    let spinner = Spinner::start(&format!("Pulling from {}...", config.remote));
+    let output = run_git(data_dir, &["pull", "--ff-only", &config.remote, &branch]);
+    drop(spinner);
+    let output = output?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(
+            "Pull from {} {branch} failed: {}",
+            config.remote,
+            stderr.trim()
+        );
+        eprintln!(
+            "Warning: git pull --ff-only {} {branch} failed: {}. \
+             Local data left unchanged; reconcile the repository in {} by hand.",
+            config.remote,
+            stderr.trim(),
+            data_dir.display()
+        );
+        return Ok(false);
+    }
+    Ok(true)
+}
 
+fn push_if_enabled(data_dir: &Path, config: &GitConfig) -> Result<()> {
     if config.auto_push {
-        let remote_check = run_git(data_dir, &["remote"])?;
-        let remotes = String::from_utf8_lossy(&remote_check.stdout);
-        if remotes.trim().is_empty() {
-            eprintln!("Warning: No git remote configured. Data is saved locally only.");
+        if has_remote(data_dir)? {
+            git_push(data_dir, config)?;
         } else {
-            git_push(data_dir, &config.remote)?;
+            eprintln!("Warning: No git remote configured. Data is saved locally only.");
         }
     }
 
@@ -144,6 +480,7 @@ pub fn git_sync(data_dir: &Path, config: &GitConfig, message: &str, no_git: bool
 
 pub fn git_init_and_commit(
     data_dir: &Path,
+    filename: &str,
     config: &GitConfig,
     remote_url: &str,
     no_git: bool,
@@ -152,11 +489,16 @@ pub fn git_init_and_commit(
         return Ok(());
     }
 
-    git_init(data_dir, &config.remote, remote_url)?;
-    git_commit(data_dir, "Initialize hours tracking")?;
+    git_init(
+        data_dir,
+        &config.remote,
+        remote_url,
+        config.branch.as_deref(),
+    )?;
+    git_commit(data_dir, filename, "Initialize hours tracking")?;
 
     if config.auto_push {
-        git_push(data_dir, &config.remote)?;
+        git_push(data_dir, config)?;
     }
 
     Ok(())
@@ -226,11 +568,12 @@ mod tests {
     fn git_init_creates_repo_and_gitignore() {
         let tmp = TempDir::new().unwrap();
         let data_dir = tmp.path().join("data");
-        git_init(&data_dir, "origin", "git@example.com:test/test.git").unwrap();
+        git_init(&data_dir, "origin", "git@example.com:test/test.git", None).unwrap();
         assert!(is_git_repo(&data_dir));
         assert!(data_dir.join(".gitignore").exists());
         let gitignore = std::fs::read_to_string(data_dir.join(".gitignore")).unwrap();
         assert!(gitignore.contains("*.tmp"));
+        assert!(gitignore.contains("*.bak.*"));
         assert!(gitignore.contains("exports/"));
     }
 
@@ -238,11 +581,35 @@ mod tests {
     fn git_init_idempotent() {
         let tmp = TempDir::new().unwrap();
         let data_dir = tmp.path().join("data");
-        git_init(&data_dir, "origin", "git@example.com:test/test.git").unwrap();
-        git_init(&data_dir, "origin", "git@example.com:test/test.git").unwrap();
+        git_init(&data_dir, "origin", "git@example.com:test/test.git", None).unwrap();
+        git_init(&data_dir, "origin", "git@example.com:test/test.git", None).unwrap();
         assert!(is_git_repo(&data_dir));
     }
 
+    #[test]
+    fn git_clone_adopts_remote_contents() {
+        let tmp = TempDir::new().unwrap();
+        let remote = tmp.path().join("remote");
+        std::fs::create_dir(&remote).unwrap();
+        setup_git_repo(&remote);
+        std::fs::write(remote.join("hours.json"), r#"{"weeks":[]}"#).unwrap();
+        git_commit(&remote, "hours.json", "Seed").unwrap();
+
+        let data_dir = tmp.path().join("data");
+        git_clone(&data_dir, "origin", remote.to_str().unwrap(), false).unwrap();
+        assert!(is_git_repo(&data_dir));
+        assert!(data_dir.join("hours.json").exists());
+    }
+
+    #[test]
+    fn git_clone_refuses_non_empty_dir() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("hours.json"), r#"{"weeks":[]}"#).unwrap();
+        let err = git_clone(tmp.path(), "origin", "/nonexistent/remote", false).unwrap_err();
+        assert!(err.to_string().contains("is not empty"), "{err}");
+        assert!(tmp.path().join("hours.json").exists());
+    }
+
     #[test]
     fn git_commit_with_data_file() {
         let tmp = TempDir::new().unwrap();
@@ -250,13 +617,28 @@ mod tests {
         setup_git_repo(data_dir);
 
         std::fs::write(data_dir.join("hours.json"), r#"{"weeks":[]}"#).unwrap();
-        git_commit(data_dir, "Test commit").unwrap();
+        git_commit(data_dir, "hours.json", "Test commit").unwrap();
 
         let log = run_git(data_dir, &["log", "--oneline"]).unwrap();
         let log_text = String::from_utf8_lossy(&log.stdout);
         assert!(log_text.contains("Test commit"));
     }
 
+    #[test]
+    fn git_commit_custom_filename() {
+        let tmp = TempDir::new().unwrap();
+        let data_dir = tmp.path();
+        setup_git_repo(data_dir);
+
+        std::fs::write(data_dir.join("clinic.json"), r#"{"weeks":[]}"#).unwrap();
+        std::fs::write(data_dir.join("hours.json"), r#"{"weeks":[]}"#).unwrap();
+        git_commit(data_dir, "clinic.json", "Custom name").unwrap();
+
+        let contents = git_show_file(data_dir, "HEAD", "clinic.json", false).unwrap();
+        assert_eq!(contents, r#"{"weeks":[]}"#);
+        assert!(git_show_file(data_dir, "HEAD", "hours.json", false).is_err());
+    }
+
     #[test]
     fn git_commit_nothing_to_commit_is_ok() {
         let tmp = TempDir::new().unwrap();
@@ -264,8 +646,8 @@ mod tests {
         setup_git_repo(data_dir);
 
         std::fs::write(data_dir.join("hours.json"), r#"{"weeks":[]}"#).unwrap();
-        git_commit(data_dir, "First commit").unwrap();
-        let result = git_commit(data_dir, "Nothing changed");
+        git_commit(data_dir, "hours.json", "First commit").unwrap();
+        let result = git_commit(data_dir, "hours.json", "Nothing changed");
         assert!(result.is_ok());
     }
 
@@ -273,7 +655,7 @@ mod tests {
     fn git_commit_fails_if_not_repo() {
         let tmp = TempDir::new().unwrap();
         std::fs::write(tmp.path().join("hours.json"), r#"{"weeks":[]}"#).unwrap();
-        let result = git_commit(tmp.path(), "Should fail");
+        let result = git_commit(tmp.path(), "hours.json", "Should fail");
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -287,8 +669,11 @@ mod tests {
         let config = GitConfig {
             remote: "origin".to_string(),
             auto_push: true,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         };
-        let result = git_sync(tmp.path(), &config, "test", true);
+        let result = git_sync(tmp.path(), "hours.json", &config, "test", true);
         assert!(result.is_ok());
     }
 
@@ -303,14 +688,186 @@ mod tests {
         let config = GitConfig {
             remote: "origin".to_string(),
             auto_push: false,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         };
-        git_sync(data_dir, &config, "Sync commit", false).unwrap();
+        git_sync(data_dir, "hours.json", &config, "Sync commit", false).unwrap();
 
         let log = run_git(data_dir, &["log", "--oneline"]).unwrap();
         let log_text = String::from_utf8_lossy(&log.stdout);
         assert!(log_text.contains("Sync commit"));
     }
 
+    // A bare remote with one commit, plus two clones of it.
+    fn setup_two_clones(tmp: &Path) -> (std::path::PathBuf, std::path::PathBuf) {
+        let remote = tmp.join("remote.git");
+        let seed = tmp.join("seed");
+        Command::new("git")
+            .args(["init", "--bare"])
+            .arg(&remote)
+            .output()
+            .unwrap();
+        // Clones check out whatever the bare repo's HEAD names.
+        run_git_checked(&remote, &["symbolic-ref", "HEAD", "refs/heads/main"]).unwrap();
+        std::fs::create_dir_all(&seed).unwrap();
+        git_init(&seed, "origin", remote.to_str().unwrap(), Some("main")).unwrap();
+        set_git_test_config(&seed);
+        std::fs::write(seed.join("hours.json"), "one").unwrap();
+        git_commit(&seed, "hours.json", "first").unwrap();
+        run_git_checked(&seed, &["push", "-u", "origin", "main"]).unwrap();
+
+        let clones: Vec<_> = ["laptop", "desktop"]
+            .iter()
+            .map(|name| {
+                let dir = tmp.join(name);
+                Command::new("git")
+                    .arg("clone")
+                    .arg(&remote)
+                    .arg(&dir)
+                    .output()
+                    .unwrap();
+                set_git_test_config(&dir);
+                dir
+            })
+            .collect();
+        (clones[0].clone(), clones[1].clone())
+    }
+
+    fn pull_config() -> GitConfig {
+        GitConfig {
+            remote: "origin".to_string(),
+            auto_push: false,
+            notify_on_push: false,
+            auto_pull: true,
+            branch: None,
+        }
+    }
+
+    #[test]
+    fn pull_before_write_picks_up_remote_data_file() {
+        let tmp = TempDir::new().unwrap();
+        let (laptop, desktop) = setup_two_clones(tmp.path());
+
+        std::fs::write(laptop.join("hours.json"), "from laptop").unwrap();
+        git_commit(&laptop, "hours.json", "laptop change").unwrap();
+        run_git_checked(&laptop, &["push", "origin", "main"]).unwrap();
+
+        pull_before_write(&desktop, &pull_config(), false).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(desktop.join("hours.json")).unwrap(),
+            "from laptop"
+        );
+
+        std::fs::write(desktop.join("hours.json"), "from laptop, then desktop").unwrap();
+        git_sync(&desktop, "hours.json", &pull_config(), "Sync commit", false).unwrap();
+
+        let log = run_git(&desktop, &["log", "--oneline"]).unwrap();
+        let log_text = String::from_utf8_lossy(&log.stdout);
+        assert!(log_text.contains("laptop change"), "{log_text}");
+        assert!(log_text.contains("Sync commit"), "{log_text}");
+        assert!(!log_text.contains("Merge"), "{log_text}");
+        assert!(working_tree_clean(&desktop).unwrap());
+    }
+
+    #[test]
+    fn pull_before_write_skips_without_auto_pull() {
+        let tmp = TempDir::new().unwrap();
+        let (laptop, desktop) = setup_two_clones(tmp.path());
+
+        std::fs::write(laptop.join("hours.json"), "from laptop").unwrap();
+        git_commit(&laptop, "hours.json", "laptop change").unwrap();
+        run_git_checked(&laptop, &["push", "origin", "main"]).unwrap();
+
+        let config = GitConfig {
+            auto_pull: false,
+            ..pull_config()
+        };
+        pull_before_write(&desktop, &config, false).unwrap();
+        pull_before_write(&desktop, &pull_config(), true).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(desktop.join("hours.json")).unwrap(),
+            "one"
+        );
+    }
+
+    #[test]
+    fn git_pull_warns_on_diverged_history() {
+        let tmp = TempDir::new().unwrap();
+        let (laptop, desktop) = setup_two_clones(tmp.path());
+
+        std::fs::write(laptop.join("hours.json"), "laptop").unwrap();
+        git_commit(&laptop, "hours.json", "laptop change").unwrap();
+        run_git_checked(&laptop, &["push", "origin", "main"]).unwrap();
+
+        std::fs::write(desktop.join("hours.json"), "desktop").unwrap();
+        git_commit(&desktop, "hours.json", "desktop change").unwrap();
+
+        assert!(!git_pull(&desktop, &pull_config()).unwrap());
+        assert_eq!(
+            std::fs::read_to_string(desktop.join("hours.json")).unwrap(),
+            "desktop"
+        );
+        let log = run_git(&desktop, &["log", "--oneline"]).unwrap();
+        let log_text = String::from_utf8_lossy(&log.stdout);
+        assert!(!log_text.contains("laptop change"), "{log_text}");
+    }
+
+    #[test]
+    fn status_helpers_report_dirty_tree_and_divergence() {
+        let tmp = TempDir::new().unwrap();
+        let (laptop, desktop) = setup_two_clones(tmp.path());
+        assert!(working_tree_clean(&desktop).unwrap());
+        assert_eq!(
+            ahead_behind(&desktop, "origin", "main").unwrap(),
+            Some((0, 0))
+        );
+        assert_eq!(ahead_behind(&desktop, "origin", "trunk").unwrap(), None);
+
+        std::fs::write(laptop.join("hours.json"), "laptop").unwrap();
+        git_commit(&laptop, "hours.json", "laptop change").unwrap();
+        run_git_checked(&laptop, &["push", "origin", "main"]).unwrap();
+        std::fs::write(desktop.join("hours.json"), "desktop").unwrap();
+        assert!(!working_tree_clean(&desktop).unwrap());
+        git_commit(&desktop, "hours.json", "desktop change").unwrap();
+        assert!(working_tree_clean(&desktop).unwrap());
+
+        run_git_checked(&desktop, &["fetch", "origin"]).unwrap();
+        assert_eq!(
+            ahead_behind(&desktop, "origin", "main").unwrap(),
+            Some((1, 1))
+        );
+    }
+
+    #[test]
+    fn parse_left_right_counts() {
+        assert_eq!(parse_left_right("2\t5\n").unwrap(), (2, 5));
+        assert!(parse_left_right("2\n").is_err());
+        assert!(parse_left_right("x\ty").is_err());
+    }
+
+    #[test]
+    fn git_commit_export_rejects_file_outside_data_dir() {
+        let tmp = TempDir::new().unwrap();
+        let data_dir = tmp.path().join("data");
+        std::fs::create_dir_all(&data_dir).unwrap();
+        setup_git_repo(&data_dir);
+        let outside = tmp.path().join("report.pdf");
+        std::fs::write(&outside, b"%PDF").unwrap();
+
+        let config = GitConfig {
+            remote: "origin".to_string(),
+            auto_push: false,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
+        };
+        let err = git_commit_export(&data_dir, &outside, &config, "Add report", false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("outside the data directory"), "{err}");
+    }
+
     #[test]
     fn git_sync_no_push_when_auto_push_disabled() {
         let tmp = TempDir::new().unwrap();
@@ -322,8 +879,11 @@ mod tests {
         let config = GitConfig {
             remote: "origin".to_string(),
             auto_push: false,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         };
-        git_sync(data_dir, &config, "No push", false).unwrap();
+        git_sync(data_dir, "hours.json", &config, "No push", false).unwrap();
 
         let log = run_git(data_dir, &["log", "--oneline"]).unwrap();
         let log_text = String::from_utf8_lossy(&log.stdout);
@@ -341,12 +901,21 @@ mod tests {
         let config = GitConfig {
             remote: "origin".to_string(),
             auto_push: false,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         };
 
-        git_init(&data_dir, &config.remote, "git@example.com:test/test.git").unwrap();
+        git_init(
+            &data_dir,
+            &config.remote,
+            "git@example.com:test/test.git",
+            None,
+        )
+        .unwrap();
         set_git_test_config(&data_dir);
 
-        git_commit(&data_dir, "Initialize hours tracking").unwrap();
+        git_commit(&data_dir, "hours.json", "Initialize hours tracking").unwrap();
 
         assert!(is_git_repo(&data_dir));
         let log = run_git(&data_dir, &["log", "--oneline"]).unwrap();
@@ -362,8 +931,17 @@ mod tests {
         let config = GitConfig {
             remote: "origin".to_string(),
             auto_push: true,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         };
-        let result = git_init_and_commit(&data_dir, &config, "git@example.com:test/test.git", true);
+        let result = git_init_and_commit(
+            &data_dir,
+            "hours.json",
+            &config,
+            "git@example.com:test/test.git",
+            true,
+        );
         assert!(result.is_ok());
         assert!(!data_dir.exists());
     }
@@ -375,12 +953,73 @@ mod tests {
         setup_git_repo(data_dir);
 
         std::fs::write(data_dir.join("hours.json"), r#"{"weeks":[]}"#).unwrap();
-        git_commit(data_dir, "test").unwrap();
+        git_commit(data_dir, "hours.json", "test").unwrap();
 
-        let result = git_push(data_dir, "origin");
+        let config = GitConfig {
+            remote: "origin".to_string(),
+            auto_push: true,
+            notify_on_push: true,
+            auto_pull: false,
+            branch: None,
+        };
+        let result = git_push(data_dir, &config);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn push_args_use_configured_branch() {
+        let mut config = GitConfig {
+            remote: "origin".to_string(),
+            auto_push: true,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
+        };
+        assert_eq!(
+            push_args(&config, "master"),
+            ["push", "-u", "origin", "master"]
+        );
+
+        config.branch = Some("main".to_string());
+        assert_eq!(
+            push_args(&config, "master"),
+            ["push", "-u", "origin", "master:main"]
+        );
+    }
+
+    #[test]
+    fn git_push_targets_configured_branch() {
+        let tmp = TempDir::new().unwrap();
+        let remote = tmp.path().join("remote.git");
+        Command::new("git")
+            .args(["init", "--bare"])
+            .arg(&remote)
+            .output()
+            .unwrap();
+        let data_dir = tmp.path().join("data");
+        std::fs::create_dir_all(&data_dir).unwrap();
+        git_init(&data_dir, "origin", remote.to_str().unwrap(), Some("trunk")).unwrap();
+        set_git_test_config(&data_dir);
+
+        std::fs::write(data_dir.join("hours.json"), r#"{"weeks":[]}"#).unwrap();
+        git_commit(&data_dir, "hours.json", "first").unwrap();
+        assert_eq!(current_branch(&data_dir).unwrap(), "trunk");
+
+        let config = GitConfig {
+            remote: "origin".to_string(),
+            auto_push: true,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: Some("main".to_string()),
+        };
+        git_push(&data_dir, &config).unwrap();
+
+        let heads = run_git(&remote, &["branch", "--list"]).unwrap();
+        let heads = String::from_utf8_lossy(&heads.stdout);
+        assert!(heads.contains("main"), "{heads}");
+        assert!(!heads.contains("trunk"), "{heads}");
+    }
+
     #[test]
     fn git_sync_warns_no_remote() {
         let tmp = TempDir::new().unwrap();
@@ -392,8 +1031,48 @@ mod tests {
         let config = GitConfig {
             remote: "origin".to_string(),
             auto_push: true,
+            notify_on_push: false,
+            auto_pull: false,
+            branch: None,
         };
-        let result = git_sync(data_dir, &config, "test", false);
+        let result = git_sync(data_dir, "hours.json", &config, "test", false);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn git_show_file_reads_past_revision() {
+        let tmp = TempDir::new().unwrap();
+        let data_dir = tmp.path();
+        setup_git_repo(data_dir);
+
+        std::fs::write(data_dir.join("hours.json"), "first").unwrap();
+        git_commit(data_dir, "hours.json", "first").unwrap();
+        std::fs::write(data_dir.join("hours.json"), "second").unwrap();
+        git_commit(data_dir, "hours.json", "second").unwrap();
+
+        let contents = git_show_file(data_dir, "HEAD~1", "hours.json", false).unwrap();
+        assert_eq!(contents, "first");
+        let contents = git_show_file(data_dir, "HEAD", "hours.json", false).unwrap();
+        assert_eq!(contents, "second");
+    }
+
+    #[test]
+    fn git_show_file_unknown_revision() {
+        let tmp = TempDir::new().unwrap();
+        let data_dir = tmp.path();
+        setup_git_repo(data_dir);
+
+        std::fs::write(data_dir.join("hours.json"), "first").unwrap();
+        git_commit(data_dir, "hours.json", "first").unwrap();
+
+        let err = git_show_file(data_dir, "does-not-exist", "hours.json", false).unwrap_err();
+        assert!(err.to_string().contains("Could not read hours.json"));
+    }
+
+    #[test]
+    fn git_show_file_disabled() {
+        let tmp = TempDir::new().unwrap();
+        let err = git_show_file(tmp.path(), "HEAD", "hours.json", true).unwrap_err();
+        assert!(err.to_string().contains("git is disabled"));
+    }
 }
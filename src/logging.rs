@@ -0,0 +1,31 @@
+use std::fs::OpenOptions;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use env_logger::{Builder, Env, Target};
+
+// Silent unless asked: `RUST_LOG` turns logging on (to stderr), and
+// `--log-file` appends to a file at debug level. `RUST_LOG` still picks the
+// filter when both are given.
+pub fn init(log_file: Option<&Path>) -> Result<()> {
+    let default_filter = if log_file.is_some() {
+        "hours=debug"
+    } else {
+        "off"
+    };
+    let mut builder = Builder::from_env(Env::default().default_filter_or(default_filter));
+    builder.format_timestamp_millis();
+
+    if let Some(path) = log_file {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open log file {}", path.display()))?;
+        builder.target(Target::Pipe(Box::new(file)));
+    }
+
+    // Only fails if a logger is already installed, which leaves that one in place.
+    let _ = builder.try_init();
+    Ok(())
+}
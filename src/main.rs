@@ -1,7 +1,10 @@
 mod cli;
 mod config;
 mod data;
+mod display;
+mod export;
 mod git;
+mod logging;
 mod pdf;
 mod ui;
 
@@ -1,17 +1,18 @@
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Local, NaiveDate};
 use genpdf::elements::{self, Paragraph, TableLayout};
 use genpdf::fonts::{FontData, FontFamily};
 use genpdf::style::Style;
-use genpdf::{Alignment, Document, Element, Margins, PaperSize};
+use genpdf::{Alignment, Document, Element, Margins, PaperSize, Size};
 
 use crate::config::LicensureConfig;
-use crate::data::model::HoursData;
-use crate::data::week;
+use crate::data::model::{Category, HoursData};
+use crate::data::progress::{self, Progress};
+use crate::display;
 
-fn load_font_family() -> Result<FontFamily<FontData>> {
+pub(crate) fn load_font_family() -> Result<FontFamily<FontData>> {
     let regular = FontData::new(
         include_bytes!("../assets/fonts/LiberationSans-Regular.ttf").to_vec(),
         None,
@@ -44,6 +45,22 @@ fn load_font_family() -> Result<FontFamily<FontData>> {
     })
 }
 
+// Only the regular face is bundled; the appendix never styles its text.
+fn load_mono_font_family() -> Result<FontFamily<FontData>> {
+    let regular = FontData::new(
+        include_bytes!("../assets/fonts/DejaVuSansMono.ttf").to_vec(),
+        None,
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to load monospace font: {}", e))?;
+
+    Ok(FontFamily {
+        regular: regular.clone(),
+        bold: regular.clone(),
+        italic: regular.clone(),
+        bold_italic: regular,
+    })
+}
+
 fn format_date(date: NaiveDate) -> String {
     date.format("%B %e, %Y").to_string()
 }
@@ -57,21 +74,62 @@ fn format_week_range(start: NaiveDate, end: NaiveDate) -> String {
     )
 }
 
-fn months_between(start: NaiveDate, end: NaiveDate) -> u32 {
-    if end < start {
-        return 0;
+#[derive(Debug, Clone)]
+pub struct EmbeddedData {
+    pub name: String,
+    pub contents: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportOptions {
+    pub header_text: Option<String>,
+    pub footer_text: Option<String>,
+    pub landscape: bool,
+    pub precision: usize,
+    pub embedded_data: Option<EmbeddedData>,
+    // Adds a trailing weekly average over the last N weeks to the summary.
+    pub window: Option<u32>,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            header_text: None,
+            footer_text: None,
+            landscape: false,
+            precision: 1,
+            embedded_data: None,
+            window: None,
+        }
     }
-    let year_diff = end.year() - start.year();
-    let month_diff = end.month() as i32 - start.month() as i32;
-    let mut months = year_diff * 12 + month_diff;
-    if end.day() < start.day() {
-        months -= 1;
+}
+
+fn page_size(landscape: bool) -> Size {
+    let size = Size::from(PaperSize::Letter);
+    if landscape {
+        Size::new(size.height, size.width)
+    } else {
+        size
     }
-    months.max(0) as u32
 }
 
-fn round1(val: f64) -> f64 {
-    (val * 10.0).round() / 10.0
+fn text_block_lines(text: &str) -> Vec<&str> {
+    let trimmed = text.trim_matches(|c| c == '\n' || c == '\r');
+    if trimmed.trim().is_empty() {
+        return Vec::new();
+    }
+    trimmed.lines().map(|l| l.trim_end()).collect()
+}
+
+fn build_text_block(doc: &mut Document, lines: &[&str]) {
+    let style = Style::new().with_font_size(10);
+    for &line in lines {
+        if line.is_empty() {
+            doc.push(elements::Break::new(1.0));
+        } else {
+            doc.push(Paragraph::new(line).styled(style));
+        }
+    }
 }
 
 fn styled_centered(text: &str, style: Style) -> impl Element {
@@ -111,7 +169,9 @@ fn build_header(doc: &mut Document, data: &HoursData, config: &LicensureConfig)
     doc.push(elements::Break::new(1.5));
 }
 
-fn build_hours_table(doc: &mut Document, data: &HoursData) {
+fn build_hours_table(doc: &mut Document, data: &HoursData, precision: usize) {
+    let fmt = |value: f64| display::format_hours(value, precision);
+
     let non_zero_weeks: Vec<_> = data.weeks.iter().filter(|w| w.total() > 0.0).collect();
 
     let mut table = TableLayout::new(vec![3, 2, 2, 2, 2, 2]);
@@ -148,17 +208,11 @@ fn build_hours_table(doc: &mut Document, data: &HoursData) {
         table
             .row()
             .element(Paragraph::new(format_week_range(w.start, w.end)).styled(body_style))
-            .element(styled_right(
-                &format!("{:.1}", w.individual_supervision),
-                body_style,
-            ))
-            .element(styled_right(
-                &format!("{:.1}", w.group_supervision),
-                body_style,
-            ))
-            .element(styled_right(&format!("{:.1}", w.direct), body_style))
-            .element(styled_right(&format!("{:.1}", w.indirect), body_style))
-            .element(styled_right(&format!("{:.1}", w.total()), body_style))
+            .element(styled_right(&fmt(w.individual_supervision), body_style))
+            .element(styled_right(&fmt(w.group_supervision), body_style))
+            .element(styled_right(&fmt(w.direct), body_style))
+            .element(styled_right(&fmt(w.indirect), body_style))
+            .element(styled_right(&fmt(w.total()), body_style))
             .push()
             .expect("Invalid table data row");
     }
@@ -166,107 +220,210 @@ fn build_hours_table(doc: &mut Document, data: &HoursData) {
     table
         .row()
         .element(Paragraph::new("TOTALS").styled(bold_body))
-        .element(styled_right(&format!("{:.1}", sum_ind), bold_body))
-        .element(styled_right(&format!("{:.1}", sum_grp), bold_body))
-        .element(styled_right(&format!("{:.1}", sum_direct), bold_body))
-        .element(styled_right(&format!("{:.1}", sum_indirect), bold_body))
-        .element(styled_right(&format!("{:.1}", sum_total), bold_body))
+        .element(styled_right(&fmt(sum_ind), bold_body))
+        .element(styled_right(&fmt(sum_grp), bold_body))
+        .element(styled_right(&fmt(sum_direct), bold_body))
+        .element(styled_right(&fmt(sum_indirect), bold_body))
+        .element(styled_right(&fmt(sum_total), bold_body))
         .push()
         .expect("Invalid table totals row");
 
     doc.push(table);
 }
 
-fn build_progress_summary(doc: &mut Document, data: &HoursData, config: &LicensureConfig) {
+fn progress_lines(
+    data: &HoursData,
+    config: &LicensureConfig,
+    today: NaiveDate,
+    precision: usize,
+    window: Option<u32>,
+) -> Vec<String> {
+    let progress = Progress::compute(data, config, today);
+    let fmt = |value: f64| display::format_hours(value, precision);
+
+    let mut lines = vec![
+        format!(
+            "Total supervised hours:    {} / {}  ({:.1}%)",
+            fmt(progress.total_hours.current),
+            display::format_hours(progress.total_hours.target.into(), 0),
+            display::round(progress.total_hours.percentage)
+        ),
+        format!(
+            "Direct client hours:       {} / {}  ({:.1}%)",
+            fmt(progress.direct_hours.current),
+            display::format_hours(progress.direct_hours.target.into(), 0),
+            display::round(progress.direct_hours.percentage)
+        ),
+    ];
+    // Direct is shown above; the rest only once the board sets a minimum.
+    for category in &progress.by_category {
+        if category.category == Category::Direct {
+            continue;
+        }
+        if let (Some(target), Some(pct)) = (category.target, category.percentage) {
+            lines.push(format!(
+                "{:<27}{} / {}  ({:.1}%)",
+                format!("{}:", category.category.long_name()),
+                fmt(category.current),
+                display::format_hours(target.into(), 0),
+                display::round(pct)
+            ));
+        }
+    }
+    lines.extend([
+        format!(
+            "Months of experience:        {}  /   {}   ({:.1}%)",
+            progress.months.current,
+            progress.months.target,
+            display::round(progress.months.percentage)
+        ),
+        format!(
+            "Weekly average:             {} hrs/week (target: {})",
+            fmt(progress.weekly_average.current),
+            fmt(progress.weekly_average.target)
+        ),
+    ]);
+    if let Some(weeks) = window {
+        let window = progress::window_average(data, config, today, weeks);
+        lines.push(format!(
+            "{:<27}{} hrs/week (target: {})",
+            format!("Last {weeks} weeks average:"),
+            fmt(window.average.current),
+            fmt(window.average.target)
+        ));
+    }
+    lines.push(format!(
+        "Weeks logged:               {}",
+        progress.weeks_logged
+    ));
+    lines
+}
+
+fn build_progress_summary(
+    doc: &mut Document,
+    data: &HoursData,
+    config: &LicensureConfig,
+    precision: usize,
+    window: Option<u32>,
+) {
     let today = Local::now().date_naive();
-    let start_date = config.start_date;
 
-    let total_hours: f64 = data.weeks.iter().map(|w| w.total()).sum();
-    let direct_hours: f64 = data.weeks.iter().map(|w| w.direct).sum();
-    let months = months_between(start_date, today);
+    doc.push(elements::Break::new(1.5));
 
-    let (current_week_start, _) = week::current_week(today);
-    let weeks_elapsed = if current_week_start >= start_date {
-        ((current_week_start - start_date).num_days() / 7) + 1
-    } else {
-        1
-    };
+    doc.push(
+        Paragraph::new("Licensure Progress Summary").styled(Style::new().bold().with_font_size(12)),
+    );
 
-    let weekly_average = if weeks_elapsed > 0 {
-        direct_hours / weeks_elapsed as f64
-    } else {
-        0.0
-    };
+    doc.push(elements::Break::new(0.5));
 
-    let weeks_logged = data.weeks.iter().filter(|w| w.total() > 0.0).count();
+    let summary_style = Style::new().with_font_size(10);
 
-    let total_pct = if config.total_hours_target > 0 {
-        total_hours / config.total_hours_target as f64 * 100.0
-    } else {
-        0.0
-    };
-    let direct_pct = if config.direct_hours_target > 0 {
-        direct_hours / config.direct_hours_target as f64 * 100.0
-    } else {
-        0.0
-    };
-    let months_pct = if config.min_months > 0 {
-        months as f64 / config.min_months as f64 * 100.0
+    for line in progress_lines(data, config, today, precision, window) {
+        doc.push(Paragraph::new(line).styled(summary_style));
+    }
+}
+
+// The supervisor's name and license when configured, then lines to sign and
+// date by hand.
+fn signature_lines(config: &LicensureConfig) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(name) = &config.supervisor_name {
+        lines.push(format!("Supervisor: {name}"));
+    }
+    if let Some(license) = &config.supervisor_license {
+        lines.push(format!("License: {license}"));
+    }
+    let label = if lines.is_empty() {
+        "Supervisor signature"
     } else {
-        0.0
+        "Signature"
     };
+    lines.push(format!(
+        "{label}: {}   Date: {}",
+        "_".repeat(32),
+        "_".repeat(14)
+    ));
+    lines
+}
 
-    doc.push(elements::Break::new(1.5));
+fn build_signature_block(doc: &mut Document, config: &LicensureConfig) {
+    doc.push(elements::Break::new(2.0));
+    let style = Style::new().with_font_size(10);
+    for (i, line) in signature_lines(config).into_iter().enumerate() {
+        if i > 0 {
+            doc.push(elements::Break::new(0.5));
+        }
+        doc.push(Paragraph::new(line).styled(style));
+    }
+}
+
+// genpdf cannot attach files, so the source data is printed verbatim on its own
+// pages instead. Indentation survives because genpdf keeps spaces as words.
+fn build_data_appendix(doc: &mut Document, embedded: &EmbeddedData) -> Result<()> {
+    let mono = doc.add_font_family(load_mono_font_family()?);
+    let code_style = Style::new().with_font_family(mono).with_font_size(7);
 
+    doc.push(elements::PageBreak::new());
     doc.push(
-        Paragraph::new("Licensure Progress Summary").styled(Style::new().bold().with_font_size(12)),
+        Paragraph::new(format!("Appendix: Source Data ({})", embedded.name))
+            .styled(Style::new().bold().with_font_size(12)),
     );
-
     doc.push(elements::Break::new(0.5));
 
-    let summary_style = Style::new().with_font_size(10);
+    for line in embedded.contents.lines() {
+        if line.trim().is_empty() {
+            doc.push(elements::Break::new(1.0));
+        } else {
+            doc.push(Paragraph::new(line).styled(code_style));
+        }
+    }
 
-    let lines = vec![
-        format!(
-            "Total supervised hours:    {:.1} / {}  ({:.1}%)",
-            round1(total_hours),
-            config.total_hours_target,
-            round1(total_pct)
-        ),
-        format!(
-            "Direct client hours:       {:.1} / {}  ({:.1}%)",
-            round1(direct_hours),
-            config.direct_hours_target,
-            round1(direct_pct)
-        ),
-        format!(
-            "Months of experience:        {}  /   {}   ({:.1}%)",
-            months,
-            config.min_months,
-            round1(months_pct)
-        ),
-        format!(
-            "Weekly average:             {:.1} hrs/week (target: {:.1})",
-            round1(weekly_average),
-            config.min_weekly_average
-        ),
-        format!("Weeks logged:               {}", weeks_logged),
-    ];
+    Ok(())
+}
 
-    for line in lines {
-        doc.push(Paragraph::new(line).styled(summary_style));
+// The report's parts in page order; `generate_report` renders each in turn.
+#[derive(Debug, PartialEq)]
+enum Section<'a> {
+    HeaderText(Vec<&'a str>),
+    Header,
+    HoursTable,
+    NoData,
+    FooterText(Vec<&'a str>),
+    Signature,
+    DataAppendix,
+}
+
+fn report_sections<'a>(data: &HoursData, options: &'a ReportOptions) -> Vec<Section<'a>> {
+    let mut sections = Vec::new();
+    if let Some(text) = &options.header_text {
+        sections.push(Section::HeaderText(text_block_lines(text)));
+    }
+    sections.push(Section::Header);
+    if data.weeks.iter().any(|w| w.total() > 0.0) {
+        sections.push(Section::HoursTable);
+    } else {
+        sections.push(Section::NoData);
     }
+    if let Some(text) = &options.footer_text {
+        sections.push(Section::FooterText(text_block_lines(text)));
+    }
+    sections.push(Section::Signature);
+    if options.embedded_data.is_some() {
+        sections.push(Section::DataAppendix);
+    }
+    sections
 }
 
 pub fn generate_report(
     data: &HoursData,
     config: &LicensureConfig,
+    options: &ReportOptions,
     output_path: &Path,
 ) -> Result<()> {
     let font_family = load_font_family()?;
     let mut doc = Document::new(font_family);
 
-    doc.set_paper_size(PaperSize::Letter);
+    doc.set_paper_size(page_size(options.landscape));
     doc.set_font_size(10);
     doc.set_line_spacing(1.25);
 
@@ -276,18 +433,32 @@ pub fn generate_report(
 
     doc.set_title("Counseling Licensure Hours Report");
 
-    build_header(&mut doc, data, config);
-
-    let has_data = data.weeks.iter().any(|w| w.total() > 0.0);
-
-    if has_data {
-        build_hours_table(&mut doc, data);
-        build_progress_summary(&mut doc, data, config);
-    } else {
-        doc.push(
-            Paragraph::new("No hours have been logged yet.")
-                .styled(Style::new().with_font_size(10)),
-        );
+    for section in report_sections(data, options) {
+        match section {
+            Section::HeaderText(lines) => {
+                build_text_block(&mut doc, &lines);
+                doc.push(elements::Break::new(1.0));
+            }
+            Section::Header => build_header(&mut doc, data, config),
+            Section::HoursTable => {
+                build_hours_table(&mut doc, data, options.precision);
+                build_progress_summary(&mut doc, data, config, options.precision, options.window);
+            }
+            Section::NoData => doc.push(
+                Paragraph::new("No hours have been logged yet.")
+                    .styled(Style::new().with_font_size(10)),
+            ),
+            Section::FooterText(lines) => {
+                doc.push(elements::Break::new(1.5));
+                build_text_block(&mut doc, &lines);
+            }
+            Section::Signature => build_signature_block(&mut doc, config),
+            Section::DataAppendix => {
+                if let Some(embedded) = &options.embedded_data {
+                    build_data_appendix(&mut doc, embedded)?;
+                }
+            }
+        }
     }
 
     doc.render_to_file(output_path)
@@ -314,6 +485,14 @@ mod tests {
             direct_hours_target: 1200,
             min_months: 24,
             min_weekly_average: 15.0,
+            min_monthly_hours: None,
+            months_inclusive: false,
+            week_start_day: None,
+            individual_supervision_target: None,
+            group_supervision_target: None,
+            indirect_target: None,
+            supervisor_name: None,
+            supervisor_license: None,
         }
     }
 
@@ -324,7 +503,7 @@ mod tests {
         let data = HoursData::new();
         let config = sample_config();
 
-        generate_report(&data, &config, &path).unwrap();
+        generate_report(&data, &config, &ReportOptions::default(), &path).unwrap();
 
         assert!(path.exists());
         let metadata = std::fs::metadata(&path).unwrap();
@@ -335,23 +514,56 @@ mod tests {
     fn generate_report_single_week() {
         let tmp = TempDir::new().unwrap();
         let path = tmp.path().join("report.pdf");
-        let data = HoursData {
-            weeks: vec![WeekEntry {
-                start: date(2025, 1, 28),
-                end: date(2025, 2, 3),
-                individual_supervision: 1.0,
-                group_supervision: 2.0,
-                direct: 14.5,
-                indirect: 6.0,
-            }],
-        };
-        let config = sample_config();
-
-        generate_report(&data, &config, &path).unwrap();
+        let data = HoursData::from_weeks(vec![WeekEntry {
+            start: date(2025, 1, 28),
+            end: date(2025, 2, 3),
+            individual_supervision: 1.0,
+            group_supervision: 2.0,
+            direct: 14.5,
+            indirect: 6.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
+        let mut config = sample_config();
+
+        generate_report(&data, &config, &ReportOptions::default(), &path).unwrap();
 
         assert!(path.exists());
         let metadata = std::fs::metadata(&path).unwrap();
         assert!(metadata.len() > 0);
+
+        config.supervisor_name = Some("Dr. Jane Smith".to_string());
+        config.supervisor_license = Some("LPC-S 12345".to_string());
+        let signed = tmp.path().join("signed.pdf");
+        generate_report(&data, &config, &ReportOptions::default(), &signed).unwrap();
+        assert!(std::fs::metadata(&signed).unwrap().len() > 0);
+    }
+
+    #[test]
+    fn signature_lines_fall_back_to_generic_blanks() {
+        let mut config = sample_config();
+        let blank = format!("{}   Date: {}", "_".repeat(32), "_".repeat(14));
+        assert_eq!(
+            signature_lines(&config),
+            vec![format!("Supervisor signature: {blank}")]
+        );
+
+        config.supervisor_name = Some("Dr. Jane Smith".to_string());
+        config.supervisor_license = Some("LPC-S 12345".to_string());
+        assert_eq!(
+            signature_lines(&config),
+            vec![
+                "Supervisor: Dr. Jane Smith".to_string(),
+                "License: LPC-S 12345".to_string(),
+                format!("Signature: {blank}"),
+            ]
+        );
     }
 
     #[test]
@@ -370,13 +582,21 @@ mod tests {
                 group_supervision: 1.5,
                 direct: 10.0,
                 indirect: 3.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
             });
             start += chrono::Duration::days(7);
         }
-        let data = HoursData { weeks };
+        let data = HoursData::from_weeks(weeks);
         let config = sample_config();
 
-        generate_report(&data, &config, &path).unwrap();
+        generate_report(&data, &config, &ReportOptions::default(), &path).unwrap();
 
         assert!(path.exists());
         let metadata = std::fs::metadata(&path).unwrap();
@@ -391,11 +611,155 @@ mod tests {
         let config = sample_config();
 
         std::fs::create_dir_all(path.parent().unwrap()).unwrap();
-        generate_report(&data, &config, &path).unwrap();
+        generate_report(&data, &config, &ReportOptions::default(), &path).unwrap();
 
         assert!(path.exists());
     }
 
+    #[test]
+    fn generate_report_with_header_and_footer_text() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("custom.pdf");
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 12.0;
+        let data = HoursData::from_weeks(vec![entry]);
+        let options = ReportOptions {
+            header_text: Some("Riverside Counseling Program\nTrainee: A. Example\n".to_string()),
+            footer_text: Some("I attest that these hours are accurate.".to_string()),
+            ..ReportOptions::default()
+        };
+
+        // Header text above the report header, footer text between the
+        // summary and the signature block.
+        assert_eq!(
+            report_sections(&data, &options),
+            vec![
+                Section::HeaderText(vec!["Riverside Counseling Program", "Trainee: A. Example"]),
+                Section::Header,
+                Section::HoursTable,
+                Section::FooterText(vec!["I attest that these hours are accurate."]),
+                Section::Signature,
+            ]
+        );
+
+        generate_report(&data, &sample_config(), &options, &path).unwrap();
+        assert!(std::fs::read(&path).unwrap().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn report_sections_without_custom_text() {
+        let data = HoursData::new();
+        let options = ReportOptions {
+            embedded_data: Some(EmbeddedData {
+                name: "hours.json".to_string(),
+                contents: "[]".to_string(),
+            }),
+            ..ReportOptions::default()
+        };
+        assert_eq!(
+            report_sections(&data, &options),
+            vec![
+                Section::Header,
+                Section::NoData,
+                Section::Signature,
+                Section::DataAppendix,
+            ]
+        );
+    }
+
+    #[test]
+    fn page_size_landscape_swaps_dimensions() {
+        let portrait = page_size(false);
+        let landscape = page_size(true);
+        assert_eq!(portrait, Size::from(PaperSize::Letter));
+        assert_eq!(landscape.width, portrait.height);
+        assert_eq!(landscape.height, portrait.width);
+        assert!(landscape.width > landscape.height);
+    }
+
+    #[test]
+    fn generate_report_with_embedded_data_appendix() {
+        let tmp = TempDir::new().unwrap();
+        let plain_path = tmp.path().join("plain.pdf");
+        let embedded_path = tmp.path().join("embedded.pdf");
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 12.0;
+        let data = HoursData::from_weeks(vec![entry]);
+        let contents = serde_json::to_string_pretty(&data).unwrap();
+
+        generate_report(
+            &data,
+            &sample_config(),
+            &ReportOptions::default(),
+            &plain_path,
+        )
+        .unwrap();
+        let options = ReportOptions {
+            embedded_data: Some(EmbeddedData {
+                name: "hours.json".to_string(),
+                contents,
+            }),
+            ..ReportOptions::default()
+        };
+        generate_report(&data, &sample_config(), &options, &embedded_path).unwrap();
+
+        let plain = std::fs::read(&plain_path).unwrap();
+        let embedded = std::fs::read(&embedded_path).unwrap();
+        assert!(embedded.starts_with(b"%PDF"));
+        assert!(embedded.len() > plain.len());
+        let text = String::from_utf8_lossy(&embedded);
+        assert!(
+            text.contains("DejaVuSansMono"),
+            "monospace font not embedded"
+        );
+        // The appendix starts on a page of its own.
+        let pages = |bytes: &[u8]| String::from_utf8_lossy(bytes).matches("/MediaBox").count();
+        assert_eq!(pages(&embedded), pages(&plain) + 1);
+    }
+
+    #[test]
+    fn mono_font_loads() {
+        assert!(load_mono_font_family().is_ok());
+    }
+
+    #[test]
+    fn generate_landscape_report() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("landscape.pdf");
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 12.0;
+        let data = HoursData::from_weeks(vec![entry]);
+        let options = ReportOptions {
+            landscape: true,
+            ..ReportOptions::default()
+        };
+
+        generate_report(&data, &sample_config(), &options, &path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(
+            text.contains("/MediaBox[0 0 790.87 612.28]"),
+            "expected an 11in x 8.5in landscape page"
+        );
+    }
+
+    #[test]
+    fn text_block_lines_preserves_inner_blank_lines() {
+        let lines = text_block_lines("\nProgram: Riverside\n\nSupervisor: Dr. Smith  \n\n");
+        assert_eq!(
+            lines,
+            vec!["Program: Riverside", "", "Supervisor: Dr. Smith"]
+        );
+    }
+
+    #[test]
+    fn text_block_lines_empty_input() {
+        assert!(text_block_lines("").is_empty());
+        assert!(text_block_lines("  \n \n").is_empty());
+    }
+
     #[test]
     fn format_date_outputs_expected_format() {
         let d = date(2025, 1, 28);
@@ -416,67 +780,105 @@ mod tests {
     }
 
     #[test]
-    fn months_between_same_date() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 1, 28)), 0);
-    }
+    fn generate_report_weeks_with_zero_hours_excluded() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("report.pdf");
+        let data = HoursData::from_weeks(vec![
+            WeekEntry {
+                start: date(2025, 1, 28),
+                end: date(2025, 2, 3),
+                individual_supervision: 0.0,
+                group_supervision: 0.0,
+                direct: 0.0,
+                indirect: 0.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
+            },
+            WeekEntry {
+                start: date(2025, 2, 4),
+                end: date(2025, 2, 10),
+                individual_supervision: 1.0,
+                group_supervision: 0.0,
+                direct: 5.0,
+                indirect: 0.0,
+                entries: 0,
+                added_hours: None,
+                created_at: None,
+                updated_at: None,
+                note: None,
+                target: None,
+                excluded: false,
+                days: Vec::new(),
+            },
+        ]);
+        let config = sample_config();
 
-    #[test]
-    fn months_between_one_month() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 2, 28)), 1);
-    }
+        generate_report(&data, &config, &ReportOptions::default(), &path).unwrap();
 
-    #[test]
-    fn months_between_partial_month() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2025, 2, 27)), 0);
+        assert!(path.exists());
+        let metadata = std::fs::metadata(&path).unwrap();
+        assert!(metadata.len() > 0);
     }
 
     #[test]
-    fn months_between_across_years() {
-        assert_eq!(months_between(date(2025, 1, 28), date(2027, 1, 28)), 24);
+    fn progress_lines_round_like_other_views() {
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 0.25;
+        let data = HoursData::from_weeks(vec![entry]);
+
+        let lines = progress_lines(&data, &sample_config(), date(2025, 2, 3), 1, None);
+        assert!(lines[0].contains(&format!("{} / 3,000", display::hours(0.25))));
+        assert!(lines[0].contains("0.3 / 3,000"), "{}", lines[0]);
     }
 
     #[test]
-    fn months_between_end_before_start() {
-        assert_eq!(months_between(date(2025, 6, 1), date(2025, 1, 1)), 0);
+    fn progress_lines_group_thousands_and_respect_precision() {
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.direct = 2450.5;
+        let data = HoursData::from_weeks(vec![entry]);
+
+        let lines = progress_lines(&data, &sample_config(), date(2025, 2, 3), 1, None);
+        assert!(lines[0].contains("2,450.5 / 3,000"), "{}", lines[0]);
+
+        let lines = progress_lines(&data, &sample_config(), date(2025, 2, 3), 2, None);
+        assert!(lines[1].contains("2,450.50 / 1,200"), "{}", lines[1]);
     }
 
     #[test]
-    fn round1_values() {
-        assert!((round1(8.233) - 8.2).abs() < f64::EPSILON);
-        assert!((round1(102.75) - 102.8).abs() < f64::EPSILON);
-        assert!((round1(0.0) - 0.0).abs() < f64::EPSILON);
+    fn progress_lines_show_only_configured_category_targets() {
+        let mut entry = WeekEntry::new(date(2025, 1, 28), date(2025, 2, 3));
+        entry.group_supervision = 25.0;
+        let data = HoursData::from_weeks(vec![entry]);
+
+        let lines = progress_lines(&data, &sample_config(), date(2025, 2, 3), 1, None);
+        assert!(!lines.iter().any(|l| l.starts_with("Group Supervision")));
+
+        let mut config = sample_config();
+        config.group_supervision_target = Some(100);
+        let lines = progress_lines(&data, &config, date(2025, 2, 3), 1, None);
+        assert_eq!(lines[2], "Group Supervision:         25.0 / 100  (25.0%)");
+        assert!(!lines.iter().any(|l| l.starts_with("Indirect")));
     }
 
     #[test]
-    fn generate_report_weeks_with_zero_hours_excluded() {
-        let tmp = TempDir::new().unwrap();
-        let path = tmp.path().join("report.pdf");
-        let data = HoursData {
-            weeks: vec![
-                WeekEntry {
-                    start: date(2025, 1, 28),
-                    end: date(2025, 2, 3),
-                    individual_supervision: 0.0,
-                    group_supervision: 0.0,
-                    direct: 0.0,
-                    indirect: 0.0,
-                },
-                WeekEntry {
-                    start: date(2025, 2, 4),
-                    end: date(2025, 2, 10),
-                    individual_supervision: 1.0,
-                    group_supervision: 0.0,
-                    direct: 5.0,
-                    indirect: 0.0,
-                },
-            ],
-        };
-        let config = sample_config();
+    fn progress_lines_add_trailing_average_when_windowed() {
+        let mut entry = WeekEntry::new(date(2025, 2, 4), date(2025, 2, 10));
+        entry.direct = 18.0;
+        let data = HoursData::from_weeks(vec![entry]);
 
-        generate_report(&data, &config, &path).unwrap();
+        let lines = progress_lines(&data, &sample_config(), date(2025, 2, 5), 1, None);
+        assert!(!lines.iter().any(|l| l.starts_with("Last ")));
 
-        assert!(path.exists());
-        let metadata = std::fs::metadata(&path).unwrap();
-        assert!(metadata.len() > 0);
+        let lines = progress_lines(&data, &sample_config(), date(2025, 2, 5), 1, Some(1));
+        assert!(
+            lines.contains(&"Last 1 weeks average:      18.0 hrs/week (target: 15.0)".to_string())
+        );
+        assert!(lines.last().unwrap().starts_with("Weeks logged:"));
     }
 }
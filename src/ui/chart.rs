@@ -0,0 +1,164 @@
+use crossterm::style::Stylize;
+
+use crate::display;
+
+pub const CHART_HEIGHT: usize = 8;
+
+const MIN_PLOT_WIDTH: usize = 10;
+const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const PACE_MARK: char = '·';
+
+// One column per week of cumulative hours, filled as an area chart, with the
+// target pace (`pace` hours per week) dotted over the empty cells. The y-axis
+// runs from 0 to the target, or to the highest value once the target is passed.
+// Weeks are downsampled to fit `width`, keeping each bucket's last value.
+pub fn render_cumulative(
+    values: &[f64],
+    target: f64,
+    pace: f64,
+    width: usize,
+    color: bool,
+) -> String {
+    if values.is_empty() {
+        return "No weeks to chart yet.".to_string();
+    }
+
+    let peak = values.iter().copied().fold(target, f64::max);
+    let y_max = if peak > 0.0 { peak } else { 1.0 };
+
+    let label_width = display::format_hours(y_max, 0).len();
+    let plot_width = width
+        .saturating_sub(label_width + 2)
+        .max(MIN_PLOT_WIDTH)
+        .min(values.len());
+
+    let columns: Vec<(f64, f64)> = (0..plot_width)
+        .map(|c| {
+            let idx = (c + 1) * values.len() / plot_width - 1;
+            (values[idx], pace * (idx + 1) as f64)
+        })
+        .collect();
+
+    let band = y_max / CHART_HEIGHT as f64;
+    let mut lines = Vec::with_capacity(CHART_HEIGHT + 2);
+    for row in (0..CHART_HEIGHT).rev() {
+        let low = row as f64 * band;
+        let high = low + band;
+        // Label every other gridline: 100%, 75%, 50%, 25% of the axis.
+        let label = if (CHART_HEIGHT - 1 - row).is_multiple_of(2) {
+            display::format_hours(high, 0)
+        } else {
+            String::new()
+        };
+
+        let cells: String = columns
+            .iter()
+            .map(|&(value, pace_value)| {
+                let eighths = (((value - low) / band) * 8.0).round().clamp(0.0, 8.0) as usize;
+                if eighths > 0 {
+                    let block = BLOCKS[eighths].to_string();
+                    if color {
+                        block.green().to_string()
+                    } else {
+                        block
+                    }
+                } else if pace > 0.0 && pace_value >= low && pace_value < high {
+                    if color {
+                        PACE_MARK.to_string().yellow().to_string()
+                    } else {
+                        PACE_MARK.to_string()
+                    }
+                } else {
+                    " ".to_string()
+                }
+            })
+            .collect();
+
+        lines.push(format!("{label:>label_width$} ┤{cells}"));
+    }
+
+    lines.push(format!(
+        "{:>label_width$} └{}",
+        "0",
+        "─".repeat(columns.len())
+    ));
+    lines.push(format!(
+        "{:>label_width$}  █ logged  {PACE_MARK} target pace ({} hrs/wk)",
+        "",
+        display::hours(pace)
+    ));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plot_rows(chart: &str) -> Vec<String> {
+        chart
+            .lines()
+            .take(CHART_HEIGHT)
+            .map(|line| line.split('┤').nth(1).unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn value_at_target_fills_full_column() {
+        let chart = render_cumulative(&[0.0, 3000.0], 3000.0, 0.0, 80, false);
+        let rows = plot_rows(&chart);
+        assert!(rows.iter().all(|r| r.ends_with('█')), "{chart}");
+        assert!(rows.iter().all(|r| r.starts_with(' ')), "{chart}");
+        assert!(chart.lines().next().unwrap().starts_with("3,000 ┤"));
+    }
+
+    #[test]
+    fn half_target_fills_half_the_rows() {
+        let chart = render_cumulative(&[1500.0], 3000.0, 0.0, 80, false);
+        let filled = plot_rows(&chart).iter().filter(|r| *r == "█").count();
+        assert_eq!(filled, CHART_HEIGHT / 2);
+    }
+
+    #[test]
+    fn values_above_target_rescale_axis() {
+        let chart = render_cumulative(&[4000.0], 3000.0, 0.0, 80, false);
+        assert!(chart.lines().next().unwrap().starts_with("4,000 ┤"));
+        assert!(plot_rows(&chart).iter().all(|r| r == "█"));
+    }
+
+    #[test]
+    fn pace_line_drawn_in_empty_cells() {
+        let chart = render_cumulative(&[0.0; 8], 80.0, 10.0, 80, false);
+        let rows = plot_rows(&chart);
+        assert!(rows.iter().any(|r| r.contains(PACE_MARK)), "{chart}");
+        assert!(chart.contains("target pace (10.0 hrs/wk)"));
+    }
+
+    #[test]
+    fn respects_width() {
+        let values: Vec<f64> = (1..=200).map(|w| w as f64 * 15.0).collect();
+        let chart = render_cumulative(&values, 3000.0, 15.0, 40, false);
+        for line in chart.lines().take(CHART_HEIGHT + 1) {
+            assert!(line.chars().count() <= 40, "{line:?}");
+        }
+        // The last column keeps the latest value.
+        assert!(plot_rows(&chart).iter().all(|r| r.ends_with('█')));
+    }
+
+    #[test]
+    fn never_narrower_than_minimum() {
+        let values = [1.0; 30];
+        let chart = render_cumulative(&values, 10.0, 0.0, 5, false);
+        assert_eq!(plot_rows(&chart)[0].chars().count(), MIN_PLOT_WIDTH);
+    }
+
+    #[test]
+    fn empty_and_zero_inputs() {
+        assert_eq!(
+            render_cumulative(&[], 3000.0, 15.0, 80, false),
+            "No weeks to chart yet."
+        );
+        let chart = render_cumulative(&[0.0, 0.0], 0.0, 0.0, 80, false);
+        assert!(plot_rows(&chart).iter().all(|r| r.trim().is_empty()));
+    }
+}
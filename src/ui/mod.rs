@@ -1,6 +1,8 @@
+pub mod chart;
 pub mod prompts;
+pub mod spinner;
 
 pub use prompts::{
-    flash_confirmation, input_date, input_hours, input_text, select_category,
-    select_category_with_values, select_week, PromptResult,
+    confirm, flash_confirmation, input_date, input_hours, input_text, select_category,
+    select_edit_field, select_week, EditField, PromptResult, TEXT_INPUT_MAX_LEN,
 };
@@ -3,7 +3,7 @@ use std::thread;
 use std::time::Duration;
 
 use anyhow::{bail, Result};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Weekday};
 use crossterm::{
     cursor,
     event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
@@ -14,6 +14,7 @@ use crossterm::{
 
 use crate::data::model::{Category, HoursData, WeekEntry};
 use crate::data::week;
+use crate::display;
 
 pub enum PromptResult<T> {
     Value(T),
@@ -36,53 +37,113 @@ impl Drop for RawModeGuard {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SelectAction {
     Up,
     Down,
     Top,
     Bottom,
+    Current,
     Confirm,
     Back,
     Exit,
     Help,
+    Redraw,
+    // Confirm this item right away; see `select_from_list`'s `shortcuts`.
+    Jump(usize),
 }
 
-fn read_select_key() -> Result<SelectAction> {
-    loop {
-        if let Event::Key(KeyEvent {
+// Single-key shortcuts in the category selectors. The list's built-in keys
+// (j, k, g, G, c, q, ?) are left alone.
+fn category_shortcut(category: Category) -> char {
+    match category {
+        Category::IndividualSupervision => 's',
+        Category::GroupSupervision => 'r',
+        Category::Direct => 'd',
+        Category::Indirect => 'i',
+    }
+}
+
+const NOTE_SHORTCUT: char = 'n';
+
+fn category_shortcuts() -> Vec<(char, usize)> {
+    Category::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, category)| (category_shortcut(*category), idx))
+        .collect()
+}
+
+fn select_action(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    shortcuts: &[(char, usize)],
+) -> Option<SelectAction> {
+    if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('c') {
+        return Some(SelectAction::Exit);
+    }
+    if let KeyCode::Char(key) = code {
+        if let Some(&(_, idx)) = shortcuts.iter().find(|(shortcut, _)| *shortcut == key) {
+            return Some(SelectAction::Jump(idx));
+        }
+    }
+    match code {
+        KeyCode::Char('j') | KeyCode::Down => Some(SelectAction::Down),
+        KeyCode::Char('k') | KeyCode::Up => Some(SelectAction::Up),
+        KeyCode::Char('g') => Some(SelectAction::Top),
+        KeyCode::Char('G') => Some(SelectAction::Bottom),
+        KeyCode::Char('c') => Some(SelectAction::Current),
+        KeyCode::Enter => Some(SelectAction::Confirm),
+        KeyCode::Esc | KeyCode::Char('q') => Some(SelectAction::Back),
+        KeyCode::Char('?') => Some(SelectAction::Help),
+        _ => None,
+    }
+}
+
+fn select_event(event: Event, shortcuts: &[(char, usize)]) -> Option<SelectAction> {
+    match event {
+        Event::Key(KeyEvent {
             code, modifiers, ..
-        }) = event::read()?
-        {
-            if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('c') {
-                return Ok(SelectAction::Exit);
-            }
-            match code {
-                KeyCode::Char('j') | KeyCode::Down => return Ok(SelectAction::Down),
-                KeyCode::Char('k') | KeyCode::Up => return Ok(SelectAction::Up),
-                KeyCode::Char('g') => return Ok(SelectAction::Top),
-                KeyCode::Char('G') => return Ok(SelectAction::Bottom),
-                KeyCode::Enter => return Ok(SelectAction::Confirm),
-                KeyCode::Esc | KeyCode::Char('q') => return Ok(SelectAction::Back),
-                KeyCode::Char('?') => return Ok(SelectAction::Help),
-                _ => {}
-            }
+        }) => select_action(code, modifiers, shortcuts),
+        Event::Resize(..) => Some(SelectAction::Redraw),
+        _ => None,
+    }
+}
+
+// Keys that map to nothing, including letters without a shortcut, are skipped.
+fn read_select_key(shortcuts: &[(char, usize)]) -> Result<SelectAction> {
+    loop {
+        if let Some(action) = select_event(event::read()?, shortcuts) {
+            return Ok(action);
         }
     }
 }
 
+// The slice of items that fits below the header in a terminal `rows` tall,
+// scrolled just far enough to keep `selected` visible. Always at least one row,
+// so a zero-height terminal still shows the selection.
+fn visible_range(len: usize, selected: usize, rows: u16) -> std::ops::Range<usize> {
+    let capacity = (rows as usize).saturating_sub(1).max(1);
+    let start = selected.saturating_sub(capacity - 1);
+    start..len.min(start + capacity)
+}
+
 fn render_list(
     stdout: &mut io::Stdout,
     header: &str,
     items: &[String],
     selected: usize,
 ) -> Result<()> {
+    let rows = terminal::size().map(|(_, r)| r).unwrap_or(u16::MAX);
+
     stdout.queue(cursor::MoveTo(0, 0))?;
     stdout.queue(terminal::Clear(ClearType::All))?;
 
     stdout.queue(style::PrintStyledContent(header.bold()))?;
     stdout.queue(cursor::MoveToNextLine(1))?;
 
-    for (i, item) in items.iter().enumerate() {
+    let range = visible_range(items.len(), selected, rows);
+    for (i, item) in items.iter().enumerate().take(range.end).skip(range.start) {
         if i == selected {
             stdout.queue(style::PrintStyledContent("  > ".green()))?;
             stdout.queue(style::PrintStyledContent(item.as_str().green()))?;
@@ -96,7 +157,7 @@ fn render_list(
     Ok(())
 }
 
-fn render_help_overlay(stdout: &mut io::Stdout) -> Result<()> {
+fn draw_help_overlay(stdout: &mut io::Stdout) -> Result<()> {
     stdout.queue(cursor::MoveTo(0, 0))?;
     stdout.queue(terminal::Clear(ClearType::All))?;
 
@@ -112,6 +173,13 @@ fn render_help_overlay(stdout: &mut io::Stdout) -> Result<()> {
         ("Esc / q", "Go back"),
         ("g", "Jump to first item"),
         ("G", "Jump to last item"),
+        ("c", "Pick the current week (week selector)"),
+        (
+            "s / r",
+            "Individual / Group Supervision (category selector)",
+        ),
+        ("d / i", "Direct / Indirect (category selector)"),
+        ("n", "Note (edit category selector)"),
         ("?", "Show this help"),
         ("Ctrl+C", "Exit immediately"),
     ];
@@ -126,10 +194,17 @@ fn render_help_overlay(stdout: &mut io::Stdout) -> Result<()> {
         "Press any key to dismiss...".dark_grey(),
     ))?;
     stdout.flush()?;
+    Ok(())
+}
+
+fn render_help_overlay(stdout: &mut io::Stdout) -> Result<()> {
+    draw_help_overlay(stdout)?;
 
     loop {
-        if let Event::Key(_) = event::read()? {
-            break;
+        match event::read()? {
+            Event::Key(_) => break,
+            Event::Resize(..) => draw_help_overlay(stdout)?,
+            _ => {}
         }
     }
 
@@ -150,7 +225,16 @@ pub fn flash_confirmation(message: &str) -> Result<()> {
     Ok(())
 }
 
-fn select_from_list(header: &str, items: &[String], initial: usize) -> Result<PromptResult<usize>> {
+// `current` is the item the `c` shortcut confirms directly; lists without a
+// "current" item pass `None` and the key is ignored. `shortcuts` maps extra
+// keys to items they confirm directly.
+fn select_from_list(
+    header: &str,
+    items: &[String],
+    initial: usize,
+    current: Option<usize>,
+    shortcuts: &[(char, usize)],
+) -> Result<PromptResult<usize>> {
     if items.is_empty() {
         bail!("No items to select from");
     }
@@ -163,7 +247,7 @@ fn select_from_list(header: &str, items: &[String], initial: usize) -> Result<Pr
     render_list(&mut stdout, header, items, selected)?;
 
     let result = loop {
-        match read_select_key()? {
+        match read_select_key(shortcuts)? {
             SelectAction::Down => {
                 if selected < items.len() - 1 {
                     selected += 1;
@@ -184,13 +268,21 @@ fn select_from_list(header: &str, items: &[String], initial: usize) -> Result<Pr
                 selected = items.len() - 1;
                 render_list(&mut stdout, header, items, selected)?;
             }
+            SelectAction::Current => {
+                if let Some(index) = current {
+                    break PromptResult::Value(index);
+                }
+            }
             SelectAction::Confirm => break PromptResult::Value(selected),
+            SelectAction::Jump(index) if index < items.len() => break PromptResult::Value(index),
+            SelectAction::Jump(_) => {}
             SelectAction::Back => break PromptResult::Back,
             SelectAction::Exit => break PromptResult::Exit,
             SelectAction::Help => {
                 render_help_overlay(&mut stdout)?;
                 render_list(&mut stdout, header, items, selected)?;
             }
+            SelectAction::Redraw => render_list(&mut stdout, header, items, selected)?,
         }
     };
 
@@ -234,10 +326,15 @@ pub fn select_week(
     let current_index = weeks
         .iter()
         .rev()
-        .position(|(start, _)| *start == current_week_start)
-        .unwrap_or(0);
-
-    match select_from_list("Select week:", &items, current_index)? {
+        .position(|(start, _)| *start == current_week_start);
+
+    match select_from_list(
+        "Select week:",
+        &items,
+        current_index.unwrap_or(0),
+        current_index,
+        &[],
+    )? {
         PromptResult::Value(idx) => {
             let reversed_idx = weeks.len() - 1 - idx;
             Ok(PromptResult::Value(weeks[reversed_idx].0))
@@ -253,21 +350,43 @@ pub fn select_category() -> Result<PromptResult<Category>> {
         .map(|c| c.long_name().to_string())
         .collect();
 
-    match select_from_list("Select category:", &items, 0)? {
+    match select_from_list("Select category:", &items, 0, None, &category_shortcuts())? {
         PromptResult::Value(idx) => Ok(PromptResult::Value(Category::ALL[idx])),
         PromptResult::Back => Ok(PromptResult::Back),
         PromptResult::Exit => Ok(PromptResult::Exit),
     }
 }
 
-pub fn select_category_with_values(entry: &WeekEntry) -> Result<PromptResult<Category>> {
+// What the edit menu can change: one of the hour categories, or the note
+// listed after them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditField {
+    Category(Category),
+    Note,
+}
+
+const NOTE_PREVIEW_LEN: usize = 40;
+
+fn note_preview(note: Option<&str>) -> String {
+    let first_line = note.and_then(|n| n.lines().next()).unwrap_or("");
+    if first_line.is_empty() {
+        return "(none)".to_string();
+    }
+    let mut preview: String = first_line.chars().take(NOTE_PREVIEW_LEN).collect();
+    if first_line.chars().count() > NOTE_PREVIEW_LEN || note.is_some_and(|n| n.contains('\n')) {
+        preview.push('…');
+    }
+    preview
+}
+
+pub fn select_edit_field(entry: &WeekEntry) -> Result<PromptResult<EditField>> {
     let max_name_len = Category::ALL
         .iter()
         .map(|c| c.long_name().len())
         .max()
         .unwrap_or(0);
 
-    let items: Vec<String> = Category::ALL
+    let mut items: Vec<String> = Category::ALL
         .iter()
         .map(|c| {
             let name = c.long_name();
@@ -275,14 +394,29 @@ pub fn select_category_with_values(entry: &WeekEntry) -> Result<PromptResult<Cat
             format!("{:<width$}    {val:.1} hrs", name, width = max_name_len)
         })
         .collect();
-
-    match select_from_list("Select category:", &items, 0)? {
-        PromptResult::Value(idx) => Ok(PromptResult::Value(Category::ALL[idx])),
+    items.push(format!(
+        "{:<width$}    {}",
+        "Note",
+        note_preview(entry.note.as_deref()),
+        width = max_name_len
+    ));
+
+    let mut shortcuts = category_shortcuts();
+    shortcuts.push((NOTE_SHORTCUT, Category::ALL.len()));
+
+    match select_from_list("Select category:", &items, 0, None, &shortcuts)? {
+        PromptResult::Value(idx) => Ok(PromptResult::Value(match Category::ALL.get(idx) {
+            Some(category) => EditField::Category(*category),
+            None => EditField::Note,
+        })),
         PromptResult::Back => Ok(PromptResult::Back),
         PromptResult::Exit => Ok(PromptResult::Exit),
     }
 }
 
+// Backspace keeps its empty-input check inside the arm, as in
+// `input_text` and `input_date`.
+#[allow(clippy::collapsible_match)]
 pub fn input_hours(prompt: &str, current_value: Option<f64>) -> Result<PromptResult<f64>> {
     let _guard = RawModeGuard::enable()?;
     let mut stdout = io::stdout();
@@ -306,9 +440,14 @@ pub fn input_hours(prompt: &str, current_value: Option<f64>) -> Result<PromptRes
     let mut input = String::new();
 
     loop {
+        let event = event::read()?;
+        if let Event::Resize(..) = event {
+            render_prompt(&mut stdout, &input)?;
+            continue;
+        }
         if let Event::Key(KeyEvent {
             code, modifiers, ..
-        }) = event::read()?
+        }) = event
         {
             if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('c') {
                 stdout.execute(cursor::MoveToNextLine(1))?;
@@ -331,7 +470,7 @@ pub fn input_hours(prompt: &str, current_value: Option<f64>) -> Result<PromptRes
                             }
                         };
                     }
-                    match input.parse::<f64>() {
+                    match display::parse_hours(&input) {
                         Ok(val) if val >= 0.0 => return Ok(PromptResult::Value(val)),
                         Ok(_) => {
                             stdout.queue(style::PrintStyledContent(
@@ -341,9 +480,9 @@ pub fn input_hours(prompt: &str, current_value: Option<f64>) -> Result<PromptRes
                             input.clear();
                             render_prompt(&mut stdout, "")?;
                         }
-                        Err(_) => {
+                        Err(e) => {
                             stdout.queue(style::PrintStyledContent(
-                                "Invalid number. Try again.".red(),
+                                format!("{e}. Try again.").red(),
                             ))?;
                             stdout.queue(cursor::MoveToNextLine(1))?;
                             input.clear();
@@ -355,7 +494,7 @@ pub fn input_hours(prompt: &str, current_value: Option<f64>) -> Result<PromptRes
                     render_help_overlay(&mut stdout)?;
                     render_prompt(&mut stdout, &input)?;
                 }
-                KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => {
+                KeyCode::Char(c) if c.is_ascii_digit() || c == '.' || c == ',' => {
                     input.push(c);
                     stdout.queue(style::Print(c))?;
                     stdout.flush()?;
@@ -379,7 +518,33 @@ pub fn input_hours(prompt: &str, current_value: Option<f64>) -> Result<PromptRes
     }
 }
 
-pub fn input_text(prompt: &str, default: Option<&str>) -> Result<Option<String>> {
+pub const TEXT_INPUT_MAX_LEN: usize = 1024;
+
+// Pasted text can carry control characters (escape sequences, tabs, newlines)
+// that corrupt the prompt line, so only printable characters are kept.
+fn accept_text_char(input: &str, c: char, max_len: usize) -> bool {
+    !c.is_control() && input.chars().count() < max_len
+}
+
+// The tail of `input` that fits on one terminal row after the prompt.
+fn visible_tail(prompt: &str, input: &str, columns: usize) -> String {
+    let available = columns.saturating_sub(prompt.chars().count() + 1);
+    let len = input.chars().count();
+    input.chars().skip(len.saturating_sub(available)).collect()
+}
+
+fn render_text_line(stdout: &mut io::Stdout, prompt: &str, input: &str) -> Result<()> {
+    let columns = terminal::size().map(|(c, _)| c as usize).unwrap_or(80);
+    stdout.queue(cursor::MoveToColumn(0))?;
+    stdout.queue(terminal::Clear(ClearType::CurrentLine))?;
+    stdout.queue(style::Print(prompt))?;
+    stdout.queue(style::Print(visible_tail(prompt, input, columns)))?;
+    stdout.flush()?;
+    Ok(())
+}
+
+#[allow(clippy::collapsible_match)]
+pub fn input_text(prompt: &str, default: Option<&str>, max_len: usize) -> Result<Option<String>> {
     let _guard = RawModeGuard::enable()?;
     let mut stdout = io::stdout();
 
@@ -390,15 +555,20 @@ pub fn input_text(prompt: &str, default: Option<&str>) -> Result<Option<String>>
 
     stdout.queue(cursor::MoveTo(0, 0))?;
     stdout.queue(terminal::Clear(ClearType::All))?;
-    stdout.queue(style::Print(&display_prompt))?;
-    stdout.flush()?;
+    render_text_line(&mut stdout, &display_prompt, "")?;
 
     let mut input = String::new();
 
     loop {
+        let event = event::read()?;
+        if let Event::Resize(..) = event {
+            // Recomputes the visible tail for the new width.
+            render_text_line(&mut stdout, &display_prompt, &input)?;
+            continue;
+        }
         if let Event::Key(KeyEvent {
             code, modifiers, ..
-        }) = event::read()?
+        }) = event
         {
             if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('c') {
                 stdout.execute(cursor::MoveToNextLine(1))?;
@@ -412,18 +582,14 @@ pub fn input_text(prompt: &str, default: Option<&str>) -> Result<Option<String>>
                     }
                     return Ok(Some(input));
                 }
-                KeyCode::Char(c) => {
+                KeyCode::Char(c) if accept_text_char(&input, c, max_len) => {
                     input.push(c);
-                    stdout.queue(style::Print(c))?;
-                    stdout.flush()?;
+                    render_text_line(&mut stdout, &display_prompt, &input)?;
                 }
                 KeyCode::Backspace => {
                     if !input.is_empty() {
                         input.pop();
-                        stdout.queue(cursor::MoveLeft(1))?;
-                        stdout.queue(style::Print(' '))?;
-                        stdout.queue(cursor::MoveLeft(1))?;
-                        stdout.flush()?;
+                        render_text_line(&mut stdout, &display_prompt, &input)?;
                     }
                 }
                 KeyCode::Esc => {
@@ -436,7 +602,38 @@ pub fn input_text(prompt: &str, default: Option<&str>) -> Result<Option<String>>
     }
 }
 
-pub fn input_date(prompt: &str, must_be_tuesday: bool) -> Result<Option<NaiveDate>> {
+// `week_start` is the weekday the date must fall on, if any.
+fn date_hint(input: &str, week_start: Option<Weekday>) -> Option<String> {
+    if input.len() != 10 {
+        return None;
+    }
+    let date = NaiveDate::parse_from_str(input, "%Y-%m-%d").ok()?;
+    let weekday = date.format("%A");
+    match week_start {
+        Some(day) if !week::is_week_start(date, day) => {
+            Some(format!("({weekday} — not a {})", week::weekday_name(day)))
+        }
+        _ => Some(format!("({weekday})")),
+    }
+}
+
+fn render_date_hint(
+    stdout: &mut io::Stdout,
+    input: &str,
+    week_start: Option<Weekday>,
+) -> Result<()> {
+    stdout.queue(cursor::SavePosition)?;
+    stdout.queue(terminal::Clear(ClearType::UntilNewLine))?;
+    if let Some(hint) = date_hint(input, week_start) {
+        stdout.queue(style::PrintStyledContent(format!(" {hint}").dark_grey()))?;
+    }
+    stdout.queue(cursor::RestorePosition)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+#[allow(clippy::collapsible_match)]
+pub fn input_date(prompt: &str, week_start: Option<Weekday>) -> Result<Option<NaiveDate>> {
     let _guard = RawModeGuard::enable()?;
     let mut stdout = io::stdout();
 
@@ -450,9 +647,18 @@ pub fn input_date(prompt: &str, must_be_tuesday: bool) -> Result<Option<NaiveDat
     let mut input = String::new();
 
     loop {
+        let event = event::read()?;
+        if let Event::Resize(..) = event {
+            stdout.queue(cursor::MoveToColumn(0))?;
+            stdout.queue(terminal::Clear(ClearType::CurrentLine))?;
+            stdout.queue(style::Print(&display_prompt))?;
+            stdout.queue(style::Print(&input))?;
+            render_date_hint(&mut stdout, &input, week_start)?;
+            continue;
+        }
         if let Event::Key(KeyEvent {
             code, modifiers, ..
-        }) = event::read()?
+        }) = event
         {
             if modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('c') {
                 stdout.execute(cursor::MoveToNextLine(1))?;
@@ -460,6 +666,7 @@ pub fn input_date(prompt: &str, must_be_tuesday: bool) -> Result<Option<NaiveDat
             }
             match code {
                 KeyCode::Enter => {
+                    stdout.queue(terminal::Clear(ClearType::UntilNewLine))?;
                     stdout.execute(cursor::MoveToNextLine(1))?;
                     if input.is_empty() {
                         stdout.queue(style::PrintStyledContent("Date is required.".red()))?;
@@ -470,10 +677,14 @@ pub fn input_date(prompt: &str, must_be_tuesday: bool) -> Result<Option<NaiveDat
                     }
                     match NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
                         Ok(date) => {
-                            if must_be_tuesday && !week::is_tuesday(date) {
-                                stdout.queue(style::PrintStyledContent(
-                                    "Date must be a Tuesday. Try again.".red(),
-                                ))?;
+                            if let Some(day) =
+                                week_start.filter(|day| !week::is_week_start(date, *day))
+                            {
+                                let message = format!(
+                                    "Date must be a {}. Try again.",
+                                    week::weekday_name(day)
+                                );
+                                stdout.queue(style::PrintStyledContent(message.red()))?;
                                 stdout.queue(cursor::MoveToNextLine(1))?;
                                 input.clear();
                                 stdout.queue(style::Print(&display_prompt))?;
@@ -496,15 +707,13 @@ pub fn input_date(prompt: &str, must_be_tuesday: bool) -> Result<Option<NaiveDat
                 KeyCode::Char(c) if c.is_ascii_digit() || c == '-' => {
                     input.push(c);
                     stdout.queue(style::Print(c))?;
-                    stdout.flush()?;
+                    render_date_hint(&mut stdout, &input, week_start)?;
                 }
                 KeyCode::Backspace => {
                     if !input.is_empty() {
                         input.pop();
                         stdout.queue(cursor::MoveLeft(1))?;
-                        stdout.queue(style::Print(' '))?;
-                        stdout.queue(cursor::MoveLeft(1))?;
-                        stdout.flush()?;
+                        render_date_hint(&mut stdout, &input, week_start)?;
                     }
                 }
                 KeyCode::Esc => {
@@ -517,7 +726,6 @@ pub fn input_date(prompt: &str, must_be_tuesday: bool) -> Result<Option<NaiveDat
     }
 }
 
-#[allow(dead_code)]
 pub fn confirm(prompt: &str) -> Result<bool> {
     let _guard = RawModeGuard::enable()?;
     let mut stdout = io::stdout();
@@ -558,18 +766,177 @@ mod tests {
         NaiveDate::from_ymd_opt(y, m, d).unwrap()
     }
 
+    #[test]
+    fn note_preview_shows_first_line() {
+        assert_eq!(note_preview(None), "(none)");
+        assert_eq!(note_preview(Some("")), "(none)");
+        assert_eq!(note_preview(Some("Heavy caseload")), "Heavy caseload");
+        assert_eq!(note_preview(Some("Intake\nConsult")), "Intake…");
+        let long = "x".repeat(NOTE_PREVIEW_LEN + 5);
+        assert_eq!(
+            note_preview(Some(&long)).chars().count(),
+            NOTE_PREVIEW_LEN + 1
+        );
+    }
+
+    #[test]
+    fn test_select_action_current_week_key() {
+        assert_eq!(
+            select_action(KeyCode::Char('c'), KeyModifiers::NONE, &[]),
+            Some(SelectAction::Current)
+        );
+        assert_eq!(
+            select_action(KeyCode::Char('c'), KeyModifiers::CONTROL, &[]),
+            Some(SelectAction::Exit)
+        );
+        assert_eq!(
+            select_action(KeyCode::Enter, KeyModifiers::NONE, &[]),
+            Some(SelectAction::Confirm)
+        );
+        assert_eq!(
+            select_action(KeyCode::Char('x'), KeyModifiers::NONE, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_select_event_resize_redraws() {
+        assert_eq!(
+            select_event(Event::Resize(40, 10), &[]),
+            Some(SelectAction::Redraw)
+        );
+        assert_eq!(
+            select_event(Event::Resize(0, 0), &[]),
+            Some(SelectAction::Redraw)
+        );
+        assert_eq!(
+            select_event(
+                Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+                &[]
+            ),
+            Some(SelectAction::Confirm)
+        );
+        assert_eq!(select_event(Event::FocusGained, &[]), None);
+    }
+
+    #[test]
+    fn test_category_shortcuts_jump_to_category() {
+        let shortcuts = category_shortcuts();
+        for (idx, category) in Category::ALL.iter().enumerate() {
+            assert_eq!(
+                select_action(
+                    KeyCode::Char(category_shortcut(*category)),
+                    KeyModifiers::NONE,
+                    &shortcuts
+                ),
+                Some(SelectAction::Jump(idx))
+            );
+        }
+        assert_eq!(
+            select_action(KeyCode::Char('d'), KeyModifiers::NONE, &shortcuts),
+            Some(SelectAction::Jump(2))
+        );
+        // Unmapped letters are ignored, and built-in keys keep their meaning.
+        assert_eq!(
+            select_action(KeyCode::Char('x'), KeyModifiers::NONE, &shortcuts),
+            None
+        );
+        assert_eq!(
+            select_action(KeyCode::Char('j'), KeyModifiers::NONE, &shortcuts),
+            Some(SelectAction::Down)
+        );
+        assert_eq!(
+            select_action(KeyCode::Down, KeyModifiers::NONE, &shortcuts),
+            Some(SelectAction::Down)
+        );
+        // The week selector has no shortcuts, so `d` does nothing there.
+        assert_eq!(
+            select_action(KeyCode::Char('d'), KeyModifiers::NONE, &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_category_shortcuts_avoid_builtin_keys() {
+        for (key, _) in category_shortcuts().into_iter().chain([(NOTE_SHORTCUT, 0)]) {
+            assert_eq!(
+                select_action(KeyCode::Char(key), KeyModifiers::NONE, &[]),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_visible_range_fits_everything_in_tall_terminal() {
+        assert_eq!(visible_range(5, 0, 24), 0..5);
+        assert_eq!(visible_range(5, 4, u16::MAX), 0..5);
+    }
+
+    #[test]
+    fn test_visible_range_scrolls_to_keep_selection() {
+        // Header takes one of the 4 rows, leaving 3 for items.
+        assert_eq!(visible_range(10, 0, 4), 0..3);
+        assert_eq!(visible_range(10, 2, 4), 0..3);
+        assert_eq!(visible_range(10, 5, 4), 3..6);
+        assert_eq!(visible_range(10, 9, 4), 7..10);
+    }
+
+    #[test]
+    fn test_visible_range_zero_size_terminal() {
+        for rows in [0, 1] {
+            let range = visible_range(10, 7, rows);
+            assert_eq!(range, 7..8);
+        }
+        assert_eq!(visible_range(1, 0, 0), 0..1);
+    }
+
+    #[test]
+    fn test_visible_tail_zero_width() {
+        assert_eq!(visible_tail("Note: ", "hello", 0), "");
+    }
+
+    #[test]
+    fn test_accept_text_char_filters_control_chars() {
+        assert!(accept_text_char("", 'a', 10));
+        assert!(accept_text_char("~/Sync", '/', 10));
+        assert!(accept_text_char("", 'é', 10));
+        assert!(!accept_text_char("", '\u{1b}', 10));
+        assert!(!accept_text_char("", '\t', 10));
+        assert!(!accept_text_char("", '\n', 10));
+    }
+
+    #[test]
+    fn test_accept_text_char_enforces_max_len() {
+        assert!(accept_text_char("abcd", 'e', 5));
+        assert!(!accept_text_char("abcde", 'f', 5));
+        assert!(!accept_text_char("ééééé", 'f', 5));
+    }
+
+    #[test]
+    fn test_visible_tail_fits_terminal_width() {
+        assert_eq!(visible_tail("Dir: ", "~/Sync", 80), "~/Sync");
+        assert_eq!(visible_tail("Dir: ", "abcdefghij", 10), "ghij");
+        assert_eq!(visible_tail("A very long prompt: ", "abc", 10), "");
+    }
+
     #[test]
     fn test_format_week_label_current_with_hours() {
-        let data = HoursData {
-            weeks: vec![crate::data::model::WeekEntry {
-                start: date(2025, 1, 28),
-                end: date(2025, 2, 3),
-                individual_supervision: 1.0,
-                group_supervision: 2.0,
-                direct: 14.5,
-                indirect: 6.0,
-            }],
-        };
+        let data = HoursData::from_weeks(vec![crate::data::model::WeekEntry {
+            start: date(2025, 1, 28),
+            end: date(2025, 2, 3),
+            individual_supervision: 1.0,
+            group_supervision: 2.0,
+            direct: 14.5,
+            indirect: 6.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
 
         let label = format_week_label(date(2025, 1, 28), date(2025, 2, 3), true, &data);
         assert!(label.contains("Jan 28"));
@@ -590,22 +957,56 @@ mod tests {
 
     #[test]
     fn test_format_week_label_not_current_with_hours() {
-        let data = HoursData {
-            weeks: vec![crate::data::model::WeekEntry {
-                start: date(2025, 2, 4),
-                end: date(2025, 2, 10),
-                individual_supervision: 0.0,
-                group_supervision: 0.0,
-                direct: 5.0,
-                indirect: 3.0,
-            }],
-        };
+        let data = HoursData::from_weeks(vec![crate::data::model::WeekEntry {
+            start: date(2025, 2, 4),
+            end: date(2025, 2, 10),
+            individual_supervision: 0.0,
+            group_supervision: 0.0,
+            direct: 5.0,
+            indirect: 3.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
+        }]);
 
         let label = format_week_label(date(2025, 2, 4), date(2025, 2, 10), false, &data);
         assert!(!label.contains("(current)"));
         assert!(label.contains("8.0 hrs"));
     }
 
+    #[test]
+    fn test_date_hint_incomplete_input() {
+        assert_eq!(date_hint("", Some(Weekday::Tue)), None);
+        assert_eq!(date_hint("2025-01-2", Some(Weekday::Tue)), None);
+        assert_eq!(date_hint("2025-13-01", Some(Weekday::Tue)), None);
+    }
+
+    #[test]
+    fn test_date_hint_tuesday() {
+        assert_eq!(
+            date_hint("2025-01-28", Some(Weekday::Tue)).unwrap(),
+            "(Tuesday)"
+        );
+    }
+
+    #[test]
+    fn test_date_hint_not_tuesday() {
+        assert_eq!(
+            date_hint("2025-01-29", Some(Weekday::Tue)).unwrap(),
+            "(Wednesday — not a Tuesday)"
+        );
+        assert_eq!(date_hint("2025-01-29", None).unwrap(), "(Wednesday)");
+        assert_eq!(
+            date_hint("2025-01-28", Some(Weekday::Mon)).unwrap(),
+            "(Tuesday — not a Monday)"
+        );
+    }
+
     #[test]
     fn test_raw_mode_guard_cleanup() {
         {
@@ -635,6 +1036,14 @@ mod tests {
             group_supervision: 2.0,
             direct: 14.5,
             indirect: 6.0,
+            entries: 0,
+            added_hours: None,
+            created_at: None,
+            updated_at: None,
+            note: None,
+            target: None,
+            excluded: false,
+            days: Vec::new(),
         };
 
         let max_name_len = Category::ALL
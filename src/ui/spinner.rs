@@ -0,0 +1,90 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crossterm::{
+    cursor,
+    style::Print,
+    terminal::{Clear, ClearType},
+    QueueableCommand,
+};
+
+const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const FRAME_INTERVAL: Duration = Duration::from_millis(80);
+
+// Animates on stderr from a background thread until dropped. Dropping stops
+// and joins the thread and clears the line, so early returns clean up too.
+pub struct Spinner {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Spinner {
+    // Draws nothing when stderr is not a terminal (pipes, CI, tests).
+    pub fn start(message: &str) -> Self {
+        Self::start_if(io::stderr().is_terminal(), message)
+    }
+
+    fn start_if(enabled: bool, message: &str) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = enabled.then(|| {
+            let stop = Arc::clone(&stop);
+            let message = message.to_string();
+            thread::spawn(move || animate(&stop, &message))
+        });
+        Spinner { stop, handle }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn animate(stop: &AtomicBool, message: &str) {
+    let mut stderr = io::stderr();
+    for frame in FRAMES.iter().cycle() {
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+        let _ = draw(&mut stderr, &format!("{frame} {message}"));
+        thread::sleep(FRAME_INTERVAL);
+    }
+    let _ = draw(&mut stderr, "");
+}
+
+fn draw(stderr: &mut io::Stderr, line: &str) -> io::Result<()> {
+    stderr
+        .queue(cursor::MoveToColumn(0))?
+        .queue(Clear(ClearType::CurrentLine))?
+        .queue(Print(line))?;
+    stderr.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn disabled_spinner_spawns_no_thread() {
+        let spinner = Spinner::start_if(false, "Pushing");
+        assert!(spinner.handle.is_none());
+    }
+
+    #[test]
+    fn drop_stops_and_joins_thread() {
+        let spinner = Spinner::start_if(true, "Pushing");
+        assert!(spinner.handle.is_some());
+        thread::sleep(FRAME_INTERVAL * 2);
+        let started = Instant::now();
+        drop(spinner);
+        assert!(started.elapsed() < FRAME_INTERVAL * 5);
+    }
+}
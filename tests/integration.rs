@@ -11,9 +11,18 @@ fn hours_cmd() -> Command {
 }
 
 fn init_env(config_dir: &TempDir, data_dir: &TempDir) {
+    init_env_with_week_start(config_dir, data_dir, None, "2025-01-28");
+}
+
+fn init_env_with_week_start(
+    config_dir: &TempDir,
+    data_dir: &TempDir,
+    week_start_day: Option<&str>,
+    start_date: &str,
+) {
     let data_path = data_dir.path().to_str().unwrap();
-    hours_cmd()
-        .env("HOURS_CONFIG_DIR", config_dir.path())
+    let mut cmd = hours_cmd();
+    cmd.env("HOURS_CONFIG_DIR", config_dir.path())
         .env("HOURS_NO_GIT", "1")
         .args([
             "--no-git",
@@ -23,13 +32,26 @@ fn init_env(config_dir: &TempDir, data_dir: &TempDir) {
             "--remote",
             "git@github.com:test/test.git",
             "--start-date",
-            "2025-01-28",
+            start_date,
             "--non-interactive",
-        ])
-        .assert()
-        .success();
+        ]);
+    if let Some(day) = week_start_day {
+        cmd.args(["--week-start-day", day]);
+    }
+    cmd.assert().success();
 }
 
+// (week_start_day flag, a start date on that day, its weekday, a date that is not)
+const WEEK_START_CASES: [(Option<&str>, &str, chrono::Weekday, &str); 2] = [
+    (None, "2025-01-28", chrono::Weekday::Tue, "2025-01-29"),
+    (
+        Some("Monday"),
+        "2025-01-27",
+        chrono::Weekday::Mon,
+        "2025-01-28",
+    ),
+];
+
 fn add_hours(config_dir: &TempDir, data_dir: &TempDir, category: &str, hours: &str) {
     hours_cmd()
         .env("HOURS_CONFIG_DIR", config_dir.path())
@@ -135,38 +157,40 @@ fn initialize_fresh_setup() {
 
 #[test]
 fn add_hours_to_current_week() {
-    let config_dir = TempDir::new().unwrap();
-    let data_dir = TempDir::new().unwrap();
-    init_env(&config_dir, &data_dir);
+    for (week_start_day, start, weekday, _) in WEEK_START_CASES {
+        let config_dir = TempDir::new().unwrap();
+        let data_dir = TempDir::new().unwrap();
+        init_env_with_week_start(&config_dir, &data_dir, week_start_day, start);
 
-    add_hours(&config_dir, &data_dir, "direct", "3.5");
+        add_hours(&config_dir, &data_dir, "direct", "3.5");
 
-    let data = load_data(&data_dir);
-    let weeks = data["weeks"].as_array().unwrap();
-    assert_eq!(weeks.len(), 1);
+        let data = load_data(&data_dir);
+        let weeks = data["weeks"].as_array().unwrap();
+        assert_eq!(weeks.len(), 1);
 
-    let week = &weeks[0];
-    assert_eq!(week["direct"].as_f64().unwrap(), 3.5);
-    assert_eq!(week["individual_supervision"].as_f64().unwrap(), 0.0);
-    assert_eq!(week["group_supervision"].as_f64().unwrap(), 0.0);
-    assert_eq!(week["indirect"].as_f64().unwrap(), 0.0);
+        let week = &weeks[0];
+        assert_eq!(week["direct"].as_f64().unwrap(), 3.5);
+        assert_eq!(week["individual_supervision"].as_f64().unwrap(), 0.0);
+        assert_eq!(week["group_supervision"].as_f64().unwrap(), 0.0);
+        assert_eq!(week["indirect"].as_f64().unwrap(), 0.0);
 
-    let start = week["start"].as_str().unwrap();
-    let start_date = chrono::NaiveDate::parse_from_str(start, "%Y-%m-%d").unwrap();
-    assert_eq!(
-        start_date.weekday(),
-        chrono::Weekday::Tue,
-        "Week start must be a Tuesday"
-    );
+        let start = week["start"].as_str().unwrap();
+        let start_date = chrono::NaiveDate::parse_from_str(start, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            start_date.weekday(),
+            weekday,
+            "Week start must be a {weekday}"
+        );
 
-    let end = week["end"].as_str().unwrap();
-    let end_date = chrono::NaiveDate::parse_from_str(end, "%Y-%m-%d").unwrap();
-    assert_eq!(
-        end_date.weekday(),
-        chrono::Weekday::Mon,
-        "Week end must be a Monday"
-    );
-    assert_eq!((end_date - start_date).num_days(), 6);
+        let end = week["end"].as_str().unwrap();
+        let end_date = chrono::NaiveDate::parse_from_str(end, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            end_date.weekday(),
+            weekday.pred(),
+            "Week end must be the day before {weekday}"
+        );
+        assert_eq!((end_date - start_date).num_days(), 6);
+    }
 }
 
 #[test]
@@ -257,6 +281,78 @@ fn edit_overwrites_values() {
     assert_eq!(weeks[0]["direct"].as_f64().unwrap(), 10.0);
 }
 
+#[test]
+fn edit_if_empty_leaves_filled_category_unchanged() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3.5");
+
+    let edit = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(["edit", "--week", "2025-01-28", "--non-interactive"])
+            .args(args)
+            .assert()
+    };
+
+    edit(&["--direct", "10.0", "--indirect", "2.0", "--if-empty"])
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "Week of 2025-01-28 already has direct 3.5 hrs; left unchanged (--if-empty)",
+        ));
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["direct"], 3.5);
+    assert_eq!(data["weeks"][0]["indirect"], 0.0);
+
+    // An empty category is still filled in.
+    edit(&["--indirect", "2.0", "--if-empty"]).success();
+    assert_eq!(load_data(&data_dir)["weeks"][0]["indirect"], 2.0);
+}
+
+#[test]
+fn edit_force_overrides_if_empty() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3.5");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "edit",
+            "--week",
+            "2025-01-28",
+            "--direct",
+            "10.0",
+            "--non-interactive",
+            "--if-empty",
+            "--force",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Edited hours for week of 2025-01-28",
+        ));
+    assert_eq!(load_data(&data_dir)["weeks"][0]["direct"], 10.0);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["edit", "--direct", "1.0", "--if-empty"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--if-empty requires --non-interactive",
+        ));
+}
+
 #[test]
 fn edit_preserves_unspecified_categories() {
     let config_dir = TempDir::new().unwrap();
@@ -298,6 +394,41 @@ fn edit_preserves_unspecified_categories() {
     );
 }
 
+#[test]
+fn edit_note_append_keeps_previous_lines() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3.5");
+
+    for text in ["Intake with new client", "  Case consult  ", "   "] {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args([
+                "--no-git",
+                "edit",
+                "--week",
+                "2025-01-28",
+                "--note-append",
+                text,
+                "--non-interactive",
+            ])
+            .assert()
+            .success();
+    }
+
+    let data = load_data(&data_dir);
+    let week = &data["weeks"][0];
+    assert_eq!(
+        week["note"].as_str().unwrap(),
+        "Intake with new client\nCase consult"
+    );
+    assert_eq!(week["direct"].as_f64().unwrap(), 3.5);
+}
+
 #[test]
 fn list_output_table() {
     let config_dir = TempDir::new().unwrap();
@@ -339,7 +470,8 @@ fn list_output_json() {
         .clone();
 
     let json: Value = serde_json::from_slice(&output).unwrap();
-    let arr = json.as_array().unwrap();
+    assert_eq!(json["schema_version"], 1);
+    let arr = json["weeks"].as_array().unwrap();
     assert_eq!(arr.len(), 1);
     assert_eq!(arr[0]["start"].as_str().unwrap(), "2025-01-28");
     assert_eq!(arr[0]["direct"].as_f64().unwrap(), 5.0);
@@ -368,7 +500,7 @@ fn list_with_last_n() {
         .clone();
 
     let json: Value = serde_json::from_slice(&output).unwrap();
-    let arr = json.as_array().unwrap();
+    let arr = json["weeks"].as_array().unwrap();
     assert_eq!(arr.len(), 2);
     assert_eq!(arr[0]["start"].as_str().unwrap(), "2025-02-04");
     assert_eq!(arr[1]["start"].as_str().unwrap(), "2025-02-11");
@@ -455,6 +587,74 @@ fn summary_weekly_average_counts_direct_only() {
     );
 }
 
+#[test]
+fn summary_json_includes_current_week() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "9.0");
+    add_hours(&config_dir, &data_dir, "direct", "3.5");
+    add_hours(&config_dir, &data_dir, "indirect", "1.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let data = load_data(&data_dir);
+    let logged_current = data["weeks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|w| w["direct"].as_f64() == Some(3.5))
+        .unwrap();
+
+    let current = &json["current_week"];
+    assert_eq!(current["start"], logged_current["start"]);
+    assert_eq!(current["end"], logged_current["end"]);
+    assert_eq!(current["direct"].as_f64().unwrap(), 3.5);
+    assert_eq!(current["indirect"].as_f64().unwrap(), 1.0);
+    assert_eq!(current["group_supervision"].as_f64().unwrap(), 0.0);
+    assert_eq!(current["total"].as_f64().unwrap(), 4.5);
+}
+
+#[test]
+fn summary_warns_when_data_is_stale() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "9.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Warning: the latest logged week (Jan 28, 2025) is",
+        ));
+
+    add_hours(&config_dir, &data_dir, "direct", "3.5");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("summary")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Warning").not());
+}
+
 #[test]
 fn summary_empty_state() {
     let config_dir = TempDir::new().unwrap();
@@ -479,6 +679,114 @@ fn summary_empty_state() {
     assert_eq!(json["total_hours"]["percentage"].as_f64().unwrap(), 0.0);
     assert_eq!(json["direct_hours"]["percentage"].as_f64().unwrap(), 0.0);
     assert_eq!(json["weeks_logged"].as_u64().unwrap(), 0);
+    // Nothing logged since the 2025 start date puts the pace delta below zero.
+    assert!(json["pace_delta"].as_f64().unwrap() < 0.0);
+}
+
+#[test]
+fn data_dir_flag_reads_alternate_data() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    let other_config = TempDir::new().unwrap();
+    let other_data = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    init_env(&other_config, &other_data);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&other_config, &other_data, "2025-02-04", "indirect", "2.5");
+
+    // The flag wins over both the config file and HOURS_DATA_DIR.
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--json", "--data-dir"])
+        .arg(other_data.path())
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    let weeks = &json["weeks"];
+    assert_eq!(weeks.as_array().unwrap().len(), 1);
+    assert_eq!(weeks[0]["start"], "2025-02-04");
+    assert_eq!(weeks[0]["indirect"].as_f64().unwrap(), 2.5);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env_remove("HOURS_DATA_DIR")
+        .env("HOURS_NO_GIT", "1")
+        .arg("--data-dir")
+        .arg(other_data.path())
+        .args(["summary", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"current\": 2.5"));
+}
+
+#[test]
+fn summary_graph_renders_chart() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "500.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--graph", "--no-color"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Licensure Progress"))
+        .stdout(predicate::str::contains("3,000 ┤"))
+        .stdout(predicate::str::contains("target pace (28.8 hrs/wk)"))
+        .stdout(predicate::str::contains("\u{1b}[").not());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--graph", "--json"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn gaps_lists_empty_weeks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-11", "direct", "5.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["gaps", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let gaps: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let gaps = gaps.as_array().unwrap();
+    assert_eq!(gaps[0]["start"], "2025-02-04");
+    assert_eq!(gaps[0]["end"], "2025-02-10");
+    assert_eq!(gaps[1]["start"], "2025-02-18");
+    assert!(gaps.iter().all(|g| g["start"] != "2025-02-11"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("gaps")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("week(s) with no hours logged:"))
+        .stdout(predicate::str::contains("Feb 04 – Feb 10, 2025"));
 }
 
 #[test]
@@ -513,6 +821,110 @@ fn export_generates_pdf() {
     );
 }
 
+#[test]
+fn export_commit_tracks_ignored_report() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    let dir = data_dir.path();
+    git_in(dir, &["init", "-q"]);
+    git_in(dir, &["config", "user.email", "test@test.com"]);
+    git_in(dir, &["config", "user.name", "Test"]);
+    fs::write(dir.join(".gitignore"), "*.tmp\nexports/\n").unwrap();
+    git_in(dir, &["add", "hours.json", ".gitignore"]);
+    git_in(dir, &["commit", "-q", "-m", "baseline"]);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env_remove("HOURS_NO_GIT")
+        .args(["export", "--commit"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Committed"));
+
+    let tracked = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["ls-files", "exports"])
+        .output()
+        .unwrap();
+    let tracked = String::from_utf8_lossy(&tracked.stdout);
+    assert!(
+        tracked.trim().starts_with("exports/hours-report-") && tracked.trim().ends_with(".pdf"),
+        "report should be tracked, got {tracked:?}"
+    );
+
+    let log = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["log", "-1", "--format=%s"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&log.stdout).starts_with("Add report "));
+}
+
+#[test]
+fn export_fail_on_empty() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["export", "--fail-on-empty"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Nothing to export"));
+    assert!(!data_dir.path().join("exports").exists());
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["export", "--fail-on-empty"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Report saved to"));
+}
+
+#[test]
+fn export_preview_no_write() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["export", "--preview", "--no-write"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Jan 28 – Feb 03, 2025"))
+        .stdout(predicate::str::contains("TOTALS"))
+        .stdout(predicate::str::contains("Licensure Progress"))
+        .stdout(predicate::str::contains("Report saved to").not());
+    assert!(!data_dir.path().join("exports").exists());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["export", "--no-write"])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn export_custom_output_path() {
     let config_dir = TempDir::new().unwrap();
@@ -598,30 +1010,35 @@ fn validation_rejects_negative_hours() {
 }
 
 #[test]
-fn validation_rejects_non_tuesday_week_start() {
-    let config_dir = TempDir::new().unwrap();
-    let data_dir = TempDir::new().unwrap();
-    init_env(&config_dir, &data_dir);
+fn validation_rejects_off_day_week_start() {
+    for (week_start_day, start, _, off_day) in WEEK_START_CASES {
+        let config_dir = TempDir::new().unwrap();
+        let data_dir = TempDir::new().unwrap();
+        init_env_with_week_start(&config_dir, &data_dir, week_start_day, start);
 
-    // 2025-01-29 is a Wednesday
-    hours_cmd()
-        .env("HOURS_CONFIG_DIR", config_dir.path())
-        .env("HOURS_DATA_DIR", data_dir.path())
-        .env("HOURS_NO_GIT", "1")
-        .args([
-            "--no-git",
-            "add",
-            "--week",
-            "2025-01-29",
-            "--category",
-            "direct",
-            "--hours",
-            "1.0",
-            "--non-interactive",
-        ])
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("Tuesday"));
+        let expected = format!("must be a {}", week_start_day.unwrap_or("Tuesday"));
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args([
+                "--no-git",
+                "add",
+                "--week",
+                off_day,
+                "--category",
+                "direct",
+                "--hours",
+                "1.0",
+                "--non-interactive",
+            ])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(expected));
+
+        // The configured day itself is accepted.
+        add_hours_to_week(&config_dir, &data_dir, start, "direct", "1.0");
+    }
 }
 
 #[test]
@@ -706,3 +1123,3266 @@ fn data_file_integrity_after_multiple_operations() {
     assert_eq!(weeks[1]["indirect"].as_f64().unwrap(), 2.0);
     assert_eq!(weeks[1]["direct"].as_f64().unwrap(), 1.0);
 }
+
+#[test]
+fn export_embed_data_appends_source() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    let output_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    let export = |name: &str, extra: &[&str]| {
+        let path = output_dir.path().join(name);
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(["export", "--output", path.to_str().unwrap()])
+            .args(extra)
+            .assert()
+            .success();
+        fs::read(path).unwrap()
+    };
+
+    let plain = export("plain.pdf", &[]);
+    let embedded = export("embedded.pdf", &["--embed-data"]);
+    assert!(embedded.starts_with(b"%PDF"));
+    assert!(embedded.len() > plain.len());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["export", "--format", "ics", "--embed-data"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--embed-data only applies to PDF export",
+        ));
+}
+
+#[test]
+fn export_generates_ics() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "indirect", "2.5");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["--no-git", "export", "--format", "ics"])
+        .assert()
+        .success();
+
+    let exports_dir = data_dir.path().join("exports");
+    let ics_files: Vec<_> = fs::read_dir(&exports_dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "ics"))
+        .collect();
+    assert_eq!(ics_files.len(), 1, "Expected exactly one ICS file");
+
+    let contents = fs::read_to_string(ics_files[0].path()).unwrap();
+    assert_eq!(contents.matches("BEGIN:VEVENT").count(), 2);
+    assert!(contents.contains("DTSTART;VALUE=DATE:20250128"));
+    assert!(contents.contains("DTEND;VALUE=DATE:20250204"));
+    assert!(contents.contains("DTSTART;VALUE=DATE:20250204"));
+    assert!(contents.contains("DTEND;VALUE=DATE:20250211"));
+    assert!(contents.contains("SUMMARY:Supervision: 5.0 hrs"));
+}
+
+#[test]
+fn quiet_skips_confirmation_lines() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let run = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("--quiet")
+            .args(args)
+            .assert()
+    };
+
+    run(&[
+        "add",
+        "--week",
+        "2025-01-28",
+        "--category",
+        "direct",
+        "--hours",
+        "3.5",
+        "--non-interactive",
+    ])
+    .success()
+    .stdout("");
+    run(&[
+        "edit",
+        "--week",
+        "2025-01-28",
+        "--indirect",
+        "1",
+        "--non-interactive",
+    ])
+    .success()
+    .stdout("");
+    run(&["export", "--format", "csv"]).success().stdout("");
+    assert_eq!(load_data(&data_dir)["weeks"][0]["direct"], 3.5);
+
+    // Requested output and errors are unaffected.
+    run(&["list", "--json"])
+        .success()
+        .stdout(predicate::str::contains("\"direct\": 3.5"));
+    run(&["add", "--category", "direct", "--non-interactive"])
+        .failure()
+        .stderr(predicate::str::contains("--hours is required"));
+}
+
+#[test]
+fn export_generates_csv() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "indirect", "2.5");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "is", "1.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["--no-git", "export", "--format", "csv"])
+        .assert()
+        .success();
+
+    let exports_dir = data_dir.path().join("exports");
+    let csv_files: Vec<_> = fs::read_dir(&exports_dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "csv"))
+        .collect();
+    assert_eq!(csv_files.len(), 1, "Expected exactly one CSV file");
+
+    let contents = fs::read_to_string(csv_files[0].path()).unwrap();
+    let mut lines = contents.lines();
+    assert_eq!(
+        lines.next(),
+        Some("start,end,individual_supervision,group_supervision,direct,indirect,total")
+    );
+    let rows: Vec<Vec<&str>> = lines.map(|l| l.split(',').collect()).collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0][0], "2025-01-28");
+    assert_eq!(rows[0][1], "2025-02-03");
+    assert_eq!(rows[1][0], "2025-02-04");
+    let totals: Vec<f64> = rows.iter().map(|r| r[6].parse().unwrap()).collect();
+    assert_eq!(totals, vec![6.0, 2.5]);
+}
+
+#[test]
+fn export_rejects_unknown_format() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["--no-git", "export", "--format", "docx"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid export format"));
+}
+
+#[test]
+fn export_exec_pipes_list_json() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "indirect", "2.5");
+
+    let run = |args: &[&str]| {
+        let output = hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let listed: serde_json::Value = serde_json::from_str(&run(&["list", "--json"])).unwrap();
+    let piped: serde_json::Value =
+        serde_json::from_str(&run(&["export", "--format", "exec:cat"])).unwrap();
+    // The command gets the bare weeks array, without the schema_version wrapper.
+    assert_eq!(piped, listed["weeks"]);
+
+    let out = data_dir.path().join("weeks.json");
+    let stdout = run(&[
+        "export",
+        "--format",
+        "exec:cat",
+        "--output",
+        out.to_str().unwrap(),
+    ]);
+    assert!(stdout.contains("Report saved to"), "{stdout}");
+    let written: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&out).unwrap()).unwrap();
+    assert_eq!(written, listed["weeks"]);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["export", "--format", "exec:exit 2", "--output"])
+        .arg(&out)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Export command `exit 2` failed with exit code 2",
+        ));
+}
+
+#[test]
+fn verify_passes_on_valid_data() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["verify", "--strict"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Verified 1 weeks"));
+}
+
+#[test]
+fn verify_strict_flags_zero_and_out_of_period_weeks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let data_json = r#"{"weeks":[{"start":"2025-01-21","end":"2025-01-27","individual_supervision":0.0,"group_supervision":0.0,"direct":4.0,"indirect":0.0},{"start":"2025-01-28","end":"2025-02-03","individual_supervision":0.0,"group_supervision":0.0,"direct":0.0,"indirect":0.0}]}"#;
+    fs::write(data_dir.path().join("hours.json"), data_json).unwrap();
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["verify"])
+        .assert()
+        .success();
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["verify", "--strict"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "warning: Week starting 2025-01-28 has zero hours logged",
+        ))
+        .stderr(predicate::str::contains(
+            "error: Week starting 2025-01-21 is outside the tracking period",
+        ));
+}
+
+#[test]
+fn add_increments_entry_counter() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "1.0");
+
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["entries"].as_u64().unwrap(), 2);
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["entries"]["count"].as_u64().unwrap(), 2);
+    assert_eq!(json["entries"]["average_hours"].as_f64().unwrap(), 2.0);
+}
+
+#[test]
+fn export_with_header_and_footer_text() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    let text_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    let header = text_dir.path().join("header.txt");
+    let footer = text_dir.path().join("footer.txt");
+    fs::write(&header, "Riverside Counseling Program\n").unwrap();
+    fs::write(&footer, "Trainee attestation: hours are accurate.\n").unwrap();
+
+    let output = text_dir.path().join("report.pdf");
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "--no-git",
+            "export",
+            "--output",
+            output.to_str().unwrap(),
+            "--header-text",
+            header.to_str().unwrap(),
+            "--footer-text",
+            footer.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+    assert!(output.exists());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "--no-git",
+            "export",
+            "--output",
+            output.to_str().unwrap(),
+            "--header-text",
+            text_dir.path().join("missing.txt").to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing.txt"));
+
+    for (flag, format) in [("--header-text", "csv"), ("--footer-text", "ics")] {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(["--no-git", "export", "--format", format, flag])
+            .arg(&header)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(format!(
+                "{flag} only applies to PDF export"
+            )));
+    }
+}
+
+#[test]
+fn summary_csv_snapshot() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "30.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "indirect", "30.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--csv"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let text = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        lines[0],
+        "date,total_hours,direct_hours,total_pct,direct_pct,weekly_average"
+    );
+
+    let fields: Vec<&str> = lines[1].split(',').collect();
+    assert_eq!(fields.len(), 6);
+    let today = chrono::Local::now().date_naive();
+    assert_eq!(fields[0], today.format("%Y-%m-%d").to_string());
+    assert_eq!(fields[1], "60.0");
+    assert_eq!(fields[2], "30.0");
+    assert_eq!(fields[3], "2.0");
+    assert_eq!(fields[4], "2.5");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--csv", "--no-header"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let text = String::from_utf8(output).unwrap();
+    assert_eq!(text.lines().count(), 1);
+    assert!(!text.contains("total_hours"));
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--format", "csv", "--no-header"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let text = String::from_utf8(output).unwrap();
+    assert_eq!(text.lines().count(), 1);
+    assert!(!text.contains("total_hours"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--format", "json", "--no-header"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--no-header only applies to CSV output",
+        ));
+}
+
+#[test]
+fn list_filters_by_date_range() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    for (week, hours) in [
+        ("2025-01-28", "1.0"),
+        ("2025-02-04", "2.0"),
+        ("2025-02-11", "3.0"),
+        ("2025-02-18", "4.0"),
+    ] {
+        add_hours_to_week(&config_dir, &data_dir, week, "direct", hours);
+    }
+
+    let list_json = |args: &[&str]| -> Vec<String> {
+        let output = hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("list")
+            .args(args)
+            .arg("--json")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["weeks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["start"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        list_json(&["--from", "2025-02-04", "--to", "2025-02-17"]),
+        ["2025-02-04", "2025-02-11"]
+    );
+    // A week that only partly overlaps a bound is left out.
+    assert_eq!(
+        list_json(&["--from", "2025-02-05"]),
+        ["2025-02-11", "2025-02-18"]
+    );
+    assert_eq!(
+        list_json(&["--to", "2025-02-10"]),
+        ["2025-01-28", "2025-02-04"]
+    );
+    // The range applies before --last.
+    assert_eq!(
+        list_json(&["--to", "2025-02-17", "--last", "1"]),
+        ["2025-02-11"]
+    );
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--from", "2026-01-01"])
+        .assert()
+        .success()
+        .stdout("No hours in that range\n");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--to", "02/17/2025"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --to date '02/17/2025'"));
+}
+
+#[test]
+fn list_category_shows_one_column() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "10.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "indirect", "3.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "2.5");
+
+    let list = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("list")
+            .args(args)
+            .assert()
+    };
+
+    let output = list(&["--category", "direct", "--json"])
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(
+        json["weeks"],
+        serde_json::json!([
+            {"start": "2025-01-28", "end": "2025-02-03", "hours": 10.0},
+            {"start": "2025-02-04", "end": "2025-02-10", "hours": 2.5},
+        ])
+    );
+
+    let output = list(&["--category", "d"])
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains("Direct"), "{text}");
+    assert!(!text.contains("Indirect"), "{text}");
+    assert!(text.contains("12.5"), "{text}");
+
+    list(&["--category", "client"])
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid category 'client'. Valid categories:",
+        ));
+}
+
+#[test]
+fn list_json_stream_handles_many_weeks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let first = chrono::NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+    let weeks: Vec<Value> = (0..520)
+        .map(|i| {
+            let start = first + chrono::Duration::weeks(i);
+            serde_json::json!({
+                "start": start.to_string(),
+                "end": (start + chrono::Duration::days(6)).to_string(),
+                "individual_supervision": 1.0,
+                "group_supervision": 0.5,
+                "direct": (i % 20) as f64,
+                "indirect": 2.0,
+            })
+        })
+        .collect();
+    fs::write(
+        data_dir.path().join("hours.json"),
+        serde_json::to_string(&serde_json::json!({ "weeks": weeks })).unwrap(),
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let output = hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        output.stdout
+    };
+
+    let streamed = run(&["list", "--json", "--stream", "--running-total"]);
+    // Compact: a single line.
+    assert_eq!(streamed.iter().filter(|&&b| b == b'\n').count(), 1);
+    assert!(streamed.ends_with(b"]}\n"));
+    let streamed: Value = serde_json::from_slice(&streamed).unwrap();
+    assert_eq!(streamed["schema_version"], 1);
+    let arr = streamed["weeks"].as_array().unwrap();
+    assert_eq!(arr.len(), 520);
+    assert_eq!(arr[519]["start"], "2035-01-09");
+
+    let pretty: Value =
+        serde_json::from_slice(&run(&["list", "--json", "--running-total"])).unwrap();
+    assert_eq!(streamed, pretty);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--stream"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn list_last_zero_is_rejected() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "1.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--last", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--last must be >= 1"));
+}
+
+#[test]
+fn add_and_edit_record_timestamps() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3.0");
+
+    let data = load_data(&data_dir);
+    let created = data["weeks"][0]["created_at"].as_str().unwrap().to_string();
+    let updated = data["weeks"][0]["updated_at"].as_str().unwrap().to_string();
+    assert_eq!(created, updated);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "--no-git",
+            "edit",
+            "--week",
+            "2025-01-28",
+            "--direct",
+            "4.0",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    let week = &json["weeks"][0];
+    let created_at =
+        chrono::DateTime::parse_from_rfc3339(week["created_at"].as_str().unwrap()).unwrap();
+    let updated_at =
+        chrono::DateTime::parse_from_rfc3339(week["updated_at"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        created_at,
+        chrono::DateTime::parse_from_rfc3339(&created).unwrap()
+    );
+    assert!(updated_at >= created_at);
+}
+
+#[test]
+fn list_no_totals_omits_totals_row() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--no-totals"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Jan 28"))
+        .stdout(predicate::str::contains("TOTALS").not());
+}
+
+#[test]
+fn list_running_total_ends_at_grand_total() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "3.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "indirect", "1.5");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--json", "--running-total"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    let weeks = json["weeks"].as_array().unwrap();
+    assert_eq!(weeks[0]["cumulative_total"].as_f64().unwrap(), 6.5);
+    let grand_total: f64 = weeks.iter().map(|w| w["total"].as_f64().unwrap()).sum();
+    assert_eq!(
+        weeks.last().unwrap()["cumulative_total"].as_f64().unwrap(),
+        grand_total
+    );
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--running-total"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let table = String::from_utf8(output).unwrap();
+    assert!(table.contains("Running Total"));
+    let cells = |label: &str| -> Vec<String> {
+        let line = table.lines().find(|l| l.contains(label)).unwrap();
+        line.split(['│', '┆'])
+            .map(|c| c.trim().to_string())
+            .collect()
+    };
+    // Cells: "", week, 4 categories, total, running total, "".
+    let last_week = cells("Feb 04");
+    let totals = cells("TOTALS");
+    assert_eq!(last_week[7], "9.5", "{table}");
+    assert_eq!(last_week[7], totals[6], "{table}");
+}
+
+#[test]
+fn list_porcelain_emits_tab_separated_lines() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "indirect", "1.5");
+    add_hours_to_week(
+        &config_dir,
+        &data_dir,
+        "2025-02-04",
+        "group_supervision",
+        "2.0",
+    );
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--porcelain"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "one line per week, no header or totals");
+    for line in &lines {
+        assert_eq!(line.matches('\t').count(), 6, "{line:?}");
+    }
+    assert_eq!(lines[0], "2025-01-28\t2025-02-03\t0.0\t0.0\t5.0\t1.5\t6.5");
+    assert_eq!(lines[1], "2025-02-04\t2025-02-10\t0.0\t2.0\t0.0\t0.0\t2.0");
+}
+
+#[test]
+fn add_prints_milestone_when_crossing_increment() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "240");
+
+    let add = |week: &str, hours: &str| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args([
+                "--no-git",
+                "add",
+                "--week",
+                week,
+                "--category",
+                "direct",
+                "--hours",
+                hours,
+                "--non-interactive",
+            ])
+            .assert()
+            .success()
+    };
+
+    add("2025-02-04", "5").stdout(predicate::str::contains("Milestone").not());
+    add("2025-02-11", "10").stdout(predicate::str::contains("Milestone: 250 hours!"));
+}
+
+#[test]
+fn list_week_shows_single_week() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "indirect", "2.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--week", "2025-02-04", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let weeks = json["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0]["start"], "2025-02-04");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--week", "2025-02-04"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Feb 04"))
+        .stdout(predicate::str::contains("Jan 28").not());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--week", "2025-02-11"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No such week"));
+}
+
+fn git_in(dir: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn auto_pull_fast_forwards_before_saving() {
+    if std::process::Command::new("git")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        return;
+    }
+
+    let remote = TempDir::new().unwrap();
+    git_in(remote.path(), &["init", "-q", "--bare", "-b", "main"]);
+    let remote_url = remote.path().to_str().unwrap();
+
+    // The laptop seeds the remote and later pushes a week through hours.
+    let laptop_config = TempDir::new().unwrap();
+    let laptop = TempDir::new().unwrap();
+    init_env(&laptop_config, &laptop);
+    let dir = laptop.path();
+    git_in(dir, &["init", "-q", "-b", "main"]);
+    git_in(dir, &["config", "user.email", "test@test.com"]);
+    git_in(dir, &["config", "user.name", "Test"]);
+    git_in(dir, &["add", "hours.json"]);
+    git_in(dir, &["commit", "-q", "-m", "seed"]);
+    git_in(dir, &["remote", "add", "origin", remote_url]);
+    git_in(dir, &["push", "-q", "-u", "origin", "main"]);
+
+    let desktop_config = TempDir::new().unwrap();
+    let desktop_parent = TempDir::new().unwrap();
+    init_env(&desktop_config, &desktop_parent);
+    let desktop = desktop_parent.path().join("clone");
+    git_in(
+        desktop_parent.path(),
+        &["clone", "-q", remote_url, desktop.to_str().unwrap()],
+    );
+    git_in(&desktop, &["config", "user.email", "test@test.com"]);
+    git_in(&desktop, &["config", "user.name", "Test"]);
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", desktop_config.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "set", "git.auto_pull", "true"])
+        .assert()
+        .success();
+
+    let add = |config_dir: &TempDir, data_dir: &std::path::Path, week: &str| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir)
+            .env_remove("HOURS_NO_GIT")
+            .args([
+                "add",
+                "--week",
+                week,
+                "--category",
+                "direct",
+                "--hours",
+                "2",
+                "--non-interactive",
+            ])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("Warning").not());
+    };
+    // The remote commit changes hours.json itself.
+    add(&laptop_config, laptop.path(), "2025-01-28");
+    add(&desktop_config, &desktop, "2025-02-04");
+
+    let data: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(desktop.join("hours.json")).unwrap()).unwrap();
+    let starts: Vec<_> = data["weeks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| w["start"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(starts, ["2025-01-28", "2025-02-04"]);
+
+    let log = std::process::Command::new("git")
+        .arg("-C")
+        .arg(remote.path())
+        .args(["log", "--format=%s", "main"])
+        .output()
+        .unwrap();
+    let log = String::from_utf8_lossy(&log.stdout);
+    assert_eq!(log.lines().count(), 3, "{log}");
+    assert!(!log.contains("Merge"), "{log}");
+}
+
+#[test]
+fn init_from_remote_adopts_existing_data() {
+    if std::process::Command::new("git")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        return;
+    }
+
+    let seed_config = TempDir::new().unwrap();
+    let remote = TempDir::new().unwrap();
+    init_env(&seed_config, &remote);
+    add_hours_to_week(&seed_config, &remote, "2025-01-28", "direct", "5.0");
+    git_in(remote.path(), &["init", "-q"]);
+    git_in(remote.path(), &["config", "user.email", "test@test.com"]);
+    git_in(remote.path(), &["config", "user.name", "Test"]);
+    git_in(remote.path(), &["add", "hours.json"]);
+    git_in(remote.path(), &["commit", "-q", "-m", "seed"]);
+
+    let config_dir = TempDir::new().unwrap();
+    let parent = TempDir::new().unwrap();
+    let data_dir = parent.path().join("data");
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env_remove("HOURS_NO_GIT")
+        .args([
+            "init",
+            "--from-remote",
+            "--data-dir",
+            data_dir.to_str().unwrap(),
+            "--remote",
+            remote.path().to_str().unwrap(),
+            "--start-date",
+            "2025-01-28",
+            "--non-interactive",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Adopted"));
+
+    assert!(config_dir.path().join("config.toml").exists());
+    let data: Value =
+        serde_json::from_str(&fs::read_to_string(data_dir.join("hours.json")).unwrap()).unwrap();
+    assert_eq!(data["weeks"][0]["direct"].as_f64().unwrap(), 5.0);
+
+    // A non-empty data directory is never clobbered.
+    let other_config = TempDir::new().unwrap();
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", other_config.path())
+        .env_remove("HOURS_NO_GIT")
+        .args([
+            "init",
+            "--from-remote",
+            "--data-dir",
+            data_dir.to_str().unwrap(),
+            "--remote",
+            remote.path().to_str().unwrap(),
+            "--start-date",
+            "2025-01-28",
+            "--non-interactive",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not empty"));
+    assert!(!other_config.path().join("config.toml").exists());
+}
+
+#[test]
+fn changes_since_reports_weeks_changed_after_revision() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "3.0");
+
+    let dir = data_dir.path();
+    git_in(dir, &["init", "-q"]);
+    git_in(dir, &["config", "user.email", "test@test.com"]);
+    git_in(dir, &["config", "user.name", "Test"]);
+    git_in(dir, &["add", "hours.json"]);
+    git_in(dir, &["commit", "-q", "-m", "baseline"]);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "1.5");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-11", "indirect", "2.0");
+    git_in(dir, &["commit", "-q", "-am", "more hours"]);
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env_remove("HOURS_NO_GIT")
+        .args(["changes", "--since", "HEAD~1", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let changes: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let changes = changes.as_array().unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0]["change"], "modified");
+    assert_eq!(changes[0]["start"], "2025-02-04");
+    assert_eq!(changes[0]["before"]["direct"], 3.0);
+    assert_eq!(changes[0]["after"]["direct"], 4.5);
+    assert_eq!(changes[1]["change"], "added");
+    assert_eq!(changes[1]["start"], "2025-02-11");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env_remove("HOURS_NO_GIT")
+        .args(["changes", "--since", "HEAD"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes since HEAD."));
+}
+
+#[test]
+fn changes_requires_git() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["changes", "--since", "HEAD~1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("git is disabled"));
+}
+
+#[test]
+fn week_arg_weekday_name_gets_guidance() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    for args in [
+        vec![
+            "add",
+            "--week",
+            "mon",
+            "--category",
+            "direct",
+            "--hours",
+            "1",
+            "--non-interactive",
+        ],
+        vec![
+            "edit",
+            "--week",
+            "mon",
+            "--direct",
+            "1",
+            "--non-interactive",
+        ],
+        vec!["list", "--week", "mon"],
+    ] {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(&args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("weekday names are not accepted"))
+            .stderr(predicate::str::contains(
+                "YYYY-MM-DD, e.g. --week 2025-01-28",
+            ));
+    }
+}
+
+#[test]
+fn summary_respects_total_target_env_override() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "50.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .env("HOURS_TOTAL_TARGET", "100")
+        .args(["summary", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["total_hours"]["target"].as_u64().unwrap(), 100);
+    let total_pct = json["total_hours"]["percentage"].as_f64().unwrap();
+    assert!(
+        (total_pct - 50.0).abs() < 0.1,
+        "total percentage should be 50.0, got {total_pct}"
+    );
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .env("HOURS_TOTAL_TARGET", "lots")
+        .args(["summary"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid HOURS_TOTAL_TARGET 'lots'",
+        ));
+}
+
+fn write_other_hours(dir: &TempDir) -> std::path::PathBuf {
+    let path = dir.path().join("other.json");
+    let json = serde_json::json!({
+        "weeks": [
+            {
+                "start": "2025-01-28",
+                "end": "2025-02-03",
+                "individual_supervision": 0.0,
+                "group_supervision": 0.0,
+                "direct": 2.0,
+                "indirect": 0.0
+            },
+            {
+                "start": "2025-02-11",
+                "end": "2025-02-17",
+                "individual_supervision": 1.0,
+                "group_supervision": 0.0,
+                "direct": 0.0,
+                "indirect": 0.0
+            }
+        ]
+    });
+    fs::write(&path, serde_json::to_string_pretty(&json).unwrap()).unwrap();
+    path
+}
+
+#[test]
+fn merge_with_each_strategy() {
+    for (strategy, expected_direct) in [("sum", 7.0), ("prefer-theirs", 2.0), ("prefer-mine", 5.0)]
+    {
+        let config_dir = TempDir::new().unwrap();
+        let data_dir = TempDir::new().unwrap();
+        let other_dir = TempDir::new().unwrap();
+        init_env(&config_dir, &data_dir);
+        add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+        let other = write_other_hours(&other_dir);
+
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args([
+                "merge",
+                other.to_str().unwrap(),
+                "--strategy",
+                strategy,
+                "--yes",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("conflict"))
+            .stdout(predicate::str::contains("added"))
+            .stdout(predicate::str::contains(
+                "Merged 1 new week(s) and 1 conflict(s).",
+            ));
+
+        let data = load_data(&data_dir);
+        let weeks = data["weeks"].as_array().unwrap();
+        assert_eq!(weeks.len(), 2, "{strategy}");
+        assert_eq!(
+            weeks[0]["direct"].as_f64().unwrap(),
+            expected_direct,
+            "{strategy}"
+        );
+        assert_eq!(weeks[1]["start"], "2025-02-11");
+        assert_eq!(weeks[1]["individual_supervision"].as_f64().unwrap(), 1.0);
+    }
+}
+
+#[test]
+fn merge_rejects_unknown_strategy() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    let other_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    let other = write_other_hours(&other_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["merge", other.to_str().unwrap(), "--strategy", "newest"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid merge strategy 'newest'"));
+}
+
+#[test]
+fn list_and_summary_totals_agree() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    for (week, direct) in [
+        ("2025-01-28", "0.05"),
+        ("2025-02-04", "0.05"),
+        ("2025-02-11", "0.15"),
+    ] {
+        add_hours_to_week(&config_dir, &data_dir, week, "direct", direct);
+    }
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-18", "indirect", "0.25");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .output()
+        .unwrap();
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let summary_total = format!("{:.1}", json["total_hours"]["current"].as_f64().unwrap());
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list"])
+        .output()
+        .unwrap();
+    let table = String::from_utf8(output.stdout).unwrap();
+    let totals_row = table.lines().find(|l| l.contains("TOTALS")).unwrap();
+    let list_total = totals_row
+        .split(['│', '┆'])
+        .map(str::trim)
+        .rfind(|cell| !cell.is_empty())
+        .unwrap();
+
+    assert_eq!(summary_total, "0.5");
+    assert_eq!(list_total, summary_total);
+
+    // A single 0.25 cell must round the same way as the summary does.
+    let feb_18 = table.lines().find(|l| l.contains("Feb 18")).unwrap();
+    assert!(feb_18.contains("0.3"), "{feb_18}");
+}
+
+fn add_over(
+    config_dir: &TempDir,
+    data_dir: &TempDir,
+    date: &str,
+    over: &str,
+    hours: &str,
+) -> String {
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "--no-git",
+            "add",
+            "--date",
+            date,
+            "--over",
+            over,
+            "--category",
+            "direct",
+            "--hours",
+            hours,
+            "--non-interactive",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn add_over_within_one_week() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let stdout = add_over(&config_dir, &data_dir, "2025-01-29", "3", "9");
+    assert!(stdout.contains("week of 2025-01-28: 9.00 hrs"), "{stdout}");
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0]["start"], "2025-01-28");
+    assert_eq!(weeks[0]["direct"].as_f64().unwrap(), 9.0);
+}
+
+#[test]
+fn add_over_splits_across_week_boundary() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    // Sun Feb 2 and Mon Feb 3 close out the Jan 28 week; Tue Feb 4 opens the next.
+    let stdout = add_over(&config_dir, &data_dir, "2025-02-02", "3", "9");
+    assert!(stdout.contains("week of 2025-01-28: 6.00 hrs"), "{stdout}");
+    assert!(stdout.contains("week of 2025-02-04: 3.00 hrs"), "{stdout}");
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 2);
+    assert_eq!(weeks[0]["direct"].as_f64().unwrap(), 6.0);
+    assert_eq!(weeks[1]["start"], "2025-02-04");
+    assert_eq!(weeks[1]["direct"].as_f64().unwrap(), 3.0);
+}
+
+#[test]
+fn add_date_records_daily_entries() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_over(&config_dir, &data_dir, "2025-01-30", "1", "2");
+    add_over(&config_dir, &data_dir, "2025-01-30", "1", "1.5");
+    add_over(&config_dir, &data_dir, "2025-01-29", "1", "3");
+
+    let data = load_data(&data_dir);
+    let week = &data["weeks"][0];
+    assert_eq!(week["direct"].as_f64().unwrap(), 6.5);
+    let days = week["days"].as_array().unwrap();
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0]["date"], "2025-01-29");
+    assert_eq!(days[0]["direct"].as_f64().unwrap(), 3.0);
+    assert_eq!(days[1]["date"], "2025-01-30");
+    assert_eq!(days[1]["direct"].as_f64().unwrap(), 3.5);
+
+    // Spreading over several days stays a week-level total.
+    add_over(&config_dir, &data_dir, "2025-01-31", "2", "4");
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["direct"].as_f64().unwrap(), 10.5);
+    assert_eq!(data["weeks"][0]["days"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn edit_below_daily_entries_drops_them() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_over(&config_dir, &data_dir, "2025-01-30", "1", "3");
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "edit",
+            "--week",
+            "2025-01-28",
+            "--direct",
+            "1",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+    assert!(load_data(&data_dir)["weeks"][0].get("days").is_none());
+
+    // The next daily entry builds on the edited total, not the dropped day.
+    add_over(&config_dir, &data_dir, "2025-01-31", "1", "1");
+    let data = load_data(&data_dir);
+    let week = &data["weeks"][0];
+    assert_eq!(week["direct"].as_f64().unwrap(), 2.0);
+    let days = week["days"].as_array().unwrap();
+    assert_eq!(days.len(), 1);
+    assert_eq!(days[0]["date"], "2025-01-31");
+    assert_eq!(days[0]["direct"].as_f64().unwrap(), 1.0);
+}
+
+#[test]
+fn add_over_note_goes_to_first_week_only() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "--no-git",
+            "add",
+            "--date",
+            "2025-02-02",
+            "--over",
+            "10",
+            "--category",
+            "direct",
+            "--hours",
+            "10",
+            "--note",
+            "Weekend retreat",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 3);
+    assert_eq!(weeks[0]["start"], "2025-01-28");
+    assert_eq!(weeks[0]["note"], "Weekend retreat");
+    assert!(weeks[1].get("note").is_none(), "{data}");
+    assert!(weeks[2].get("note").is_none(), "{data}");
+}
+
+#[test]
+fn add_over_requires_date() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--over",
+            "3",
+            "--category",
+            "direct",
+            "--hours",
+            "9",
+            "--non-interactive",
+        ])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn add_over_rejects_out_of_range_spans() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    for over in ["0", "367", "4000000000"] {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args([
+                "add",
+                "--date",
+                "2025-02-03",
+                "--over",
+                over,
+                "--category",
+                "direct",
+                "--hours",
+                "1",
+                "--non-interactive",
+            ])
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("--over"));
+    }
+    assert!(load_data(&data_dir)["weeks"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn add_accepts_comma_decimal_hours() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3,5");
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["direct"].as_f64().unwrap(), 3.5);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--week",
+            "2025-01-28",
+            "--category",
+            "direct",
+            "--hours",
+            "1,234.5",
+            "--non-interactive",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Ambiguous hours '1,234.5'"));
+}
+
+#[test]
+fn add_from_timer_reads_stdin() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["add", "--from-timer", "--category", "direct"])
+        .write_stdin("1h30m\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Timer reported 1h30m = 1.50 hrs"));
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0]["direct"].as_f64().unwrap(), 1.5);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["add", "--from-timer", "--category", "direct"])
+        .write_stdin("soon")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid timer duration 'soon'"));
+}
+
+#[test]
+fn read_only_commands_accept_wednesday_start_data() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let json = serde_json::json!({
+        "weeks": [{
+            "start": "2025-01-29",
+            "end": "2025-02-04",
+            "individual_supervision": 0.0,
+            "group_supervision": 0.0,
+            "direct": 4.0,
+            "indirect": 0.0
+        }]
+    });
+    fs::write(
+        data_dir.path().join("hours.json"),
+        serde_json::to_string_pretty(&json).unwrap(),
+    )
+    .unwrap();
+
+    for args in [vec!["list"], vec!["summary", "--json"]] {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(&args)
+            .assert()
+            .success()
+            .stderr(predicate::str::contains(
+                "does not match the Tuesday–Monday week model",
+            ));
+    }
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--category",
+            "direct",
+            "--hours",
+            "1",
+            "--non-interactive",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a Tuesday"));
+}
+
+#[test]
+fn dedupe_merges_duplicate_weeks_into_one() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let week = |direct: f64, indirect: f64| {
+        serde_json::json!({
+            "start": "2025-02-04",
+            "end": "2025-02-10",
+            "individual_supervision": 0.0,
+            "group_supervision": 0.0,
+            "direct": direct,
+            "indirect": indirect
+        })
+    };
+    let json = serde_json::json!({ "weeks": [week(3.0, 0.0), week(2.5, 1.0)] });
+    fs::write(
+        data_dir.path().join("hours.json"),
+        serde_json::to_string_pretty(&json).unwrap(),
+    )
+    .unwrap();
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--hours",
+            "1",
+            "--category",
+            "direct",
+            "--non-interactive",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("hours dedupe"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("dedupe")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("merged 2 records"))
+        .stdout(predicate::str::contains("Merged 1 duplicate week(s)."));
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0]["direct"].as_f64().unwrap(), 5.5);
+    assert_eq!(weeks[0]["indirect"].as_f64().unwrap(), 1.0);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("dedupe")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No duplicate weeks found."));
+}
+
+#[test]
+fn config_set_start_date_updates_config() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-11", "direct", "4.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "set", "start_date", "2025-02-04"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "start_date: 2025-01-28 -> 2025-02-04",
+        ))
+        .stderr(predicate::str::contains("Warning").not());
+
+    let config = fs::read_to_string(config_dir.path().join("config.toml")).unwrap();
+    assert!(config.contains("start_date = \"2025-02-04\""), "{config}");
+    // Environment overrides must not leak into the saved file.
+    assert!(config.contains("auto_push = true"), "{config}");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "set", "start_date", "2025-02-06"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be a Tuesday"));
+}
+
+#[test]
+fn config_set_and_get_licensure_targets() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let config = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("config")
+            .args(args)
+            .assert()
+    };
+
+    config(&["set", "total_hours_target", "3360"])
+        .success()
+        .stdout(predicate::str::contains("total_hours_target: 3000 -> 3360"));
+    config(&["set", "git.auto_push", "false"])
+        .success()
+        .stdout(predicate::str::contains("git.auto_push: true -> false"));
+    config(&["get", "total_hours_target"])
+        .success()
+        .stdout("3360\n");
+
+    let contents = fs::read_to_string(config_dir.path().join("config.toml")).unwrap();
+    assert!(contents.contains("total_hours_target = 3360"), "{contents}");
+    assert!(contents.contains("auto_push = false"), "{contents}");
+
+    config(&["set", "direct_hours_target", "-5"])
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid direct_hours_target '-5': expected a non-negative whole number",
+        ));
+    config(&["get", "remote"])
+        .failure()
+        .stderr(predicate::str::contains("Unsupported config key 'remote'"));
+
+    // Bare `hours config` prints the whole effective config.
+    config(&[])
+        .success()
+        .stdout(predicate::str::contains("with overrides applied"))
+        .stdout(predicate::str::contains("total_hours_target = 3360"));
+}
+
+#[test]
+fn category_targets_show_in_summary() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "gs", "25");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "set", "group_supervision_target", "100"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "group_supervision_target: none -> 100",
+        ));
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let group = &json["by_category"]["group_supervision"];
+    assert_eq!(group["target"], 100);
+    assert_eq!(group["percentage"], 25.0);
+    assert!(json["by_category"]["indirect"].get("target").is_none());
+}
+
+#[test]
+fn profile_selects_alternate_targets() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "30");
+
+    let config_path = config_dir.path().join("config.toml");
+    let mut contents = fs::read_to_string(&config_path).unwrap();
+    contents.push_str(
+        "\n[profiles.lmft]\nstart_date = \"2025-01-28\"\ntotal_hours_target = 1500\n\
+         direct_hours_target = 300\nmin_months = 24\nmin_weekly_average = 10.0\n",
+    );
+    fs::write(&config_path, contents).unwrap();
+
+    let summary = |profile: Option<&str>| {
+        let mut cmd = hours_cmd();
+        cmd.env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1");
+        if let Some(name) = profile {
+            cmd.args(["--profile", name]);
+        }
+        let output = cmd.args(["summary", "--json"]).output().unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<Value>(&output.stdout).unwrap()
+    };
+    let json = summary(None);
+    assert_eq!(json["direct_hours"]["target"], 1200);
+    let json = summary(Some("lmft"));
+    assert_eq!(json["total_hours"]["target"], 1500);
+    assert_eq!(json["direct_hours"]["target"], 300);
+    assert_eq!(json["direct_hours"]["percentage"], 10.0);
+    assert_eq!(json["weekly_average"]["target"], 10.0);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["--profile", "lpc", "summary"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown profile 'lpc'. Available profiles: lmft",
+        ));
+}
+
+#[test]
+fn log_file_records_each_step() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    let log_path = config_dir.path().join("hours.log");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .env_remove("RUST_LOG")
+        .arg("--log-file")
+        .arg(&log_path)
+        .args([
+            "add",
+            "--week",
+            "2025-01-28",
+            "--category",
+            "direct",
+            "--hours",
+            "2.5",
+            "--non-interactive",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+
+    let log = fs::read_to_string(&log_path).unwrap();
+    for expected in [
+        "hours --log-file",
+        "Loaded config",
+        "Loaded ",
+        "Adding 2.5 direct hours to week of 2025-01-28",
+        "Saved ",
+        "(1 weeks,",
+        "Finished in",
+    ] {
+        assert!(log.contains(expected), "missing {expected:?} in:\n{log}");
+    }
+
+    // Without --log-file or RUST_LOG, nothing is logged anywhere.
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .env_remove("RUST_LOG")
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .env("RUST_LOG", "hours=debug")
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Loaded config"));
+}
+
+#[test]
+fn note_flags_set_week_note() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--category",
+            "direct",
+            "--hours",
+            "3.0",
+            "--note",
+            "Covered for a colleague",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .output()
+        .unwrap();
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["current_week"]["note"], "Covered for a colleague");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "edit",
+            "--week",
+            "2025-01-28",
+            "--note",
+            "Heavy caseload",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--json"])
+        .output()
+        .unwrap();
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let weeks = json["weeks"].as_array().unwrap();
+    assert_eq!(weeks[0]["start"], "2025-01-28");
+    assert_eq!(weeks[0]["note"], "Heavy caseload");
+    assert_eq!(weeks[1]["note"], "Covered for a colleague");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "edit",
+            "--week",
+            "2025-01-28",
+            "--note",
+            "",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+    let data = load_data(&data_dir);
+    assert!(data["weeks"][0].get("note").is_none(), "{data}");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["edit", "--note", "x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--note requires --non-interactive",
+        ));
+}
+
+#[test]
+fn undo_restores_previous_save() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "indirect", "2.0");
+
+    let undo = || {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("undo")
+            .assert()
+    };
+
+    undo()
+        .success()
+        .stdout(predicate::str::contains(
+            "Restored the previous version of hours.json: total 7.0 -> 5.0 hrs",
+        ))
+        .stdout(predicate::str::contains("modified"));
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["direct"], 5.0);
+    assert_eq!(data["weeks"][0]["indirect"], 0.0);
+
+    // The default single backup generation allows one step back.
+    undo()
+        .failure()
+        .stderr(predicate::str::contains("Nothing to undo"));
+}
+
+#[test]
+fn import_csv_accumulates_and_replaces() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    let csv = data_dir.path().join("history.csv");
+    fs::write(
+        &csv,
+        "start,individual_supervision,group_supervision,direct,indirect\n\
+         2025-01-28,1,0,3,0\n\
+         2025-02-04,0,2,10,1.5\n",
+    )
+    .unwrap();
+
+    let import = |extra: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(["import", "--file", csv.to_str().unwrap(), "--format", "csv"])
+            .args(extra)
+            .assert()
+    };
+
+    import(&[])
+        .success()
+        .stdout(predicate::str::contains("1 new, 1 added to"));
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"].as_array().unwrap().len(), 2);
+    assert_eq!(data["weeks"][0]["direct"], 8.0);
+    assert_eq!(data["weeks"][0]["individual_supervision"], 1.0);
+    assert_eq!(data["weeks"][1]["start"], "2025-02-04");
+    assert_eq!(data["weeks"][1]["end"], "2025-02-10");
+
+    import(&["--replace"]).success();
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["direct"], 3.0);
+    assert_eq!(data["weeks"][1]["direct"], 10.0);
+
+    fs::write(&csv, "2025-01-29,0,0,-1,0\n").unwrap();
+    import(&[])
+        .failure()
+        .stderr(predicate::str::contains("is a Wednesday"));
+}
+
+#[test]
+fn status_reports_paths_and_counts() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "5.0");
+
+    let data_file = data_dir.path().join("hours.json");
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Data file:    {}",
+            data_file.display()
+        )))
+        .stdout(predicate::str::contains("config.toml"))
+        .stdout(predicate::str::contains("Start date:   2025-01-28"))
+        .stdout(predicate::str::contains("Weeks logged: 2"))
+        .stdout(predicate::str::contains("Git:          disabled"));
+}
+
+#[test]
+fn remove_deletes_week_entry() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "3.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["remove", "--week", "2025-01-28", "--non-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed week of 2025-01-28"));
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0]["start"], "2025-02-04");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["remove", "--week", "2025-01-28", "--non-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No week starting 2025-01-28 to remove",
+        ));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["remove", "--non-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--week is required"));
+}
+
+#[test]
+fn config_show_effective_reflects_overrides() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    let override_dir = TempDir::new().unwrap();
+    let override_path = override_dir.path().display().to_string();
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", override_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "show", "--effective"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let effective = String::from_utf8(output.stdout).unwrap();
+    assert!(effective.contains(&override_path), "{effective}");
+    assert!(effective.contains("auto_push = false"), "{effective}");
+    assert!(
+        effective.contains("filename = \"hours.json\""),
+        "{effective}"
+    );
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", override_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "show", "--raw"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let raw = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        raw,
+        fs::read_to_string(config_dir.path().join("config.toml")).unwrap()
+    );
+    assert!(!raw.contains(&override_path), "{raw}");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .args(["config", "show", "--effective", "--raw"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn config_set_start_date_warns_about_orphaned_weeks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-11", "direct", "4.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["config", "set", "start_date", "2025-02-04"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Warning: 1 logged week(s) start before the new start date 2025-02-04",
+        ))
+        .stderr(predicate::str::contains("Jan 28 – Feb 03, 2025"))
+        .stderr(predicate::str::contains("--prune-before"));
+    assert_eq!(load_data(&data_dir)["weeks"].as_array().unwrap().len(), 2);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "config",
+            "set",
+            "start_date",
+            "2025-02-04",
+            "--prune-before",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Removed 1 week(s) before 2025-02-04",
+        ));
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0]["start"], "2025-02-11");
+}
+
+#[test]
+fn config_set_start_date_commits_only_pruned_data() {
+    if std::process::Command::new("git")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        return;
+    }
+
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    let dir = data_dir.path();
+    git_in(dir, &["init", "-q", "-b", "main"]);
+    git_in(dir, &["config", "user.email", "test@test.com"]);
+    git_in(dir, &["config", "user.name", "Test"]);
+    git_in(dir, &["add", "hours.json"]);
+    git_in(dir, &["commit", "-q", "-m", "seed"]);
+    let commits = || {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["log", "--format=%s"])
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let set_start = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .args(["config", "set", "start_date"])
+            .args(args)
+            .assert()
+            .success();
+    };
+
+    // Only config.toml changes, and it is not in the data repository.
+    set_start(&["2025-01-21"]);
+    let config = fs::read_to_string(config_dir.path().join("config.toml")).unwrap();
+    assert!(config.contains("2025-01-21"), "{config}");
+    assert_eq!(commits(), "seed\n");
+
+    set_start(&["2025-02-04", "--prune-before"]);
+    assert_eq!(
+        commits(),
+        "Remove 1 week(s) before start date 2025-02-04\nseed\n"
+    );
+}
+
+#[test]
+fn doctor_reports_checks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("doctor")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pass  config"))
+        .stdout(predicate::str::contains("pass  data file"))
+        .stdout(predicate::str::contains("warn  git"))
+        .stdout(predicate::str::contains("pass  fonts"));
+
+    fs::write(data_dir.path().join("hours.json"), "{not json").unwrap();
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("doctor")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("fail  data file"))
+        .stderr(predicate::str::contains("1 check(s) failed"));
+}
+
+#[test]
+fn add_accepts_category_short_codes() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    for code in ["is", "gs", "d", "i"] {
+        add_hours_to_week(&config_dir, &data_dir, "2025-01-28", code, "1.5");
+    }
+
+    let data = load_data(&data_dir);
+    let week = &data["weeks"][0];
+    for field in [
+        "individual_supervision",
+        "group_supervision",
+        "direct",
+        "indirect",
+    ] {
+        assert_eq!(week[field], 1.5, "{field}");
+    }
+}
+
+#[test]
+fn custom_data_filename_round_trips() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let config_path = config_dir.path().join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    let config = config.replacen("[data]\n", "[data]\nfilename = \"clinic.json\"\n", 1);
+    fs::write(&config_path, config).unwrap();
+    fs::write(data_dir.path().join("clinic.json"), r#"{"weeks":[]}"#).unwrap();
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "3.0");
+
+    let clinic: Value =
+        serde_json::from_str(&fs::read_to_string(data_dir.path().join("clinic.json")).unwrap())
+            .unwrap();
+    assert_eq!(clinic["weeks"][0]["direct"], 3.0);
+    // The default file from init is left alone.
+    assert_eq!(load_data(&data_dir)["weeks"].as_array().unwrap().len(), 0);
+    assert!(!data_dir.path().join("clinic.json.tmp").exists());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--porcelain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2025-01-28"));
+}
+
+#[test]
+fn scaffold_creates_missing_empty_weeks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-11", "direct", "8.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["scaffold", "--from", "2025-01-28", "--to", "2025-02-25"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created 4 empty week(s)."));
+
+    let data = load_data(&data_dir);
+    let weeks = data["weeks"].as_array().unwrap();
+    let starts: Vec<&str> = weeks.iter().map(|w| w["start"].as_str().unwrap()).collect();
+    assert_eq!(
+        starts,
+        [
+            "2025-01-28",
+            "2025-02-04",
+            "2025-02-11",
+            "2025-02-18",
+            "2025-02-25"
+        ]
+    );
+    assert_eq!(weeks[2]["direct"], 8.0);
+    assert_eq!(weeks[0]["direct"], 0.0);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["scaffold", "--from", "2025-02-25", "--to", "2025-01-28"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is before --from"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["scaffold", "--from", "2025-01-28", "--to", "2025-02-26"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be a Tuesday"));
+}
+
+#[test]
+fn summary_forecast_projects_totals() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "30.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "indirect", "5.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "summary",
+            "--json",
+            "--forecast-weeks",
+            "10",
+            "--forecast-rate",
+            "12.5",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    let forecast = &json["forecast"];
+    assert_eq!(forecast["weeks"], 10);
+    assert_eq!(forecast["rate"], 12.5);
+    assert_eq!(forecast["total_hours"]["current"], 160.0);
+    assert_eq!(forecast["direct_hours"]["current"], 155.0);
+    assert_eq!(forecast["targets_met"]["total_hours"], false);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "summary",
+            "--forecast-weeks",
+            "10",
+            "--forecast-rate",
+            "12.5",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Forecast: 10 more week(s) at 12.5 hrs/wk",
+        ))
+        .stdout(predicate::str::contains(
+            "Total supervised hours:    160.0 / 3000   (  5.3%) not met",
+        ));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--forecast-rate", "12.5"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn summary_window_averages_recent_weeks() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let today = chrono::Local::now().date_naive();
+    let offset = (today.weekday().num_days_from_monday() + 7 - 1) % 7;
+    let this_week = today - chrono::Duration::days(offset as i64);
+    let last_week = this_week - chrono::Duration::days(7);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "100.0");
+    add_hours_to_week(
+        &config_dir,
+        &data_dir,
+        &last_week.format("%Y-%m-%d").to_string(),
+        "direct",
+        "10.0",
+    );
+    add_hours_to_week(
+        &config_dir,
+        &data_dir,
+        &this_week.format("%Y-%m-%d").to_string(),
+        "direct",
+        "20.0",
+    );
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json", "--window", "2"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(json["weekly_average_window"]["weeks"], 2);
+    assert_eq!(json["weekly_average_window"]["current"], 15.0);
+    assert_eq!(json["weekly_average_window"]["target"], 15.0);
+    assert_eq!(json["weekly_average_overall"], json["weekly_average"]);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--window", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Last 2 weeks average:      15.0 /   15.0 (100.0%)",
+        ));
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert!(json.get("weekly_average_window").is_none());
+    assert!(json.get("weekly_average_overall").is_none());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--window", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--window must be >= 1, got 0"));
+}
+
+#[test]
+fn project_local_hoursrc_is_discovered_from_nested_dir() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "4.0");
+
+    let project = TempDir::new().unwrap();
+    let nested = project.path().join("students").join("a");
+    fs::create_dir_all(&nested).unwrap();
+    fs::copy(
+        config_dir.path().join("config.toml"),
+        project.path().join(".hoursrc"),
+    )
+    .unwrap();
+
+    hours_cmd()
+        .current_dir(&nested)
+        .env_remove("HOURS_CONFIG_DIR")
+        .env_remove("HOURS_DATA_DIR")
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--porcelain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2025-01-28"));
+
+    // --config beats the discovered file.
+    let empty = TempDir::new().unwrap();
+    let other = project.path().join("other.toml");
+    let contents = fs::read_to_string(project.path().join(".hoursrc")).unwrap();
+    let data_path = data_dir.path().to_str().unwrap();
+    fs::write(
+        &other,
+        contents.replace(data_path, empty.path().to_str().unwrap()),
+    )
+    .unwrap();
+    fs::write(empty.path().join("hours.json"), r#"{"weeks":[]}"#).unwrap();
+
+    hours_cmd()
+        .current_dir(&nested)
+        .env_remove("HOURS_CONFIG_DIR")
+        .env_remove("HOURS_DATA_DIR")
+        .env("HOURS_NO_GIT", "1")
+        .args(["--config", other.to_str().unwrap(), "list", "--porcelain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn list_with_progress_shows_percent_of_weekly_target() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "15.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "6.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "indirect", "4.0");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--with-progress"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let table = String::from_utf8(output).unwrap();
+    assert!(table.contains("% of Target"), "{table}");
+    let last_cell = |label: &str| -> String {
+        let line = table.lines().find(|l| l.contains(label)).unwrap();
+        line.trim_end_matches('│')
+            .rsplit('┆')
+            .next()
+            .unwrap()
+            .trim()
+            .to_string()
+    };
+    assert_eq!(last_cell("Jan 28 – Feb 03"), "100%");
+    assert_eq!(last_cell("Feb 04 – Feb 10"), "40%");
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--with-progress", "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["weeks"][0]["weekly_target_pct"], 100.0);
+    assert_eq!(json["weeks"][1]["weekly_target_pct"], 40.0);
+}
+
+#[test]
+fn add_confirm_summary_recaps_accumulated_week() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "9.0");
+    add_hours_to_week(
+        &config_dir,
+        &data_dir,
+        "2025-01-28",
+        "group_supervision",
+        "1.5",
+    );
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--week",
+            "2025-01-28",
+            "--category",
+            "direct",
+            "--hours",
+            "6.5",
+            "--non-interactive",
+            "--confirm-summary",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Week of Jan 28 – Feb 03, 2025: Ind Sv 0.0 | Grp Sv 1.5 | Direct 15.5 | Indirect 0.0 | Total 17.0",
+        ))
+        .stdout(predicate::str::contains("Direct target: 15.0 (met)"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--category",
+            "direct",
+            "--hours",
+            "1",
+            "--confirm-summary",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--confirm-summary requires --non-interactive",
+        ));
+}
+
+#[test]
+fn data_backups_keep_configured_generations() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let config_path = config_dir.path().join("config.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config.replacen("[data]\n", "[data]\nbackups = 2\n", 1),
+    )
+    .unwrap();
+
+    for hours in ["1.0", "2.0", "3.0"] {
+        add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", hours);
+    }
+
+    let direct_in = |name: &str| -> f64 {
+        let json: Value =
+            serde_json::from_str(&fs::read_to_string(data_dir.path().join(name)).unwrap()).unwrap();
+        json["weeks"][0]["direct"].as_f64().unwrap()
+    };
+    assert_eq!(direct_in("hours.json"), 6.0);
+    assert_eq!(direct_in("hours.json.bak.1"), 3.0);
+    assert_eq!(direct_in("hours.json.bak.2"), 1.0);
+    assert!(!data_dir.path().join("hours.json.bak.3").exists());
+}
+
+#[test]
+fn edit_editor_sets_all_categories() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    let edit = |editor: &str| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .env("EDITOR", editor)
+            .args(["edit", "--editor", "--week", "2025-01-28"])
+            .assert()
+    };
+
+    edit("sed -i -e 's/^direct = .*/direct = 7.5/' -e 's/^indirect = .*/indirect = 2/'")
+        .success()
+        .stdout(predicate::str::contains(
+            "Edited hours for week of 2025-01-28",
+        ));
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["direct"], 7.5);
+    assert_eq!(data["weeks"][0]["indirect"], 2.0);
+
+    // A failed editor or an unparseable file leaves hours.json alone.
+    let before = fs::read_to_string(data_dir.path().join("hours.json")).unwrap();
+    edit("false")
+        .failure()
+        .stderr(predicate::str::contains("week left unchanged"));
+    edit("sed -i 's/^direct = .*/direct = lots/'")
+        .failure()
+        .stderr(predicate::str::contains("Failed to parse edited week"));
+    edit("sed -i 's/^direct = .*/direct = -3/'")
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid direct value -3: hours must be >= 0",
+        ));
+    let after = fs::read_to_string(data_dir.path().join("hours.json")).unwrap();
+    assert_eq!(before, after);
+
+    edit("true")
+        .success()
+        .stdout(predicate::str::contains("No changes to week of 2025-01-28"));
+}
+
+#[test]
+fn edit_exclude_marks_break_week() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let edit = |flag: &str| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(["edit", "--week", "2025-02-04", flag, "--non-interactive"])
+            .assert()
+            .success();
+    };
+
+    edit("--exclude");
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["excluded"], true);
+    assert_eq!(data["weeks"][0]["direct"], 0.0);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Feb 10, 2025 (excluded)"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--explain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(1 excluded)"));
+
+    edit("--include");
+    let data = load_data(&data_dir);
+    assert!(data["weeks"][0].get("excluded").is_none());
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["edit", "--week", "2025-02-04", "--exclude"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--exclude and --include require --non-interactive",
+        ));
+}
+
+#[test]
+fn edit_week_target_judges_week_against_override() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "6.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "6.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "edit",
+            "--week",
+            "2025-01-28",
+            "--week-target",
+            "5",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"][0]["target"], 5.0);
+    assert_eq!(data["weeks"][0]["direct"], 6.0);
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--json"])
+        .output()
+        .unwrap();
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let weeks = &json["weeks"];
+    assert_eq!(weeks[0]["target"], 5.0);
+    assert_eq!(weeks[0]["target_met"], true);
+    // The other week falls back to min_weekly_average (15.0).
+    assert_eq!(weeks[1]["target"], 15.0);
+    assert_eq!(weeks[1]["target_met"], false);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Direct Target"))
+        .stdout(predicate::str::contains("✓ 5.0"))
+        .stdout(predicate::str::contains("✗ 15.0"));
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .arg("summary")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Weekly targets met: 1 / 2 completed week(s)",
+        ));
+}
+
+#[test]
+fn list_latest_shows_only_most_recent_week() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No hours logged yet"));
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "3.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Feb 04 – Feb 10, 2025"))
+        .stdout(predicate::str::contains("Jan 28").not())
+        .stdout(predicate::str::contains("TOTALS").not());
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--latest", "--json"])
+        .output()
+        .unwrap();
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let weeks = &json["weeks"];
+    assert_eq!(weeks.as_array().unwrap().len(), 1);
+    assert_eq!(weeks[0]["start"], "2025-02-04");
+}
+
+#[test]
+fn summary_latest_reports_week_against_target() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No hours logged yet"));
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "20.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "12.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--latest"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Latest week: Feb 04 – Feb 10, 2025",
+        ))
+        .stdout(predicate::str::contains("Direct target: 15.0 (3.0 short)"))
+        .stdout(predicate::str::contains("Licensure Progress").not());
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--latest", "--json"])
+        .output()
+        .unwrap();
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(json["direct"], 12.0);
+    assert_eq!(json["target_met"], false);
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["summary", "--latest", "--format", "all"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--latest only applies to text and JSON output",
+        ));
+}
+
+#[test]
+fn backup_create_list_and_restore() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let backup = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("backup")
+            .args(args)
+            .assert()
+    };
+
+    backup(&["--list"])
+        .success()
+        .stdout(predicate::str::contains("No backups in"));
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    backup(&[])
+        .success()
+        .stdout(predicate::str::contains("Backed up hours.json to"));
+
+    let backups_dir = data_dir.path().join("backups");
+    let names: Vec<String> = fs::read_dir(&backups_dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(names.len(), 1);
+    assert!(names[0].starts_with("hours-") && names[0].ends_with(".json"));
+
+    backup(&["--list"])
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{}  1 week(s)  5.0 hrs",
+            names[0]
+        )));
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "3.0");
+    backup(&["--restore", &names[0]])
+        .success()
+        .stdout(predicate::str::contains("total 8.0 -> 5.0 hrs"));
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"].as_array().unwrap().len(), 1);
+    assert_eq!(data["weeks"][0]["direct"], 5.0);
+
+    // A file that fails validation leaves the data file untouched.
+    let bad = backups_dir.join("broken.json");
+    fs::write(&bad, "not json").unwrap();
+    backup(&["--restore", "broken.json"]).failure();
+    backup(&["--list"])
+        .success()
+        .stdout(predicate::str::contains("broken.json  (unreadable)"));
+    assert_eq!(load_data(&data_dir)["weeks"][0]["direct"], 5.0);
+}
+
+#[test]
+fn stats_reports_weekly_totals_and_streak() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    let stats = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .arg("stats")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = stats(&[]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("No hours logged yet."));
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "4.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "10.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-11", "indirect", "7.0");
+    // The current week, so the streak is 1.
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args([
+            "add",
+            "--category",
+            "direct",
+            "--hours",
+            "3.0",
+            "--non-interactive",
+        ])
+        .assert()
+        .success();
+
+    let output = stats(&["--json"]);
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(json["weeks"], 4);
+    assert_eq!(json["min"], 3.0);
+    assert_eq!(json["max"], 10.0);
+    assert_eq!(json["mean"], 6.0);
+    assert_eq!(json["median"], 5.5);
+    assert_eq!(json["best_week"], "2025-02-04");
+    assert_eq!(json["streak"], 1);
+
+    let output = stats(&[]);
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        text.contains("Maximum:            10.0 hrs (week of Feb 04, 2025)"),
+        "{text}"
+    );
+    assert!(text.contains("Current streak:   1 week(s)"), "{text}");
+}
+
+#[test]
+fn list_relative_labels_table_but_not_json() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+
+    hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--relative"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Jan 28 – Feb 03, 2025 ("))
+        .stdout(predicate::str::contains(" weeks ago)"));
+
+    let output = hours_cmd()
+        .env("HOURS_CONFIG_DIR", config_dir.path())
+        .env("HOURS_DATA_DIR", data_dir.path())
+        .env("HOURS_NO_GIT", "1")
+        .args(["list", "--relative", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["weeks"][0]["start"], "2025-01-28");
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("ago"));
+}
+
+#[test]
+fn broken_data_file_is_recovered_only_with_flag() {
+    let config_dir = TempDir::new().unwrap();
+    let data_dir = TempDir::new().unwrap();
+    init_env(&config_dir, &data_dir);
+
+    add_hours_to_week(&config_dir, &data_dir, "2025-01-28", "direct", "5.0");
+    add_hours_to_week(&config_dir, &data_dir, "2025-02-04", "direct", "2.0");
+    let data_file = data_dir.path().join("hours.json");
+    fs::write(&data_file, "{\"weeks\": [").unwrap();
+
+    let hours = |args: &[&str]| {
+        hours_cmd()
+            .env("HOURS_CONFIG_DIR", config_dir.path())
+            .env("HOURS_DATA_DIR", data_dir.path())
+            .env("HOURS_NO_GIT", "1")
+            .args(args)
+            .assert()
+    };
+
+    // Read-only commands report the readable backup but leave the file alone.
+    for args in [&["list"][..], &["summary"], &["stats"]] {
+        hours(args)
+            .failure()
+            .stderr(predicate::str::contains(
+                "hours.json.bak.1 holds a readable copy",
+            ))
+            .stderr(predicate::str::contains("--recover"));
+    }
+    assert_eq!(fs::read_to_string(&data_file).unwrap(), "{\"weeks\": [");
+
+    hours(&["--recover", "list", "--json"])
+        .success()
+        .stderr(predicate::str::contains("Recovered"))
+        .stdout(predicate::str::contains("\"2025-01-28\""));
+    let data = load_data(&data_dir);
+    assert_eq!(data["weeks"].as_array().unwrap().len(), 1);
+
+    hours(&["--recover", "list"])
+        .success()
+        .stdout(predicate::str::contains("loads fine; nothing to recover"));
+}